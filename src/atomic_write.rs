@@ -0,0 +1,72 @@
+use crate::ClientError;
+use std::ffi::OsString;
+use std::path::Path;
+use tempfile::NamedTempFile;
+use tokio::fs::File;
+use tokio::io::AsyncWriteExt;
+
+/// Creates a hidden temporary file next to `path`, so the final rename stays on one filesystem and
+/// is atomic. The file is removed when dropped, including on cancellation.
+pub(crate) fn temp_file_beside(path: &Path) -> std::io::Result<NamedTempFile> {
+    let directory = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+    let mut prefix = OsString::from(".");
+    prefix.push(path.file_name().unwrap_or_default());
+    prefix.push(".");
+    tempfile::Builder::new()
+        .prefix(&prefix)
+        .suffix(".tmp")
+        .tempfile_in(directory)
+}
+
+/// Replaces `path` with `contents` so readers see either the old or the new file, never a partial
+/// one, even if the process dies part way through.
+pub(crate) async fn write_atomically(path: &Path, contents: &[u8]) -> Result<(), ClientError> {
+    let (file, temp_path) = temp_file_beside(path)?.into_parts();
+    let mut file = File::from_std(file);
+    file.write_all(contents).await?;
+    file.sync_all().await?;
+    drop(file);
+
+    temp_path
+        .persist(path)
+        .map_err(|error| ClientError::Io(error.error))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error;
+
+    mod write_atomically_test {
+        use super::*;
+
+        #[tokio::test]
+        async fn write_atomically_replaces_existing_file() -> Result<(), Box<dyn Error>> {
+            let directory = tempfile::tempdir()?;
+            let path = directory.path().join("state.json");
+            std::fs::write(&path, "old")?;
+
+            write_atomically(&path, b"new").await?;
+
+            assert_eq!(std::fs::read_to_string(&path)?, "new");
+            assert_eq!(std::fs::read_dir(directory.path())?.count(), 1);
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn write_atomically_fails_without_parent_directory() -> Result<(), Box<dyn Error>> {
+            let directory = tempfile::tempdir()?;
+            let path = directory.path().join("missing").join("state.json");
+
+            assert!(matches!(
+                write_atomically(&path, b"new").await,
+                Err(ClientError::Io(_))
+            ));
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,195 @@
+use crate::rate_limit::{RateLimit, RateLimiter};
+use crate::response_cache::ResponseCache;
+use crate::secret::Secret;
+use crate::{ClientError, DigitalBlasphemyClient, RetryPolicy};
+use indexmap::IndexMap;
+use reqwest::header::{HeaderMap, HeaderValue, USER_AGENT};
+use reqwest::{Proxy, Url};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::Duration;
+
+/// Used for thumbnails until a response advertises the thumb endpoint in `Endpoints`.
+const DEFAULT_THUMB_ENDPOINT: &str = "https://cdn.digitalblasphemy.com";
+
+#[derive(Clone)]
+pub struct DigitalBlasphemyClientBuilder {
+    api_key: Secret,
+    base_url: String,
+    connect_timeout: Option<Duration>,
+    read_timeout: Option<Duration>,
+    timeout: Option<Duration>,
+    user_agent: Option<HeaderValue>,
+    proxy: Option<Proxy>,
+    default_headers: HeaderMap,
+    client: Option<reqwest::Client>,
+    retry_policy: RetryPolicy,
+    rate_limit: Option<RateLimit>,
+    download_rate_limit: Option<RateLimit>,
+    thumbnail_cache_dir: Option<PathBuf>,
+    response_cache: Option<ResponseCache>,
+}
+
+impl DigitalBlasphemyClientBuilder {
+    pub(crate) fn new(api_key: String) -> Self {
+        DigitalBlasphemyClientBuilder {
+            api_key: Secret::new(api_key),
+            base_url: "https://api.digitalblasphemy.com".to_string(),
+            connect_timeout: None,
+            read_timeout: None,
+            timeout: None,
+            user_agent: None,
+            proxy: None,
+            default_headers: HeaderMap::new(),
+            client: None,
+            retry_policy: RetryPolicy::none(),
+            rate_limit: None,
+            download_rate_limit: None,
+            thumbnail_cache_dir: None,
+            response_cache: None,
+        }
+    }
+
+    /// Fails with [`ClientError::InvalidApiKey`] if the API key cannot be sent in a header.
+    pub fn build(self) -> Result<DigitalBlasphemyClient, ClientError> {
+        DigitalBlasphemyClient::bearer(&self.api_key)?;
+
+        let allowed_hosts = Arc::new(RwLock::new(
+            [self.base_url.as_str(), DEFAULT_THUMB_ENDPOINT]
+                .into_iter()
+                .filter_map(|endpoint| Url::parse(endpoint).ok())
+                .filter_map(|url| url.host_str().map(str::to_string))
+                .collect(),
+        ));
+
+        let client = match self.client {
+            Some(client) => client,
+            None => {
+                let mut client_builder = reqwest::Client::builder().redirect(
+                    DigitalBlasphemyClient::redirect_policy(allowed_hosts.clone()),
+                );
+                if let Some(connect_timeout) = self.connect_timeout {
+                    client_builder = client_builder.connect_timeout(connect_timeout);
+                }
+                if let Some(read_timeout) = self.read_timeout {
+                    client_builder = client_builder.read_timeout(read_timeout);
+                }
+                if let Some(timeout) = self.timeout {
+                    client_builder = client_builder.timeout(timeout);
+                }
+                if let Some(proxy) = self.proxy {
+                    client_builder = client_builder.proxy(proxy);
+                }
+                client_builder.build()?
+            }
+        };
+
+        let mut default_headers = self.default_headers;
+        if let Some(user_agent) = self.user_agent {
+            default_headers.insert(USER_AGENT, user_agent);
+        }
+
+        Ok(DigitalBlasphemyClient {
+            api_key: self.api_key,
+            client,
+            base_url: self.base_url.trim_end_matches('/').to_string(),
+            allowed_hosts,
+            default_headers,
+            retry_policy: self.retry_policy,
+            rate_limiter: self
+                .rate_limit
+                .map(|rate_limit| Arc::new(RateLimiter::new(rate_limit))),
+            download_rate_limiter: self
+                .download_rate_limit
+                .map(|rate_limit| Arc::new(RateLimiter::new(rate_limit))),
+            thumb_endpoint: Arc::new(RwLock::new(DEFAULT_THUMB_ENDPOINT.to_string())),
+            thumbnail_cache_dir: self.thumbnail_cache_dir,
+            response_cache: self.response_cache,
+            revalidation_entries: Arc::new(Mutex::new(IndexMap::new())),
+        })
+    }
+
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    /// Ignored when a pre-configured client is supplied via [`Self::client`].
+    pub fn connect_timeout(mut self, connect_timeout: Duration) -> Self {
+        self.connect_timeout = Some(connect_timeout);
+        self
+    }
+
+    /// Ignored when a pre-configured client is supplied via [`Self::client`].
+    pub fn read_timeout(mut self, read_timeout: Duration) -> Self {
+        self.read_timeout = Some(read_timeout);
+        self
+    }
+
+    /// Ignored when a pre-configured client is supplied via [`Self::client`].
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn user_agent(mut self, user_agent: HeaderValue) -> Self {
+        self.user_agent = Some(user_agent);
+        self
+    }
+
+    /// Ignored when a pre-configured client is supplied via [`Self::client`]. SOCKS proxies
+    /// require the `socks` feature.
+    pub fn proxy(mut self, proxy: Proxy) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// Headers sent with every request, including downloads from the image host.
+    pub fn default_headers(mut self, default_headers: HeaderMap) -> Self {
+        self.default_headers = default_headers;
+        self
+    }
+
+    /// Uses `client` as is. Its own redirect policy, timeouts and proxy settings apply instead of
+    /// the ones configured on this builder. Redirects are then no longer limited to the API host
+    /// and the hosts advertised in `Endpoints`, so give `client` a suitable redirect policy.
+    pub fn client(mut self, client: reqwest::Client) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    /// Retries failed GET requests according to `retry_policy`. By default every request is sent
+    /// once.
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Limits requests to the API host. Requests wait for capacity rather than failing, and the
+    /// limit is shared by every clone of the built client.
+    pub fn rate_limit(mut self, rate_limit: RateLimit) -> Self {
+        self.rate_limit = Some(rate_limit);
+        self
+    }
+
+    /// Limits requests to any other host, such as wallpaper downloads from the image CDN. This
+    /// budget is separate from [`Self::rate_limit`].
+    pub fn download_rate_limit(mut self, download_rate_limit: RateLimit) -> Self {
+        self.download_rate_limit = Some(download_rate_limit);
+        self
+    }
+
+    /// Caches thumbnails fetched by [`DigitalBlasphemyClient::get_thumbnail`] in `directory`,
+    /// keyed by their URL. The directory is created when the first thumbnail is stored.
+    pub fn thumbnail_cache_dir(mut self, directory: impl Into<PathBuf>) -> Self {
+        self.thumbnail_cache_dir = Some(directory.into());
+        self
+    }
+
+    /// Caches `get_wallpapers` and `get_wallpaper` responses on disk, along with their `ETag` and
+    /// `Last-Modified` validators. See [`ResponseCache`].
+    pub fn response_cache(mut self, response_cache: ResponseCache) -> Self {
+        self.response_cache = Some(response_cache);
+        self
+    }
+}
@@ -0,0 +1,432 @@
+use crate::atomic_write::write_atomically;
+use crate::{
+    ClientError, DigitalBlasphemyClient, GetWallpapersRequest, Operator, Wallpaper, WallpaperId,
+};
+use futures::TryStreamExt;
+use log::warn;
+use serde::{Deserialize, Serialize};
+use std::io::ErrorKind;
+use std::path::Path;
+
+const SECONDS_PER_DAY: i64 = 86_400;
+/// No wallpaper in the catalog was released before this year.
+const FIRST_CATALOG_YEAR: u16 = 1997;
+
+/// The newest wallpaper seen so far, persisted between runs of
+/// [`DigitalBlasphemyClient::get_new_wallpapers`].
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct IncrementalState {
+    /// The newest `Wallpaper.timestamp` seen.
+    pub high_water_mark: Option<i64>,
+    /// The wallpapers released exactly at `high_water_mark`, so they are not reported twice.
+    pub seen_at_high_water_mark: Vec<WallpaperId>,
+}
+
+impl IncrementalState {
+    /// Returns the default state if `path` does not exist yet.
+    pub async fn load(path: &Path) -> Result<IncrementalState, ClientError> {
+        match tokio::fs::read_to_string(path).await {
+            Ok(state) => serde_json::from_str(&state).map_err(|source| ClientError::Decode {
+                body: state,
+                source,
+            }),
+            Err(error) if error.kind() == ErrorKind::NotFound => Ok(IncrementalState::default()),
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    /// Replaces the file atomically, so an interrupted run keeps the previous state.
+    pub async fn save(&self, path: &Path) -> Result<(), ClientError> {
+        let state = serde_json::to_vec_pretty(self).map_err(std::io::Error::other)?;
+        write_atomically(path, &state).await
+    }
+
+    /// Narrows `request` to wallpapers released on or after the UTC day before the high-water mark,
+    /// starting from the first page. The API only filters by day, and not necessarily by UTC day,
+    /// so the extra day keeps a mark just after midnight from skipping releases; [`Self::is_new`]
+    /// drops the older wallpapers this includes. A mark whose year cannot be sent to the API
+    /// filters from the start of the catalog instead.
+    pub fn request(&self, request: &GetWallpapersRequest) -> GetWallpapersRequest {
+        let mut request = request.clone();
+        if let Some(high_water_mark) = self.high_water_mark {
+            let (year, month, day) =
+                civil_from_days(high_water_mark.div_euclid(SECONDS_PER_DAY) - 1);
+            let (year, month, day) = match u16::try_from(year) {
+                Ok(year) if year >= FIRST_CATALOG_YEAR => (year, month, day),
+                _ => {
+                    warn!(
+                        "High-water mark {high_water_mark} is outside the catalog, filtering from \
+                        {FIRST_CATALOG_YEAR} instead"
+                    );
+                    (FIRST_CATALOG_YEAR, 1, 1)
+                }
+            };
+            request.filter_date_year = year;
+            request.filter_date_month = month;
+            request.filter_date_day = day;
+            request.filter_date_operator = Operator::GreaterThanOrEqual;
+            request.page = 1;
+        }
+        request
+    }
+
+    /// Wallpapers without a timestamp are always treated as new.
+    pub fn is_new(&self, wallpaper: &Wallpaper) -> bool {
+        match (self.high_water_mark, wallpaper.timestamp) {
+            (Some(high_water_mark), Some(timestamp)) => {
+                timestamp > high_water_mark
+                    || (timestamp == high_water_mark
+                        && !self.seen_at_high_water_mark.contains(&wallpaper.id))
+            }
+            _ => true,
+        }
+    }
+
+    pub fn observe(&mut self, wallpaper: &Wallpaper) {
+        let Some(timestamp) = wallpaper.timestamp else {
+            return;
+        };
+        match self.high_water_mark {
+            Some(high_water_mark) if timestamp < high_water_mark => {}
+            Some(high_water_mark) if timestamp == high_water_mark => {
+                if !self.seen_at_high_water_mark.contains(&wallpaper.id) {
+                    self.seen_at_high_water_mark.push(wallpaper.id);
+                }
+            }
+            _ => {
+                self.high_water_mark = Some(timestamp);
+                self.seen_at_high_water_mark = vec![wallpaper.id];
+            }
+        }
+    }
+}
+
+impl DigitalBlasphemyClient {
+    /// Returns the wallpapers matching `request` released since the previous call with the same
+    /// `state_file`, and advances the high-water mark stored in it. The first call returns every
+    /// match. The state is only saved once every page has been fetched.
+    pub async fn get_new_wallpapers(
+        &self,
+        state_file: &impl AsRef<Path>,
+        request: &GetWallpapersRequest,
+    ) -> Result<Vec<Wallpaper>, ClientError> {
+        let state_file = state_file.as_ref();
+        let mut state = IncrementalState::load(state_file).await?;
+        let wallpapers: Vec<Wallpaper> = self
+            .wallpapers_stream(&state.request(request))
+            .try_filter(|wallpaper| std::future::ready(state.is_new(wallpaper)))
+            .try_collect()
+            .await?;
+        wallpapers
+            .iter()
+            .for_each(|wallpaper| state.observe(wallpaper));
+        state.save(state_file).await?;
+        Ok(wallpapers)
+    }
+}
+
+/// Converts days since the Unix epoch to a proleptic Gregorian `(year, month, day)`, following
+/// Howard Hinnant's `civil_from_days`.
+fn civil_from_days(days: i64) -> (i64, u8, u8) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * month_index + 2) / 5 + 1) as u8;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    } as u8;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::wallpaper_released_at;
+    use mockito::Matcher;
+    use std::error::Error;
+    use std::fs;
+
+    mod civil_from_days_test {
+        use super::*;
+
+        #[test]
+        fn test_civil_from_days_epoch() {
+            assert_eq!(civil_from_days(0), (1970, 1, 1));
+        }
+
+        #[test]
+        fn test_civil_from_days_leap_day() {
+            assert_eq!(civil_from_days(11_016), (2000, 2, 29));
+        }
+
+        #[test]
+        fn test_civil_from_days_recent_date() {
+            assert_eq!(
+                civil_from_days(1_700_000_000 / SECONDS_PER_DAY),
+                (2023, 11, 14)
+            );
+        }
+
+        #[test]
+        fn test_civil_from_days_before_epoch() {
+            assert_eq!(civil_from_days(-1), (1969, 12, 31));
+        }
+    }
+
+    mod incremental_state_test {
+        use super::*;
+
+        #[test]
+        fn test_incremental_state_request_without_high_water_mark() {
+            let request = GetWallpapersRequest::builder().page(3).build();
+
+            let request = IncrementalState::default().request(&request);
+
+            assert_eq!(request.filter_date_year, 0);
+            assert_eq!(request.page, 3);
+        }
+
+        #[test]
+        fn test_incremental_state_request_filters_from_day_before_high_water_mark() {
+            let state = IncrementalState {
+                high_water_mark: Some(1_700_000_000),
+                seen_at_high_water_mark: vec![],
+            };
+            let request = GetWallpapersRequest::builder()
+                .filter_date_operator(Operator::LessThan)
+                .page(3)
+                .build();
+
+            let request = state.request(&request);
+
+            assert_eq!(request.filter_date_year, 2023);
+            assert_eq!(request.filter_date_month, 11);
+            assert_eq!(request.filter_date_day, 13);
+            assert_eq!(request.filter_date_operator, Operator::GreaterThanOrEqual);
+            assert_eq!(request.page, 1);
+        }
+
+        #[test]
+        fn test_incremental_state_request_clamps_out_of_range_high_water_mark() {
+            for high_water_mark in [i64::MIN, -1, 0, i64::MAX] {
+                let state = IncrementalState {
+                    high_water_mark: Some(high_water_mark),
+                    seen_at_high_water_mark: vec![],
+                };
+
+                let request = state.request(&GetWallpapersRequest::builder().build());
+
+                assert_eq!(request.filter_date_year, FIRST_CATALOG_YEAR);
+                assert_eq!(request.filter_date_month, 1);
+                assert_eq!(request.filter_date_day, 1);
+            }
+        }
+
+        #[test]
+        fn test_incremental_state_request_just_after_midnight_includes_previous_day() {
+            let state = IncrementalState {
+                // 2023-11-01 00:00:01 UTC
+                high_water_mark: Some(1_698_796_801),
+                seen_at_high_water_mark: vec![],
+            };
+
+            let request = state.request(&GetWallpapersRequest::builder().build());
+
+            assert_eq!(request.filter_date_year, 2023);
+            assert_eq!(request.filter_date_month, 10);
+            assert_eq!(request.filter_date_day, 31);
+        }
+
+        #[test]
+        fn test_incremental_state_is_new() {
+            let state = IncrementalState {
+                high_water_mark: Some(1_700_000_000),
+                seen_at_high_water_mark: vec![WallpaperId(5)],
+            };
+
+            assert!(!state.is_new(&wallpaper_released_at(4, Some(1_699_990_000))));
+            assert!(!state.is_new(&wallpaper_released_at(5, Some(1_700_000_000))));
+            assert!(state.is_new(&wallpaper_released_at(6, Some(1_700_000_000))));
+            assert!(state.is_new(&wallpaper_released_at(7, Some(1_700_090_000))));
+            assert!(state.is_new(&wallpaper_released_at(8, None)));
+        }
+
+        #[test]
+        fn test_incremental_state_observe_tracks_newest_wallpapers() {
+            let mut state = IncrementalState::default();
+
+            state.observe(&wallpaper_released_at(1, Some(100)));
+            state.observe(&wallpaper_released_at(2, Some(200)));
+            state.observe(&wallpaper_released_at(3, Some(200)));
+            state.observe(&wallpaper_released_at(4, Some(150)));
+            state.observe(&wallpaper_released_at(5, None));
+
+            assert_eq!(
+                state,
+                IncrementalState {
+                    high_water_mark: Some(200),
+                    seen_at_high_water_mark: vec![WallpaperId(2), WallpaperId(3)],
+                }
+            );
+        }
+
+        #[tokio::test]
+        async fn test_incremental_state_load_defaults_when_missing() -> Result<(), Box<dyn Error>> {
+            let directory = tempfile::tempdir()?;
+
+            let state = IncrementalState::load(&directory.path().join("state.json")).await?;
+
+            assert_eq!(state, IncrementalState::default());
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn test_incremental_state_save_round_trips() -> Result<(), Box<dyn Error>> {
+            let directory = tempfile::tempdir()?;
+            let path = directory.path().join("state.json");
+            let state = IncrementalState {
+                high_water_mark: Some(1_700_000_000),
+                seen_at_high_water_mark: vec![WallpaperId(5)],
+            };
+
+            state.save(&path).await?;
+
+            assert_eq!(IncrementalState::load(&path).await?, state);
+
+            Ok(())
+        }
+    }
+
+    mod get_new_wallpapers_test {
+        use super::*;
+
+        #[tokio::test]
+        async fn get_new_wallpapers_returns_only_wallpapers_since_last_run()
+        -> Result<(), Box<dyn Error>> {
+            let mut server = mockito::Server::new_async().await;
+            let directory = tempfile::tempdir()?;
+            let state_file = directory.path().join("state.json");
+            IncrementalState {
+                high_water_mark: Some(1_700_000_000),
+                seen_at_high_water_mark: vec![WallpaperId(5)],
+            }
+            .save(&state_file)
+            .await?;
+
+            let mock = server
+                .mock(
+                    "GET",
+                    Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
+                )
+                .match_query(Matcher::AllOf(vec![
+                    Matcher::UrlEncoded("filter_date_year".to_string(), "2023".to_string()),
+                    Matcher::UrlEncoded("filter_date_month".to_string(), "11".to_string()),
+                    Matcher::UrlEncoded("filter_date_day".to_string(), "13".to_string()),
+                    Matcher::UrlEncoded("filter_date_operator".to_string(), ">=".to_string()),
+                ]))
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(fs::read_to_string(
+                    "resources/get_wallpapers_success_new_releases.json",
+                )?)
+                .create_async()
+                .await;
+
+            let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
+
+            let wallpapers = client
+                .get_new_wallpapers(&state_file, &GetWallpapersRequest::builder().build())
+                .await?;
+
+            let ids: Vec<u32> = wallpapers.iter().map(|w| w.id.get()).collect();
+            assert_eq!(ids, vec![6, 7]);
+            assert_eq!(
+                IncrementalState::load(&state_file).await?,
+                IncrementalState {
+                    high_water_mark: Some(1_700_090_000),
+                    seen_at_high_water_mark: vec![WallpaperId(7)],
+                }
+            );
+
+            mock.assert_async().await;
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn get_new_wallpapers_returns_everything_on_first_run() -> Result<(), Box<dyn Error>>
+        {
+            let mut server = mockito::Server::new_async().await;
+            let directory = tempfile::tempdir()?;
+            let state_file = directory.path().join("state.json");
+
+            let mock = server
+                .mock(
+                    "GET",
+                    Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
+                )
+                .match_query(Matcher::UrlEncoded("page".to_string(), "3".to_string()))
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(fs::read_to_string(
+                    "resources/get_wallpapers_success_new_releases.json",
+                )?)
+                .create_async()
+                .await;
+
+            let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
+
+            let wallpapers = client
+                .get_new_wallpapers(
+                    &state_file,
+                    &GetWallpapersRequest::builder().page(3).build(),
+                )
+                .await?;
+
+            assert_eq!(wallpapers.len(), 4);
+            assert_eq!(
+                IncrementalState::load(&state_file).await?.high_water_mark,
+                Some(1_700_090_000)
+            );
+
+            mock.assert_async().await;
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn get_new_wallpapers_keeps_state_when_request_fails() -> Result<(), Box<dyn Error>> {
+            let mut server = mockito::Server::new_async().await;
+            let directory = tempfile::tempdir()?;
+            let state_file = directory.path().join("state.json");
+
+            server
+                .mock(
+                    "GET",
+                    Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
+                )
+                .with_status(500)
+                .create_async()
+                .await;
+
+            let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
+
+            let result = client
+                .get_new_wallpapers(&state_file, &GetWallpapersRequest::builder().build())
+                .await;
+
+            assert!(matches!(result, Err(ClientError::Api(_))));
+            assert!(!state_file.exists());
+
+            Ok(())
+        }
+    }
+}
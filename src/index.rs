@@ -0,0 +1,1193 @@
+use crate::{
+    ClientError, Comment, Comments, DigitalBlasphemyClient, GetWallpapersRequest, Paths, PickleJar,
+    Resolution, Resolutions, Tag, TagId, Wallpaper, WallpaperId, WallpaperType,
+};
+use rusqlite::types::Value;
+use rusqlite::{Connection, OptionalExtension, Transaction, params, params_from_iter};
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+const SCHEMA_VERSION: i32 = 2;
+
+const SCHEMA: &str = "
+CREATE TABLE wallpapers (
+    id INTEGER PRIMARY KEY,
+    name TEXT NOT NULL,
+    content TEXT,
+    sku TEXT,
+    rating REAL,
+    timestamp INTEGER,
+    free INTEGER,
+    all_free INTEGER,
+    path_api TEXT NOT NULL,
+    path_thumb TEXT NOT NULL,
+    path_web TEXT NOT NULL,
+    pickle_jar_parent TEXT,
+    has_comments INTEGER NOT NULL,
+    has_resolutions INTEGER NOT NULL,
+    has_tags INTEGER NOT NULL
+);
+CREATE INDEX wallpapers_timestamp ON wallpapers (timestamp);
+
+CREATE TABLE tags (
+    id INTEGER PRIMARY KEY,
+    name TEXT NOT NULL
+);
+
+CREATE TABLE wallpaper_tags (
+    wallpaper_id INTEGER NOT NULL REFERENCES wallpapers (id) ON DELETE CASCADE,
+    tag_key TEXT NOT NULL,
+    tag_id INTEGER NOT NULL REFERENCES tags (id),
+    PRIMARY KEY (wallpaper_id, tag_key)
+);
+CREATE INDEX wallpaper_tags_tag_id ON wallpaper_tags (tag_id);
+
+CREATE TABLE resolutions (
+    wallpaper_id INTEGER NOT NULL REFERENCES wallpapers (id) ON DELETE CASCADE,
+    type TEXT NOT NULL,
+    position INTEGER NOT NULL,
+    label TEXT NOT NULL,
+    width INTEGER NOT NULL,
+    height INTEGER NOT NULL,
+    image TEXT NOT NULL,
+    PRIMARY KEY (wallpaper_id, type, position)
+);
+CREATE INDEX resolutions_size ON resolutions (width, height);
+
+CREATE TABLE pickle_jar_siblings (
+    wallpaper_id INTEGER NOT NULL REFERENCES wallpapers (id) ON DELETE CASCADE,
+    position INTEGER NOT NULL,
+    sibling TEXT NOT NULL,
+    PRIMARY KEY (wallpaper_id, position)
+);
+
+CREATE VIRTUAL TABLE wallpapers_fts USING fts5 (
+    name, content, content = 'wallpapers', content_rowid = 'id'
+);
+CREATE TRIGGER wallpapers_fts_insert AFTER INSERT ON wallpapers BEGIN
+    INSERT INTO wallpapers_fts (rowid, name, content) VALUES (new.id, new.name, new.content);
+END;
+CREATE TRIGGER wallpapers_fts_delete AFTER DELETE ON wallpapers BEGIN
+    INSERT INTO wallpapers_fts (wallpapers_fts, rowid, name, content)
+    VALUES ('delete', old.id, old.name, old.content);
+END;
+CREATE TRIGGER wallpapers_fts_update AFTER UPDATE ON wallpapers BEGIN
+    INSERT INTO wallpapers_fts (wallpapers_fts, rowid, name, content)
+    VALUES ('delete', old.id, old.name, old.content);
+    INSERT INTO wallpapers_fts (rowid, name, content) VALUES (new.id, new.name, new.content);
+END;
+";
+
+/// Comments are keyed by their position on the wallpaper rather than their ID, so a comment ID the
+/// API repeats, on the same wallpaper or another, is stored like any other comment. `row_id` gives
+/// the full-text index a stable rowid.
+const COMMENTS_SCHEMA: &str = "
+CREATE TABLE comments (
+    row_id INTEGER PRIMARY KEY,
+    wallpaper_id INTEGER NOT NULL REFERENCES wallpapers (id) ON DELETE CASCADE,
+    position INTEGER NOT NULL,
+    id INTEGER NOT NULL,
+    author_id TEXT NOT NULL,
+    author_display TEXT NOT NULL,
+    content TEXT NOT NULL,
+    rating REAL NOT NULL,
+    timestamp INTEGER NOT NULL,
+    UNIQUE (wallpaper_id, position)
+);
+
+CREATE VIRTUAL TABLE comments_fts USING fts5 (
+    content, content = 'comments', content_rowid = 'row_id'
+);
+CREATE TRIGGER comments_fts_insert AFTER INSERT ON comments BEGIN
+    INSERT INTO comments_fts (rowid, content) VALUES (new.row_id, new.content);
+END;
+CREATE TRIGGER comments_fts_delete AFTER DELETE ON comments BEGIN
+    INSERT INTO comments_fts (comments_fts, rowid, content)
+    VALUES ('delete', old.row_id, old.content);
+END;
+CREATE TRIGGER comments_fts_update AFTER UPDATE ON comments BEGIN
+    INSERT INTO comments_fts (comments_fts, rowid, content)
+    VALUES ('delete', old.row_id, old.content);
+    INSERT INTO comments_fts (rowid, content) VALUES (new.row_id, new.content);
+END;
+";
+
+/// Version 1 keyed comments by their ID alone.
+const MIGRATE_COMMENTS_FROM_V1: &str = "
+DROP TRIGGER comments_fts_insert;
+DROP TRIGGER comments_fts_delete;
+DROP TRIGGER comments_fts_update;
+DROP TABLE comments_fts;
+ALTER TABLE comments RENAME TO comments_v1;
+DROP INDEX comments_wallpaper_id;
+";
+
+const RESOLUTION_TYPES: [WallpaperType; 4] = [
+    WallpaperType::Single,
+    WallpaperType::Dual,
+    WallpaperType::Triple,
+    WallpaperType::Mobile,
+];
+
+/// A local SQLite copy of the catalog that can be queried without the API.
+///
+/// Ingesting a wallpaper replaces what the index knows about it, except that comments,
+/// resolutions, tags and the pickle jar are kept when the new record does not include them, so
+/// pages fetched without `show_comments` and friends do not erase earlier detail.
+#[derive(Debug)]
+pub struct CatalogIndex {
+    connection: Connection,
+}
+
+impl CatalogIndex {
+    /// Opens the index at `path`, creating it if needed.
+    pub fn open(path: impl AsRef<Path>) -> Result<CatalogIndex, ClientError> {
+        Self::initialise(Connection::open(path)?)
+    }
+
+    pub fn open_in_memory() -> Result<CatalogIndex, ClientError> {
+        Self::initialise(Connection::open_in_memory()?)
+    }
+
+    fn initialise(connection: Connection) -> Result<CatalogIndex, ClientError> {
+        connection.pragma_update(None, "foreign_keys", true)?;
+        let version: i32 = connection.pragma_query_value(None, "user_version", |row| row.get(0))?;
+        if version < SCHEMA_VERSION {
+            let transaction = connection.unchecked_transaction()?;
+            if version == 0 {
+                transaction.execute_batch(SCHEMA)?;
+                transaction.execute_batch(COMMENTS_SCHEMA)?;
+            } else {
+                transaction.execute_batch(MIGRATE_COMMENTS_FROM_V1)?;
+                transaction.execute_batch(COMMENTS_SCHEMA)?;
+                transaction.execute_batch(
+                    "INSERT INTO comments (
+                        wallpaper_id, position, id, author_id, author_display, content, rating,
+                        timestamp
+                    )
+                    SELECT wallpaper_id, position, id, author_id, author_display, content, rating,
+                        timestamp
+                    FROM comments_v1;
+                    DROP TABLE comments_v1;",
+                )?;
+            }
+            transaction.pragma_update(None, "user_version", SCHEMA_VERSION)?;
+            transaction.commit()?;
+        }
+        Ok(CatalogIndex { connection })
+    }
+
+    /// Adds or updates `wallpapers` in a single transaction, returning how many were ingested.
+    pub fn ingest<'a>(
+        &mut self,
+        wallpapers: impl IntoIterator<Item = &'a Wallpaper>,
+    ) -> Result<usize, ClientError> {
+        let transaction = self.connection.transaction()?;
+        let mut ingested = 0;
+        for wallpaper in wallpapers {
+            Self::ingest_wallpaper(&transaction, wallpaper)?;
+            ingested += 1;
+        }
+        transaction.commit()?;
+        Ok(ingested)
+    }
+
+    fn ingest_wallpaper(
+        transaction: &Transaction,
+        wallpaper: &Wallpaper,
+    ) -> Result<(), rusqlite::Error> {
+        let id = wallpaper.id.get();
+        transaction.execute(
+            "INSERT INTO wallpapers (
+                id, name, content, sku, rating, timestamp, free, all_free, path_api, path_thumb,
+                path_web, pickle_jar_parent, has_comments, has_resolutions, has_tags
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15)
+            ON CONFLICT (id) DO UPDATE SET
+                name = excluded.name,
+                content = excluded.content,
+                sku = excluded.sku,
+                rating = excluded.rating,
+                timestamp = excluded.timestamp,
+                free = excluded.free,
+                all_free = excluded.all_free,
+                path_api = excluded.path_api,
+                path_thumb = excluded.path_thumb,
+                path_web = excluded.path_web,
+                pickle_jar_parent = coalesce(excluded.pickle_jar_parent, pickle_jar_parent),
+                has_comments = max(has_comments, excluded.has_comments),
+                has_resolutions = max(has_resolutions, excluded.has_resolutions),
+                has_tags = max(has_tags, excluded.has_tags)",
+            params![
+                id,
+                wallpaper.name,
+                wallpaper.content,
+                wallpaper.sku,
+                wallpaper.rating,
+                wallpaper.timestamp,
+                wallpaper.free,
+                wallpaper.all_free,
+                wallpaper.paths.api,
+                wallpaper.paths.thumb,
+                wallpaper.paths.web,
+                wallpaper
+                    .pickle_jar
+                    .as_ref()
+                    .map(|pickle_jar| &pickle_jar.parent),
+                wallpaper.comments.is_some(),
+                wallpaper.resolutions.is_some(),
+                wallpaper.tags.is_some(),
+            ],
+        )?;
+
+        if let Some(comments) = &wallpaper.comments {
+            transaction.execute("DELETE FROM comments WHERE wallpaper_id = ?1", [id])?;
+            let mut insert = transaction.prepare_cached(
+                "INSERT INTO comments (
+                    id, wallpaper_id, position, author_id, author_display, content, rating,
+                    timestamp
+                ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+            )?;
+            for (position, comment) in comments.comments.iter().enumerate() {
+                let comment_id = i64::try_from(comment.id)
+                    .map_err(|error| rusqlite::Error::ToSqlConversionFailure(Box::new(error)))?;
+                insert.execute(params![
+                    comment_id,
+                    id,
+                    position as i64,
+                    comment.author_id,
+                    comment.author_display,
+                    comment.content,
+                    comment.rating,
+                    comment.timestamp,
+                ])?;
+            }
+        }
+
+        if let Some(resolutions) = &wallpaper.resolutions {
+            transaction.execute("DELETE FROM resolutions WHERE wallpaper_id = ?1", [id])?;
+            let mut insert = transaction.prepare_cached(
+                "INSERT INTO resolutions (
+                    wallpaper_id, type, position, label, width, height, image
+                ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+            )?;
+            for wallpaper_type in &RESOLUTION_TYPES {
+                for (position, resolution) in
+                    resolutions.for_type(wallpaper_type).iter().enumerate()
+                {
+                    insert.execute(params![
+                        id,
+                        wallpaper_type.as_str(),
+                        position as i64,
+                        resolution.label,
+                        resolution.width,
+                        resolution.height,
+                        resolution.image,
+                    ])?;
+                }
+            }
+        }
+
+        if let Some(tags) = &wallpaper.tags {
+            transaction.execute("DELETE FROM wallpaper_tags WHERE wallpaper_id = ?1", [id])?;
+            let mut insert_tag = transaction.prepare_cached(
+                "INSERT INTO tags (id, name) VALUES (?1, ?2)
+                ON CONFLICT (id) DO UPDATE SET name = excluded.name",
+            )?;
+            let mut insert_wallpaper_tag = transaction.prepare_cached(
+                "INSERT INTO wallpaper_tags (wallpaper_id, tag_key, tag_id) VALUES (?1, ?2, ?3)",
+            )?;
+            for (tag_key, tag) in tags {
+                insert_tag.execute(params![tag.id.get(), tag.name])?;
+                insert_wallpaper_tag.execute(params![id, tag_key, tag.id.get()])?;
+            }
+        }
+
+        if let Some(pickle_jar) = &wallpaper.pickle_jar {
+            transaction.execute(
+                "DELETE FROM pickle_jar_siblings WHERE wallpaper_id = ?1",
+                [id],
+            )?;
+            let mut insert = transaction.prepare_cached(
+                "INSERT INTO pickle_jar_siblings (wallpaper_id, position, sibling)
+                VALUES (?1, ?2, ?3)",
+            )?;
+            for (position, sibling) in pickle_jar.siblings.iter().enumerate() {
+                insert.execute(params![id, position as i64, sibling])?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Removes `id` and everything stored with it.
+    pub fn remove(&mut self, id: impl Into<WallpaperId>) -> Result<bool, ClientError> {
+        let removed = self
+            .connection
+            .execute("DELETE FROM wallpapers WHERE id = ?1", [id.into().get()])?;
+        Ok(removed > 0)
+    }
+
+    pub fn len(&self) -> Result<usize, ClientError> {
+        let len: i64 = self
+            .connection
+            .query_row("SELECT count(*) FROM wallpapers", [], |row| row.get(0))?;
+        Ok(len as usize)
+    }
+
+    pub fn is_empty(&self) -> Result<bool, ClientError> {
+        Ok(self.len()? == 0)
+    }
+
+    pub fn get(&self, id: impl Into<WallpaperId>) -> Result<Option<Wallpaper>, ClientError> {
+        Ok(self.load(id.into())?)
+    }
+
+    /// Returns the wallpapers matching every condition in `query`, newest first.
+    pub fn search(&self, query: &IndexQuery) -> Result<Vec<Wallpaper>, ClientError> {
+        let (sql, parameters) = query.to_sql();
+        let mut statement = self.connection.prepare(&sql)?;
+        let ids = statement
+            .query_map(params_from_iter(parameters), |row| row.get::<_, u32>(0))?
+            .collect::<Result<Vec<u32>, _>>()?;
+        let mut wallpapers = Vec::with_capacity(ids.len());
+        for id in ids {
+            if let Some(wallpaper) = self.load(WallpaperId(id))? {
+                wallpapers.push(wallpaper);
+            }
+        }
+        Ok(wallpapers)
+    }
+
+    fn load(&self, id: WallpaperId) -> Result<Option<Wallpaper>, rusqlite::Error> {
+        let wallpaper = self
+            .connection
+            .query_row(
+                "SELECT name, content, sku, rating, timestamp, free, all_free, path_api,
+                    path_thumb, path_web, pickle_jar_parent, has_comments, has_resolutions,
+                    has_tags
+                FROM wallpapers WHERE id = ?1",
+                [id.get()],
+                |row| {
+                    Ok((
+                        Wallpaper {
+                            id,
+                            all_free: row.get(6)?,
+                            comments: None,
+                            content: row.get(1)?,
+                            free: row.get(5)?,
+                            name: row.get(0)?,
+                            paths: Paths {
+                                api: row.get(7)?,
+                                thumb: row.get(8)?,
+                                web: row.get(9)?,
+                            },
+                            pickle_jar: None,
+                            rating: row.get(3)?,
+                            resolutions: None,
+                            sku: row.get(2)?,
+                            tags: None,
+                            timestamp: row.get(4)?,
+                        },
+                        row.get::<_, Option<String>>(10)?,
+                        row.get::<_, bool>(11)?,
+                        row.get::<_, bool>(12)?,
+                        row.get::<_, bool>(13)?,
+                    ))
+                },
+            )
+            .optional()?;
+        let Some((mut wallpaper, pickle_jar_parent, has_comments, has_resolutions, has_tags)) =
+            wallpaper
+        else {
+            return Ok(None);
+        };
+
+        if has_comments {
+            wallpaper.comments = Some(Comments {
+                comments: self.load_comments(id)?,
+            });
+        }
+        if has_resolutions {
+            wallpaper.resolutions = Some(self.load_resolutions(id)?);
+        }
+        if has_tags {
+            wallpaper.tags = Some(self.load_tags(id)?);
+        }
+        if let Some(parent) = pickle_jar_parent {
+            let mut statement = self.connection.prepare_cached(
+                "SELECT sibling FROM pickle_jar_siblings WHERE wallpaper_id = ?1
+                ORDER BY position",
+            )?;
+            let siblings = statement
+                .query_map([id.get()], |row| row.get(0))?
+                .collect::<Result<_, _>>()?;
+            wallpaper.pickle_jar = Some(PickleJar { parent, siblings });
+        }
+        Ok(Some(wallpaper))
+    }
+
+    fn load_comments(&self, id: WallpaperId) -> Result<Vec<Comment>, rusqlite::Error> {
+        let mut statement = self.connection.prepare_cached(
+            "SELECT id, author_id, author_display, content, rating, timestamp
+            FROM comments WHERE wallpaper_id = ?1 ORDER BY position",
+        )?;
+        statement
+            .query_map([id.get()], |row| {
+                Ok(Comment {
+                    id: row.get::<_, i64>(0)? as u64,
+                    author_id: row.get(1)?,
+                    author_display: row.get(2)?,
+                    content: row.get(3)?,
+                    rating: row.get(4)?,
+                    timestamp: row.get(5)?,
+                })
+            })?
+            .collect()
+    }
+
+    /// Dual, triple and mobile come back as `None` when there are no resolutions of that type.
+    fn load_resolutions(&self, id: WallpaperId) -> Result<Resolutions, rusqlite::Error> {
+        let mut statement = self.connection.prepare_cached(
+            "SELECT type, label, width, height, image
+            FROM resolutions WHERE wallpaper_id = ?1 ORDER BY type, position",
+        )?;
+        let mut by_type: HashMap<String, Vec<Resolution>> = HashMap::new();
+        let rows = statement.query_map([id.get()], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                Resolution {
+                    label: row.get(1)?,
+                    width: row.get(2)?,
+                    height: row.get(3)?,
+                    image: row.get(4)?,
+                },
+            ))
+        })?;
+        for row in rows {
+            let (wallpaper_type, resolution) = row?;
+            by_type.entry(wallpaper_type).or_default().push(resolution);
+        }
+        let mut take = |wallpaper_type: WallpaperType| by_type.remove(wallpaper_type.as_str());
+        Ok(Resolutions {
+            single: take(WallpaperType::Single).unwrap_or_default(),
+            dual: take(WallpaperType::Dual),
+            triple: take(WallpaperType::Triple),
+            mobile: take(WallpaperType::Mobile),
+        })
+    }
+
+    fn load_tags(&self, id: WallpaperId) -> Result<HashMap<String, Tag>, rusqlite::Error> {
+        let mut statement = self.connection.prepare_cached(
+            "SELECT wallpaper_tags.tag_key, tags.id, tags.name
+            FROM wallpaper_tags JOIN tags ON tags.id = wallpaper_tags.tag_id
+            WHERE wallpaper_tags.wallpaper_id = ?1",
+        )?;
+        statement
+            .query_map([id.get()], |row| {
+                Ok((
+                    row.get(0)?,
+                    Tag {
+                        id: TagId(row.get(1)?),
+                        name: row.get(2)?,
+                    },
+                ))
+            })?
+            .collect()
+    }
+}
+
+/// Conditions for [`CatalogIndex::search`]. Every condition that is set must match.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct IndexQuery {
+    text: Option<String>,
+    comment_text: Option<String>,
+    with_tags: Vec<TagId>,
+    without_tags: Vec<TagId>,
+    wallpaper_types: Vec<WallpaperType>,
+    min_size: Option<(u32, u32)>,
+    max_size: Option<(u32, u32)>,
+    free: Option<bool>,
+    min_rating: Option<f64>,
+    released_after: Option<i64>,
+    released_before: Option<i64>,
+    limit: Option<usize>,
+}
+
+impl IndexQuery {
+    /// Matches every wallpaper.
+    pub fn new() -> Self {
+        IndexQuery::default()
+    }
+
+    /// An FTS5 query, such as `"blue moon" OR forest*`, over the name and content.
+    pub fn text(mut self, text: impl Into<String>) -> Self {
+        self.text = Some(text.into());
+        self
+    }
+
+    /// An FTS5 query over the wallpaper's comments.
+    pub fn comment_text(mut self, comment_text: impl Into<String>) -> Self {
+        self.comment_text = Some(comment_text.into());
+        self
+    }
+
+    /// Requires every one of `tags`.
+    pub fn with_tags(mut self, tags: impl IntoIterator<Item = impl Into<TagId>>) -> Self {
+        self.with_tags.extend(tags.into_iter().map(Into::into));
+        self
+    }
+
+    /// Excludes wallpapers with any of `tags`.
+    pub fn without_tags(mut self, tags: impl IntoIterator<Item = impl Into<TagId>>) -> Self {
+        self.without_tags.extend(tags.into_iter().map(Into::into));
+        self
+    }
+
+    /// Restricts the size conditions to resolutions of these types. Without any, a resolution of
+    /// any type can match.
+    pub fn wallpaper_types(
+        mut self,
+        wallpaper_types: impl IntoIterator<Item = WallpaperType>,
+    ) -> Self {
+        self.wallpaper_types.extend(wallpaper_types);
+        self
+    }
+
+    /// Requires a resolution at least `width` by `height`.
+    pub fn min_size(mut self, width: u32, height: u32) -> Self {
+        self.min_size = Some((width, height));
+        self
+    }
+
+    /// Requires a resolution at most `width` by `height`. Combined with [`Self::min_size`], the
+    /// same resolution must satisfy both.
+    pub fn max_size(mut self, width: u32, height: u32) -> Self {
+        self.max_size = Some((width, height));
+        self
+    }
+
+    pub fn free(mut self, free: bool) -> Self {
+        self.free = Some(free);
+        self
+    }
+
+    pub fn min_rating(mut self, min_rating: f64) -> Self {
+        self.min_rating = Some(min_rating);
+        self
+    }
+
+    /// Requires a `timestamp` at or after `timestamp`.
+    pub fn released_after(mut self, timestamp: i64) -> Self {
+        self.released_after = Some(timestamp);
+        self
+    }
+
+    /// Requires a `timestamp` before `timestamp`.
+    pub fn released_before(mut self, timestamp: i64) -> Self {
+        self.released_before = Some(timestamp);
+        self
+    }
+
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    fn to_sql(&self) -> (String, Vec<Value>) {
+        let mut sql = "SELECT wallpapers.id FROM wallpapers WHERE 1 = 1".to_string();
+        let mut parameters = vec![];
+
+        if let Some(text) = &self.text {
+            sql.push_str(
+                " AND wallpapers.id IN
+                (SELECT rowid FROM wallpapers_fts WHERE wallpapers_fts MATCH ?)",
+            );
+            parameters.push(Value::Text(text.clone()));
+        }
+        if let Some(comment_text) = &self.comment_text {
+            sql.push_str(
+                " AND wallpapers.id IN (SELECT comments.wallpaper_id FROM comments
+                JOIN comments_fts ON comments_fts.rowid = comments.row_id
+                WHERE comments_fts MATCH ?)",
+            );
+            parameters.push(Value::Text(comment_text.clone()));
+        }
+        for tag in &self.with_tags {
+            sql.push_str(
+                " AND EXISTS (SELECT 1 FROM wallpaper_tags
+                WHERE wallpaper_tags.wallpaper_id = wallpapers.id AND wallpaper_tags.tag_id = ?)",
+            );
+            parameters.push(Value::Integer(tag.get().into()));
+        }
+        if !self.without_tags.is_empty() {
+            sql.push_str(&format!(
+                " AND NOT EXISTS (SELECT 1 FROM wallpaper_tags
+                WHERE wallpaper_tags.wallpaper_id = wallpapers.id AND wallpaper_tags.tag_id IN ({}))",
+                placeholders(self.without_tags.len())
+            ));
+            parameters.extend(
+                self.without_tags
+                    .iter()
+                    .map(|tag| Value::Integer(tag.get().into())),
+            );
+        }
+        if !self.wallpaper_types.is_empty() || self.min_size.is_some() || self.max_size.is_some() {
+            sql.push_str(
+                " AND EXISTS (SELECT 1 FROM resolutions WHERE resolutions.wallpaper_id = wallpapers.id",
+            );
+            if !self.wallpaper_types.is_empty() {
+                sql.push_str(&format!(
+                    " AND resolutions.type IN ({})",
+                    placeholders(self.wallpaper_types.len())
+                ));
+                parameters.extend(
+                    self.wallpaper_types
+                        .iter()
+                        .map(|wallpaper_type| Value::Text(wallpaper_type.as_str().to_string())),
+                );
+            }
+            if let Some((width, height)) = self.min_size {
+                sql.push_str(" AND resolutions.width >= ? AND resolutions.height >= ?");
+                parameters.push(Value::Integer(width.into()));
+                parameters.push(Value::Integer(height.into()));
+            }
+            if let Some((width, height)) = self.max_size {
+                sql.push_str(" AND resolutions.width <= ? AND resolutions.height <= ?");
+                parameters.push(Value::Integer(width.into()));
+                parameters.push(Value::Integer(height.into()));
+            }
+            sql.push(')');
+        }
+        if let Some(free) = self.free {
+            sql.push_str(" AND wallpapers.free = ?");
+            parameters.push(Value::Integer(free.into()));
+        }
+        if let Some(min_rating) = self.min_rating {
+            sql.push_str(" AND wallpapers.rating >= ?");
+            parameters.push(Value::Real(min_rating));
+        }
+        if let Some(released_after) = self.released_after {
+            sql.push_str(" AND wallpapers.timestamp >= ?");
+            parameters.push(Value::Integer(released_after));
+        }
+        if let Some(released_before) = self.released_before {
+            sql.push_str(" AND wallpapers.timestamp < ?");
+            parameters.push(Value::Integer(released_before));
+        }
+
+        sql.push_str(" ORDER BY wallpapers.timestamp DESC, wallpapers.id DESC");
+        if let Some(limit) = self.limit {
+            sql.push_str(" LIMIT ?");
+            parameters.push(Value::Integer(limit.try_into().unwrap_or(i64::MAX)));
+        }
+        (sql, parameters)
+    }
+}
+
+fn placeholders(count: usize) -> String {
+    vec!["?"; count].join(", ")
+}
+
+impl DigitalBlasphemyClient {
+    /// Ingests every wallpaper matching `request` into `index`, starting at `request.page`, and
+    /// returns how many were ingested. Each page is ingested in its own transaction on a blocking
+    /// thread as soon as it arrives, so pages before a failed one stay indexed. Set
+    /// `show_comments`, `show_pickle_jar` and `show_resolutions` on `request` to index those
+    /// details too.
+    pub async fn index_wallpapers(
+        &self,
+        index: &Arc<Mutex<CatalogIndex>>,
+        request: &GetWallpapersRequest,
+    ) -> Result<usize, ClientError> {
+        let mut request = request.clone();
+        let mut ingested = 0;
+        loop {
+            let response = self.get_wallpapers(&request).await?;
+            let total_pages = response.db_core.total_pages;
+            let wallpapers = response.into_wallpapers();
+            let index = Arc::clone(index);
+            ingested += tokio::task::spawn_blocking(move || {
+                index
+                    .lock()
+                    .unwrap_or_else(|error| error.into_inner())
+                    .ingest(&wallpapers)
+            })
+            .await
+            .map_err(|error| ClientError::Index(Box::new(error)))??;
+            if request.page >= total_pages {
+                return Ok(ingested);
+            }
+            request.page += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::GetWallpapersResponse;
+    use mockito::Matcher;
+    use std::error::Error;
+    use std::fs;
+
+    fn wallpapers() -> Vec<Wallpaper> {
+        let response: GetWallpapersResponse = serde_json::from_str(
+            &fs::read_to_string("resources/get_wallpapers_success_fully_populated.json").unwrap(),
+        )
+        .unwrap();
+        response.into_wallpapers()
+    }
+
+    fn index() -> CatalogIndex {
+        let mut index = CatalogIndex::open_in_memory().unwrap();
+        index.ingest(&wallpapers()).unwrap();
+        index
+    }
+
+    fn ids(wallpapers: Vec<Wallpaper>) -> Vec<u32> {
+        wallpapers
+            .iter()
+            .map(|wallpaper| wallpaper.id.get())
+            .collect()
+    }
+
+    mod catalog_index_test {
+        use super::*;
+
+        #[test]
+        fn test_catalog_index_round_trips_wallpapers() {
+            let index = index();
+
+            for wallpaper in wallpapers() {
+                assert_eq!(index.get(wallpaper.id).unwrap(), Some(wallpaper));
+            }
+            assert_eq!(index.len().unwrap(), 2);
+        }
+
+        #[test]
+        fn test_catalog_index_get_returns_none_for_unknown_wallpaper() {
+            assert_eq!(index().get(1).unwrap(), None);
+        }
+
+        #[test]
+        fn test_catalog_index_ingest_keeps_details_missing_from_newer_records() {
+            let mut index = index();
+            let mut wallpaper = wallpapers().remove(0);
+            let original = wallpaper.clone();
+            wallpaper.name = "Renamed".to_string();
+            wallpaper.comments = None;
+            wallpaper.resolutions = None;
+            wallpaper.tags = None;
+            wallpaper.pickle_jar = None;
+
+            index.ingest([&wallpaper]).unwrap();
+
+            assert_eq!(
+                index.get(wallpaper.id).unwrap(),
+                Some(Wallpaper {
+                    name: "Renamed".to_string(),
+                    ..original
+                })
+            );
+        }
+
+        #[test]
+        fn test_catalog_index_ingest_replaces_included_details() {
+            let mut index = index();
+            let mut wallpaper = wallpapers().remove(0);
+            wallpaper.comments = Some(Comments { comments: vec![] });
+            wallpaper.tags = Some(HashMap::from([(
+                "99".to_string(),
+                Tag {
+                    id: TagId(99),
+                    name: "Tag 99".to_string(),
+                },
+            )]));
+
+            index.ingest([&wallpaper]).unwrap();
+
+            assert_eq!(index.get(wallpaper.id).unwrap(), Some(wallpaper));
+        }
+
+        #[test]
+        fn test_catalog_index_ingest_stores_repeated_comment_ids() {
+            let mut index = CatalogIndex::open_in_memory().unwrap();
+            let mut wallpapers = wallpapers();
+            let comment = wallpapers[0].comments.as_ref().unwrap().comments[0].clone();
+            wallpapers[0]
+                .comments
+                .as_mut()
+                .unwrap()
+                .comments
+                .push(comment.clone());
+            wallpapers[1]
+                .comments
+                .get_or_insert(Comments { comments: vec![] })
+                .comments
+                .push(comment);
+
+            assert_eq!(index.ingest(&wallpapers).unwrap(), 2);
+
+            for wallpaper in wallpapers {
+                assert_eq!(index.get(wallpaper.id).unwrap(), Some(wallpaper));
+            }
+        }
+
+        #[test]
+        fn test_catalog_index_ingest_rejects_comment_id_out_of_range() {
+            let mut index = index();
+            let mut wallpaper = wallpapers().remove(0);
+            let original = wallpaper.clone();
+            wallpaper.name = "Renamed".to_string();
+            wallpaper.comments.as_mut().unwrap().comments[0].id = u64::MAX;
+
+            assert!(matches!(
+                index.ingest([&wallpaper]),
+                Err(ClientError::Index(_))
+            ));
+            assert_eq!(index.get(wallpaper.id).unwrap(), Some(original));
+        }
+
+        #[test]
+        fn test_catalog_index_open_migrates_comments_from_version_1() -> Result<(), Box<dyn Error>>
+        {
+            let directory = tempfile::tempdir()?;
+            let path = directory.path().join("catalog.sqlite");
+            let connection = Connection::open(&path)?;
+            connection.execute_batch(SCHEMA)?;
+            connection.execute_batch(
+                "CREATE TABLE comments (
+                    id INTEGER PRIMARY KEY,
+                    wallpaper_id INTEGER NOT NULL REFERENCES wallpapers (id) ON DELETE CASCADE,
+                    position INTEGER NOT NULL,
+                    author_id TEXT NOT NULL,
+                    author_display TEXT NOT NULL,
+                    content TEXT NOT NULL,
+                    rating REAL NOT NULL,
+                    timestamp INTEGER NOT NULL
+                );
+                CREATE INDEX comments_wallpaper_id ON comments (wallpaper_id);
+                CREATE VIRTUAL TABLE comments_fts USING fts5 (
+                    content, content = 'comments', content_rowid = 'id'
+                );
+                CREATE TRIGGER comments_fts_insert AFTER INSERT ON comments BEGIN
+                    INSERT INTO comments_fts (rowid, content) VALUES (new.id, new.content);
+                END;
+                CREATE TRIGGER comments_fts_delete AFTER DELETE ON comments BEGIN
+                    INSERT INTO comments_fts (comments_fts, rowid, content)
+                    VALUES ('delete', old.id, old.content);
+                END;
+                CREATE TRIGGER comments_fts_update AFTER UPDATE ON comments BEGIN
+                    INSERT INTO comments_fts (comments_fts, rowid, content)
+                    VALUES ('delete', old.id, old.content);
+                    INSERT INTO comments_fts (rowid, content) VALUES (new.id, new.content);
+                END;
+                INSERT INTO wallpapers (
+                    id, name, path_api, path_thumb, path_web, has_comments, has_resolutions,
+                    has_tags
+                ) VALUES (7, 'Legacy', '/api', '/thumb', '/web', 1, 0, 0);
+                INSERT INTO comments (
+                    id, wallpaper_id, position, author_id, author_display, content, rating,
+                    timestamp
+                ) VALUES (3, 7, 0, 'a', 'Author', 'Legacy comment', 5, 0);
+                PRAGMA user_version = 1;",
+            )?;
+            drop(connection);
+
+            let index = CatalogIndex::open(&path)?;
+
+            assert_eq!(
+                ids(index.search(&IndexQuery::new().comment_text("Legacy"))?),
+                vec![7]
+            );
+            assert_eq!(index.get(7)?.unwrap().comments.unwrap().comments[0].id, 3);
+
+            Ok(())
+        }
+
+        #[test]
+        fn test_catalog_index_remove() {
+            let mut index = index();
+
+            assert!(index.remove(13).unwrap());
+            assert!(!index.remove(13).unwrap());
+
+            assert_eq!(index.get(13).unwrap(), None);
+            assert_eq!(
+                ids(index.search(&IndexQuery::new().text("Vulcan")).unwrap()),
+                Vec::<u32>::new()
+            );
+            assert_eq!(index.len().unwrap(), 1);
+        }
+
+        #[test]
+        fn test_catalog_index_open_persists_to_disk() -> Result<(), Box<dyn Error>> {
+            let directory = tempfile::tempdir()?;
+            let path = directory.path().join("catalog.sqlite");
+
+            CatalogIndex::open(&path)?.ingest(&wallpapers())?;
+
+            let index = CatalogIndex::open(&path)?;
+            assert_eq!(index.len()?, 2);
+            assert!(!index.is_empty()?);
+
+            Ok(())
+        }
+    }
+
+    mod index_query_test {
+        use super::*;
+
+        #[test]
+        fn test_index_query_matches_everything_newest_first() {
+            assert_eq!(
+                ids(index().search(&IndexQuery::new()).unwrap()),
+                vec![40, 13]
+            );
+        }
+
+        #[test]
+        fn test_index_query_text_searches_name_and_content() {
+            let index = index();
+
+            assert_eq!(
+                ids(index.search(&IndexQuery::new().text("vulcan")).unwrap()),
+                vec![13]
+            );
+            assert_eq!(
+                ids(index
+                    .search(&IndexQuery::new().text("\"Content 6\""))
+                    .unwrap()),
+                vec![40]
+            );
+        }
+
+        #[test]
+        fn test_index_query_comment_text() {
+            let index = index();
+
+            assert_eq!(
+                ids(index
+                    .search(&IndexQuery::new().comment_text("\"Content 4\""))
+                    .unwrap()),
+                vec![40]
+            );
+        }
+
+        #[test]
+        fn test_index_query_comment_text_follows_reingested_comments() {
+            let mut index = index();
+            let mut wallpaper = wallpapers()
+                .into_iter()
+                .find(|wallpaper| wallpaper.id.get() == 40)
+                .unwrap();
+            for comment in &mut wallpaper.comments.as_mut().unwrap().comments {
+                comment.content = "Rewritten".to_string();
+            }
+
+            index.ingest([&wallpaper]).unwrap();
+
+            assert_eq!(
+                ids(index
+                    .search(&IndexQuery::new().comment_text("\"Content 4\""))
+                    .unwrap()),
+                Vec::<u32>::new()
+            );
+            assert_eq!(
+                ids(index
+                    .search(&IndexQuery::new().comment_text("Rewritten"))
+                    .unwrap()),
+                vec![40]
+            );
+        }
+
+        #[test]
+        fn test_index_query_with_and_without_tags() {
+            let mut index = index();
+            let mut wallpaper = wallpapers().remove(1);
+            wallpaper.tags.as_mut().unwrap().insert(
+                "37".to_string(),
+                Tag {
+                    id: TagId(37),
+                    name: "Tag 1".to_string(),
+                },
+            );
+            index.ingest([&wallpaper]).unwrap();
+
+            assert_eq!(
+                ids(index.search(&IndexQuery::new().with_tags([37])).unwrap()),
+                vec![40, 13]
+            );
+            assert_eq!(
+                ids(index
+                    .search(&IndexQuery::new().with_tags([37, 64]))
+                    .unwrap()),
+                vec![40]
+            );
+            assert_eq!(
+                ids(index
+                    .search(&IndexQuery::new().with_tags([37]).without_tags([65]))
+                    .unwrap()),
+                vec![13]
+            );
+        }
+
+        #[test]
+        fn test_index_query_size_range_across_types() {
+            let index = index();
+
+            assert_eq!(
+                ids(index.search(&IndexQuery::new().min_size(50, 50)).unwrap()),
+                vec![40]
+            );
+            assert_eq!(
+                ids(index
+                    .search(&IndexQuery::new().min_size(25, 25).max_size(30, 30))
+                    .unwrap()),
+                vec![13]
+            );
+            assert_eq!(
+                ids(index
+                    .search(
+                        &IndexQuery::new()
+                            .wallpaper_types([WallpaperType::Single])
+                            .min_size(25, 25)
+                            .max_size(30, 30)
+                    )
+                    .unwrap()),
+                Vec::<u32>::new()
+            );
+            assert_eq!(
+                ids(index
+                    .search(
+                        &IndexQuery::new()
+                            .wallpaper_types([WallpaperType::Mobile, WallpaperType::Dual])
+                    )
+                    .unwrap()),
+                vec![40, 13]
+            );
+        }
+
+        #[test]
+        fn test_index_query_scalar_conditions() {
+            let index = index();
+
+            assert_eq!(
+                ids(index.search(&IndexQuery::new().free(true)).unwrap()),
+                vec![13]
+            );
+            assert_eq!(
+                ids(index.search(&IndexQuery::new().min_rating(21.0)).unwrap()),
+                vec![40]
+            );
+            assert_eq!(
+                ids(index.search(&IndexQuery::new().released_after(40)).unwrap()),
+                vec![40]
+            );
+            assert_eq!(
+                ids(index
+                    .search(&IndexQuery::new().released_before(40))
+                    .unwrap()),
+                vec![13]
+            );
+            assert_eq!(
+                ids(index.search(&IndexQuery::new().limit(1)).unwrap()),
+                vec![40]
+            );
+        }
+
+        #[test]
+        fn test_index_query_rejects_invalid_text_query() {
+            assert!(matches!(
+                index().search(&IndexQuery::new().text("\"unterminated")),
+                Err(ClientError::Index(_))
+            ));
+        }
+    }
+
+    mod index_wallpapers_test {
+        use super::*;
+
+        #[tokio::test]
+        async fn index_wallpapers_ingests_every_page() -> Result<(), Box<dyn Error>> {
+            let mut server = mockito::Server::new_async().await;
+            let mut mocks = vec![];
+            for page in 1..=3 {
+                mocks.push(
+                    server
+                        .mock(
+                            "GET",
+                            Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
+                        )
+                        .match_query(Matcher::UrlEncoded("page".to_string(), page.to_string()))
+                        .with_status(200)
+                        .with_header("content-type", "application/json")
+                        .with_body(fs::read_to_string(format!(
+                            "resources/get_wallpapers_success_page_{page}_of_3.json"
+                        ))?)
+                        .create_async()
+                        .await,
+                );
+            }
+
+            let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
+            let index = Arc::new(Mutex::new(CatalogIndex::open_in_memory()?));
+
+            let ingested = client
+                .index_wallpapers(&index, &GetWallpapersRequest::builder().build())
+                .await?;
+
+            assert_eq!(ingested, 5);
+            assert_eq!(index.lock().unwrap().len()?, 5);
+
+            for mock in mocks {
+                mock.assert_async().await;
+            }
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn index_wallpapers_keeps_pages_before_a_failed_page() -> Result<(), Box<dyn Error>> {
+            let mut server = mockito::Server::new_async().await;
+            let first_page_mock = server
+                .mock(
+                    "GET",
+                    Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
+                )
+                .match_query(Matcher::UrlEncoded("page".to_string(), "1".to_string()))
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(fs::read_to_string(
+                    "resources/get_wallpapers_success_page_1_of_3.json",
+                )?)
+                .create_async()
+                .await;
+            let failed_page_mock = server
+                .mock(
+                    "GET",
+                    Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
+                )
+                .match_query(Matcher::UrlEncoded("page".to_string(), "2".to_string()))
+                .with_status(401)
+                .create_async()
+                .await;
+
+            let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
+            let index = Arc::new(Mutex::new(CatalogIndex::open_in_memory()?));
+
+            let result = client
+                .index_wallpapers(&index, &GetWallpapersRequest::builder().build())
+                .await;
+
+            assert!(matches!(result, Err(ClientError::Unauthorized(_))));
+            assert!(!index.lock().unwrap().is_empty()?);
+
+            first_page_mock.assert_async().await;
+            failed_page_mock.assert_async().await;
+
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,389 @@
+use crate::{DownloadWallpaperRequest, FitPolicy, Resolution, Wallpaper, WallpaperType};
+
+/// The largest fraction of a spanning image's width or height that may be cropped away to cover
+/// the monitors before falling back to one single image per monitor.
+const MAX_SPAN_CROP: f64 = 0.1;
+
+/// A monitor's position and size in desktop coordinates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Monitor {
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+}
+
+/// A rectangle in the pixel coordinates of a downloaded image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Crop {
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MonitorPlan {
+    pub monitor: Monitor,
+    /// Index into [`LayoutPlan::downloads`] of the image shown on this monitor.
+    pub download: usize,
+    /// The part of that image to scale onto this monitor.
+    pub crop: Crop,
+}
+
+#[derive(Debug, Clone)]
+pub struct LayoutPlan {
+    /// Whether one Dual or Triple image spans every monitor, rather than one Single per monitor.
+    pub spanning: bool,
+    pub downloads: Vec<DownloadWallpaperRequest>,
+    /// One entry per monitor, in the order the monitors were given.
+    pub monitors: Vec<MonitorPlan>,
+}
+
+/// Plans how to show `wallpaper` across `monitors`. Two or three monitors side by side use a
+/// Dual or Triple image when one covers them without cropping more than 10%; otherwise each
+/// monitor gets the best fitting Single image. Returns `None` if any monitor cannot be served.
+pub fn plan_layout(wallpaper: &Wallpaper, monitors: &[Monitor]) -> Option<LayoutPlan> {
+    if monitors.is_empty() {
+        return None;
+    }
+    plan_spanning(wallpaper, monitors).or_else(|| plan_single(wallpaper, monitors))
+}
+
+fn plan_spanning(wallpaper: &Wallpaper, monitors: &[Monitor]) -> Option<LayoutPlan> {
+    let wallpaper_type = match monitors.len() {
+        2 => WallpaperType::Dual,
+        3 => WallpaperType::Triple,
+        _ => return None,
+    };
+    if !side_by_side(monitors) {
+        return None;
+    }
+
+    let min_x = monitors.iter().map(|monitor| monitor.x).min()?;
+    let min_y = monitors.iter().map(|monitor| monitor.y).min()?;
+    let max_x = monitors.iter().map(right).max()?;
+    let max_y = monitors.iter().map(bottom).max()?;
+    let bounds = Monitor {
+        x: min_x,
+        y: min_y,
+        width: u32::try_from(max_x - min_x as i64).ok()?,
+        height: u32::try_from(max_y - min_y as i64).ok()?,
+    };
+
+    let (resolution, request) = best_resolution(wallpaper, &bounds, wallpaper_type)?;
+    if crop_fraction(&resolution, &bounds) > MAX_SPAN_CROP {
+        return None;
+    }
+
+    let cover = cover_crop(&resolution, &bounds);
+    let scale = cover.width as f64 / bounds.width as f64;
+    let monitors = monitors
+        .iter()
+        .map(|monitor| MonitorPlan {
+            monitor: *monitor,
+            download: 0,
+            crop: Crop {
+                x: cover.x
+                    + ((i64::from(monitor.x) - i64::from(bounds.x)) as f64 * scale).round() as u32,
+                y: cover.y
+                    + ((i64::from(monitor.y) - i64::from(bounds.y)) as f64 * scale).round() as u32,
+                width: (monitor.width as f64 * scale).round() as u32,
+                height: (monitor.height as f64 * scale).round() as u32,
+            },
+        })
+        .collect();
+    Some(LayoutPlan {
+        spanning: true,
+        downloads: vec![request],
+        monitors,
+    })
+}
+
+fn plan_single(wallpaper: &Wallpaper, monitors: &[Monitor]) -> Option<LayoutPlan> {
+    let mut downloads: Vec<DownloadWallpaperRequest> = vec![];
+    let mut plans = vec![];
+    for monitor in monitors {
+        let (resolution, request) = best_resolution(wallpaper, monitor, WallpaperType::Single)?;
+        let download = match downloads.iter().position(|download| {
+            download.width == request.width && download.height == request.height
+        }) {
+            Some(download) => download,
+            None => {
+                downloads.push(request);
+                downloads.len() - 1
+            }
+        };
+        plans.push(MonitorPlan {
+            monitor: *monitor,
+            download,
+            crop: cover_crop(&resolution, monitor),
+        });
+    }
+    Some(LayoutPlan {
+        spanning: false,
+        downloads,
+        monitors: plans,
+    })
+}
+
+/// Prefers an image that only needs scaling down, then the closest aspect ratio.
+fn best_resolution(
+    wallpaper: &Wallpaper,
+    area: &Monitor,
+    wallpaper_type: WallpaperType,
+) -> Option<(Resolution, DownloadWallpaperRequest)> {
+    wallpaper
+        .best_resolution(
+            area.width,
+            area.height,
+            wallpaper_type.clone(),
+            FitPolicy::SmallestAtLeast,
+        )
+        .or_else(|| {
+            wallpaper.best_resolution(
+                area.width,
+                area.height,
+                wallpaper_type,
+                FitPolicy::ClosestAspectRatio,
+            )
+        })
+}
+
+/// Whether the monitors form a single row: none overlap horizontally and every one shares some
+/// vertical range with its neighbour.
+fn side_by_side(monitors: &[Monitor]) -> bool {
+    let mut sorted = monitors.to_vec();
+    sorted.sort_by_key(|monitor| monitor.x);
+    sorted.windows(2).all(|pair| {
+        right(&pair[0]) <= pair[1].x as i64
+            && (pair[0].y as i64) < bottom(&pair[1])
+            && (pair[1].y as i64) < bottom(&pair[0])
+    })
+}
+
+fn right(monitor: &Monitor) -> i64 {
+    monitor.x as i64 + monitor.width as i64
+}
+
+fn bottom(monitor: &Monitor) -> i64 {
+    monitor.y as i64 + monitor.height as i64
+}
+
+/// How much of the image's width or height is lost when scaling it to cover `area`.
+fn crop_fraction(resolution: &Resolution, area: &Monitor) -> f64 {
+    let image_ratio = resolution.width as f64 / resolution.height as f64;
+    let area_ratio = area.width as f64 / area.height as f64;
+    1_f64 - image_ratio.min(area_ratio) / image_ratio.max(area_ratio)
+}
+
+/// The largest centred region of the image with the same aspect ratio as `area`.
+fn cover_crop(resolution: &Resolution, area: &Monitor) -> Crop {
+    let scale = (resolution.width as f64 / area.width as f64)
+        .min(resolution.height as f64 / area.height as f64);
+    let width = ((area.width as f64 * scale).round() as u32).min(resolution.width);
+    let height = ((area.height as f64 * scale).round() as u32).min(resolution.height);
+    Crop {
+        x: (resolution.width - width) / 2,
+        y: (resolution.height - height) / 2,
+        width,
+        height,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{resolution, resolutions};
+
+    fn wallpaper() -> Wallpaper {
+        let mut wallpaper = crate::test_support::wallpaper();
+        wallpaper.resolutions = Some(resolutions(
+            &[(1920, 1080), (2560, 1440), (1920, 1200)],
+            &[(3840, 1080), (5120, 1440)],
+            &[(5760, 1080)],
+        ));
+        wallpaper
+    }
+
+    fn monitor(x: i32, y: i32, width: u32, height: u32) -> Monitor {
+        Monitor {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    mod plan_layout_test {
+        use super::*;
+
+        #[test]
+        fn test_plan_layout_single_monitor() {
+            let plan = plan_layout(&wallpaper(), &[monitor(0, 0, 2560, 1440)]).unwrap();
+
+            assert!(!plan.spanning);
+            assert_eq!(plan.downloads.len(), 1);
+            assert_eq!(plan.downloads[0].wallpaper_type, WallpaperType::Single);
+            assert_eq!(plan.downloads[0].width, 2560);
+            assert_eq!(
+                plan.monitors[0].crop,
+                Crop {
+                    x: 0,
+                    y: 0,
+                    width: 2560,
+                    height: 1440
+                }
+            );
+        }
+
+        #[test]
+        fn test_plan_layout_spans_monitors_far_apart() {
+            let monitors = [
+                monitor(-2_100_000_000, 0, 2_100_000_000, 900_000_000),
+                monitor(100_000_000, 0, 1_000_000_000, 900_000_000),
+            ];
+
+            let plan = plan_layout(&wallpaper(), &monitors).unwrap();
+
+            assert!(plan.spanning);
+            assert_eq!(plan.monitors[0].crop.x, 0);
+            assert!(plan.monitors[1].crop.x > plan.monitors[0].crop.width);
+        }
+
+        #[test]
+        fn test_plan_layout_spans_dual_monitors() {
+            let monitors = [monitor(0, 0, 1920, 1080), monitor(1920, 0, 1920, 1080)];
+
+            let plan = plan_layout(&wallpaper(), &monitors).unwrap();
+
+            assert!(plan.spanning);
+            assert_eq!(plan.downloads.len(), 1);
+            assert_eq!(plan.downloads[0].wallpaper_type, WallpaperType::Dual);
+            assert_eq!(plan.downloads[0].width, 3840);
+            assert_eq!(plan.monitors[0].download, 0);
+            assert_eq!(
+                plan.monitors[0].crop,
+                Crop {
+                    x: 0,
+                    y: 0,
+                    width: 1920,
+                    height: 1080
+                }
+            );
+            assert_eq!(
+                plan.monitors[1].crop,
+                Crop {
+                    x: 1920,
+                    y: 0,
+                    width: 1920,
+                    height: 1080
+                }
+            );
+        }
+
+        #[test]
+        fn test_plan_layout_spans_triple_monitors_in_any_order() {
+            let monitors = [
+                monitor(1920, 0, 1920, 1080),
+                monitor(0, 0, 1920, 1080),
+                monitor(3840, 0, 1920, 1080),
+            ];
+
+            let plan = plan_layout(&wallpaper(), &monitors).unwrap();
+
+            assert!(plan.spanning);
+            assert_eq!(plan.downloads[0].wallpaper_type, WallpaperType::Triple);
+            assert_eq!(plan.monitors[0].crop.x, 1920);
+            assert_eq!(plan.monitors[1].crop.x, 0);
+            assert_eq!(plan.monitors[2].crop.x, 3840);
+        }
+
+        #[test]
+        fn test_plan_layout_scales_spanning_crops() {
+            let monitors = [monitor(0, 0, 1280, 720), monitor(1280, 0, 1280, 720)];
+
+            let plan = plan_layout(&wallpaper(), &monitors).unwrap();
+
+            assert_eq!(plan.downloads[0].width, 3840);
+            assert_eq!(
+                plan.monitors[1].crop,
+                Crop {
+                    x: 1920,
+                    y: 0,
+                    width: 1920,
+                    height: 1080
+                }
+            );
+        }
+
+        #[test]
+        fn test_plan_layout_uses_singles_for_stacked_monitors() {
+            let monitors = [monitor(0, 0, 1920, 1080), monitor(0, 1080, 1920, 1080)];
+
+            let plan = plan_layout(&wallpaper(), &monitors).unwrap();
+
+            assert!(!plan.spanning);
+            assert_eq!(plan.downloads.len(), 1);
+            assert_eq!(plan.monitors[0].download, 0);
+            assert_eq!(plan.monitors[1].download, 0);
+        }
+
+        #[test]
+        fn test_plan_layout_uses_singles_for_mixed_resolutions() {
+            let monitors = [monitor(0, 0, 2560, 1440), monitor(2560, 0, 1920, 1200)];
+
+            let plan = plan_layout(&wallpaper(), &monitors).unwrap();
+
+            assert!(!plan.spanning);
+            assert_eq!(plan.downloads.len(), 2);
+            assert_eq!(plan.downloads[0].width, 2560);
+            assert_eq!(plan.downloads[1].width, 1920);
+            assert_eq!(plan.downloads[1].height, 1200);
+            assert_eq!(plan.monitors[1].download, 1);
+        }
+
+        #[test]
+        fn test_plan_layout_crops_single_to_monitor_aspect_ratio() {
+            let mut wallpaper = wallpaper();
+            wallpaper.resolutions.as_mut().unwrap().single = vec![resolution(1920, 1200)];
+
+            let plan = plan_layout(&wallpaper, &[monitor(0, 0, 1920, 1080)]).unwrap();
+
+            assert_eq!(
+                plan.monitors[0].crop,
+                Crop {
+                    x: 0,
+                    y: 60,
+                    width: 1920,
+                    height: 1080
+                }
+            );
+        }
+
+        #[test]
+        fn test_plan_layout_falls_back_to_singles_without_spanning_resolutions() {
+            let mut wallpaper = wallpaper();
+            wallpaper.resolutions.as_mut().unwrap().dual = None;
+            let monitors = [monitor(0, 0, 1920, 1080), monitor(1920, 0, 1920, 1080)];
+
+            let plan = plan_layout(&wallpaper, &monitors).unwrap();
+
+            assert!(!plan.spanning);
+            assert_eq!(plan.downloads[0].wallpaper_type, WallpaperType::Single);
+        }
+
+        #[test]
+        fn test_plan_layout_without_monitors() {
+            assert!(plan_layout(&wallpaper(), &[]).is_none());
+        }
+
+        #[test]
+        fn test_plan_layout_without_resolutions() {
+            let mut wallpaper = wallpaper();
+            wallpaper.resolutions = None;
+
+            assert!(plan_layout(&wallpaper, &[monitor(0, 0, 1920, 1080)]).is_none());
+        }
+    }
+}
@@ -1,49 +1,146 @@
+mod atomic_write;
+mod builder;
+mod incremental;
+#[cfg(feature = "index")]
+mod index;
+mod layout;
 mod model;
+mod rate_limit;
+mod request_log;
+mod response_cache;
+mod retry;
+mod secret;
+mod sync;
+#[cfg(test)]
+mod test_support;
 
+pub use builder::DigitalBlasphemyClientBuilder;
+pub use incremental::IncrementalState;
+#[cfg(feature = "index")]
+pub use index::{CatalogIndex, IndexQuery};
+pub use layout::{Crop, LayoutPlan, Monitor, MonitorPlan, plan_layout};
 pub use model::*;
+pub use rate_limit::RateLimit;
+pub use response_cache::{CacheEndpoint, Cached, Freshness, ResponseCache};
+pub use retry::{RetryPolicy, RetryPolicyBuilder, RetryableError};
+pub use sync::{SyncOptions, SyncReport, SyncTarget};
 
 pub use crate::model::{
-    DownloadWallpaperRequest, DownloadWallpaperResponse, ErrorResponse, GetWallpaperRequest,
-    GetWallpaperResponse, GetWallpapersOrderBy, GetWallpapersRequest, GetWallpapersResponse,
-    Wallpaper,
+    ClientError, DownloadWallpaperRequest, DownloadWallpaperResponse, ErrorResponse,
+    GetWallpaperRequest, GetWallpaperResponse, GetWallpapersOrderBy, GetWallpapersRequest,
+    GetWallpapersResponse, Wallpaper,
 };
-use log::{Level, debug, log_enabled};
-use reqwest::{RequestBuilder, Response, StatusCode};
-use std::error::Error;
-use std::fs::OpenOptions;
-use std::io::Write;
-use std::path::Path;
-
+use bytes::Bytes;
+use futures::future;
+use futures::stream::{self, Stream, StreamExt};
+use indexmap::IndexMap;
+use log::{Level, debug, log_enabled, warn};
+use rate_limit::RateLimiter;
+use reqwest::header::{
+    ACCEPT, ACCEPT_RANGES, AUTHORIZATION, CONTENT_RANGE, ETAG, HeaderMap, HeaderValue, IF_RANGE,
+    LAST_MODIFIED, RANGE,
+};
+use reqwest::{Method, Request, RequestBuilder, Response, StatusCode, Url, redirect};
+use response_cache::CacheEntry;
+use secret::Secret;
+use sha2::{Digest, Sha256};
+use std::collections::{HashMap, HashSet};
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::{SystemTime, UNIX_EPOCH};
+use tokio::fs::{File, OpenOptions};
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+use zeroize::Zeroizing;
+
+const MAX_REDIRECTS: usize = 10;
+/// The most responses kept in memory for revalidation when no response cache is configured.
+const MAX_REVALIDATION_ENTRIES: usize = 256;
+
+#[derive(Debug, Clone)]
 pub struct DigitalBlasphemyClient {
-    authorization: String,
+    api_key: Secret,
     client: reqwest::Client,
     base_url: String,
+    allowed_hosts: Arc<RwLock<HashSet<String>>>,
+    default_headers: HeaderMap,
+    retry_policy: RetryPolicy,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    download_rate_limiter: Option<Arc<RateLimiter>>,
+    thumb_endpoint: Arc<RwLock<String>>,
+    thumbnail_cache_dir: Option<PathBuf>,
+    response_cache: Option<ResponseCache>,
+    /// Ordered from least to most recently used.
+    revalidation_entries: Arc<Mutex<IndexMap<(CacheEndpoint, String), CacheEntry>>>,
 }
 
 impl DigitalBlasphemyClient {
-    pub fn new(api_key: String) -> Result<DigitalBlasphemyClient, Box<dyn Error>> {
-        Ok(DigitalBlasphemyClient {
-            authorization: format!("Bearer {api_key}"),
-            client: reqwest::Client::builder().build()?,
-            base_url: "https://api.digitalblasphemy.com".to_string(),
-        })
+    pub fn new(api_key: String) -> Result<DigitalBlasphemyClient, ClientError> {
+        Self::builder(api_key).build()
+    }
+
+    pub fn builder(api_key: String) -> DigitalBlasphemyClientBuilder {
+        DigitalBlasphemyClientBuilder::new(api_key)
     }
 
     #[cfg(test)]
     pub(crate) fn new_test(
         api_key: String,
         base_url: String,
-    ) -> Result<DigitalBlasphemyClient, Box<dyn Error>> {
-        Ok(DigitalBlasphemyClient {
-            authorization: format!("Bearer {api_key}"),
-            client: reqwest::Client::builder().build()?,
-            base_url,
+    ) -> Result<DigitalBlasphemyClient, ClientError> {
+        Self::builder(api_key).base_url(base_url).build()
+    }
+
+    /// Only follows redirects that stay on the same origin, or that go to the API host, the default
+    /// thumb host or hosts advertised in `Endpoints`. reqwest already drops the `Authorization`
+    /// header whenever a redirect crosses origins.
+    fn redirect_policy(allowed_hosts: Arc<RwLock<HashSet<String>>>) -> redirect::Policy {
+        redirect::Policy::custom(move |attempt| {
+            if attempt.previous().len() >= MAX_REDIRECTS {
+                return attempt.error("too many redirects");
+            }
+            let same_origin = attempt
+                .previous()
+                .last()
+                .is_some_and(|previous| previous.origin() == attempt.url().origin());
+            let allowed = same_origin
+                || attempt.url().host_str().is_some_and(|host| {
+                    allowed_hosts
+                        .read()
+                        .is_ok_and(|allowed_hosts| allowed_hosts.contains(host))
+                });
+            if allowed {
+                attempt.follow()
+            } else {
+                debug!("Not following redirect to {}", attempt.url());
+                attempt.stop()
+            }
         })
     }
 
+    fn allow_endpoints(&self, endpoints: &Endpoints) {
+        let hosts = [&endpoints.api, &endpoints.image, &endpoints.thumb]
+            .into_iter()
+            .filter_map(|endpoint| Url::parse(endpoint).ok())
+            .filter_map(|url| url.host_str().map(str::to_string));
+        if let Ok(mut allowed_hosts) = self.allowed_hosts.write() {
+            allowed_hosts.extend(hosts);
+        }
+        if let Ok(mut thumb_endpoint) = self.thumb_endpoint.write() {
+            thumb_endpoint.clone_from(&endpoints.thumb);
+        }
+    }
+
+    fn is_api_origin(&self, url: &str) -> bool {
+        match (Url::parse(url), Url::parse(&self.base_url)) {
+            (Ok(url), Ok(base_url)) => url.origin() == base_url.origin(),
+            _ => false,
+        }
+    }
+
     pub async fn get_account_information(
         &self,
-    ) -> Result<GetAccountInformationResponse, ErrorResponse> {
+    ) -> Result<GetAccountInformationResponse, ClientError> {
         let get_account_information_response = self
             .get_request_json::<GetAccountInformationResponse>(
                 &vec![],
@@ -56,16 +153,88 @@ impl DigitalBlasphemyClient {
     pub async fn get_wallpapers(
         &self,
         request: &GetWallpapersRequest,
-    ) -> Result<GetWallpapersResponse, ErrorResponse> {
+    ) -> Result<GetWallpapersResponse, ClientError> {
+        Ok(self.get_wallpapers_cached(request).await?.value)
+    }
+
+    /// Like [`Self::get_wallpapers`], but also reports whether the response came from the
+    /// configured [`ResponseCache`].
+    pub async fn get_wallpapers_cached(
+        &self,
+        request: &GetWallpapersRequest,
+    ) -> Result<Cached<GetWallpapersResponse>, ClientError> {
         let get_wallpaper_response = self
-            .get_request_json::<GetWallpapersResponse>(
+            .get_cached_json::<GetWallpapersResponse>(
+                CacheEndpoint::Wallpapers,
                 &Self::get_wallpapers_query(request),
-                format!("{}/v2/core/wallpapers", self.base_url),
+                "/v2/core/wallpapers".to_string(),
             )
             .await?;
+        self.allow_endpoints(&get_wallpaper_response.value.db_core.endpoints);
         Ok(get_wallpaper_response)
     }
 
+    /// Removes the cached response for `request`, if any.
+    pub async fn invalidate_wallpapers(
+        &self,
+        request: &GetWallpapersRequest,
+    ) -> Result<(), ClientError> {
+        let key =
+            response_cache::cache_key("/v2/core/wallpapers", &Self::get_wallpapers_query(request));
+        self.remove_entry(CacheEndpoint::Wallpapers, key).await
+    }
+
+    pub fn wallpapers_stream(
+        &self,
+        request: &GetWallpapersRequest,
+    ) -> impl Stream<Item = Result<Wallpaper, ClientError>> + '_ {
+        self.wallpapers_stream_with_prefetch(request, 1)
+    }
+
+    /// Streams every wallpaper matching `request`, starting at `request.page` and stopping at
+    /// `total_pages`. Up to `prefetch` pages are requested concurrently, but wallpapers are
+    /// still yielded in page order. The stream ends after the first error.
+    pub fn wallpapers_stream_with_prefetch(
+        &self,
+        request: &GetWallpapersRequest,
+        prefetch: usize,
+    ) -> impl Stream<Item = Result<Wallpaper, ClientError>> + '_ {
+        let request = request.clone();
+        let prefetch = prefetch.max(1);
+        let pages = stream::once(async move {
+            let first_page = self.get_wallpapers(&request).await;
+            (request, first_page)
+        })
+        .flat_map(move |(request, first_page)| match first_page {
+            Ok(response) => {
+                let remaining_pages = request.page.saturating_add(1)..=response.db_core.total_pages;
+                let remaining_pages = stream::iter(remaining_pages)
+                    .map(move |page| {
+                        let mut request = request.clone();
+                        request.page = page;
+                        async move { self.get_wallpapers(&request).await }
+                    })
+                    .buffered(prefetch);
+                stream::once(future::ready(Ok(response)))
+                    .chain(remaining_pages)
+                    .left_stream()
+            }
+            Err(error) => stream::once(future::ready(Err(error))).right_stream(),
+        });
+        stream::unfold(Some(Box::pin(pages)), |pages| async move {
+            let mut pages = pages?;
+            let page = pages.next().await?;
+            let pages = page.is_ok().then_some(pages);
+            Some((page, pages))
+        })
+        .flat_map(|page| match page {
+            Ok(response) => {
+                stream::iter(response.into_wallpapers().into_iter().map(Ok)).left_stream()
+            }
+            Err(error) => stream::once(future::ready(Err(error))).right_stream(),
+        })
+    }
+
     fn get_wallpapers_query(request: &GetWallpapersRequest) -> Vec<(&str, String)> {
         let mut query: Vec<(&str, String)> = vec![];
         if request.filter_date_day != 0 {
@@ -132,17 +301,87 @@ impl DigitalBlasphemyClient {
     pub async fn get_wallpaper(
         &self,
         request: &GetWallpaperRequest,
-    ) -> Result<Option<Wallpaper>, ErrorResponse> {
+    ) -> Result<Option<Wallpaper>, ClientError> {
+        Ok(self.get_wallpaper_cached(request).await?.value)
+    }
+
+    /// Like [`Self::get_wallpaper`], but also reports whether the response came from the
+    /// configured [`ResponseCache`].
+    pub async fn get_wallpaper_cached(
+        &self,
+        request: &GetWallpaperRequest,
+    ) -> Result<Cached<Option<Wallpaper>>, ClientError> {
         let get_wallpaper_response = self
-            .get_request_json::<GetWallpaperResponse>(
+            .get_cached_json::<GetWallpaperResponse>(
+                CacheEndpoint::Wallpaper,
                 &Self::get_wallpaper_query(request),
-                format!(
-                    "{}/v2/core/wallpaper/{}",
-                    self.base_url, request.wallpaper_id
-                ),
+                format!("/v2/core/wallpaper/{}", request.wallpaper_id),
             )
             .await?;
-        Ok(get_wallpaper_response.wallpaper)
+        self.allow_endpoints(&get_wallpaper_response.value.db_core.endpoints);
+        Ok(get_wallpaper_response.map(|response| response.wallpaper))
+    }
+
+    /// Removes the cached response for `request`, if any.
+    pub async fn invalidate_wallpaper(
+        &self,
+        request: &GetWallpaperRequest,
+    ) -> Result<(), ClientError> {
+        let key = response_cache::cache_key(
+            &format!("/v2/core/wallpaper/{}", request.wallpaper_id),
+            &Self::get_wallpaper_query(request),
+        );
+        self.remove_entry(CacheEndpoint::Wallpaper, key).await
+    }
+
+    /// Removes every cached response for `endpoint`.
+    pub async fn invalidate_endpoint(&self, endpoint: CacheEndpoint) -> Result<(), ClientError> {
+        self.revalidation_entries
+            .lock()
+            .unwrap_or_else(|error| error.into_inner())
+            .retain(|(entry_endpoint, _), _| *entry_endpoint != endpoint);
+        match &self.response_cache {
+            Some(response_cache) => response_cache.clear(Some(endpoint)).await,
+            None => Ok(()),
+        }
+    }
+
+    /// Removes every cached response.
+    pub async fn clear_response_cache(&self) -> Result<(), ClientError> {
+        self.revalidation_entries
+            .lock()
+            .unwrap_or_else(|error| error.into_inner())
+            .clear();
+        match &self.response_cache {
+            Some(response_cache) => response_cache.clear(None).await,
+            None => Ok(()),
+        }
+    }
+
+    /// Fetches each wallpaper in `ids` using `template` for every parameter but the ID, with at most
+    /// `concurrency` requests in flight. Duplicate IDs are fetched once. A wallpaper the API does
+    /// not return is reported as `Ok(None)`, while a failed request only affects its own ID.
+    pub async fn get_wallpapers_by_ids(
+        &self,
+        ids: impl IntoIterator<Item = impl Into<WallpaperId>>,
+        template: &GetWallpaperRequest,
+        concurrency: usize,
+    ) -> HashMap<WallpaperId, Result<Option<Wallpaper>, ClientError>> {
+        let mut seen = HashSet::new();
+        let ids: Vec<WallpaperId> = ids
+            .into_iter()
+            .map(Into::into)
+            .filter(|id| seen.insert(*id))
+            .collect();
+        stream::iter(ids)
+            .map(|id| {
+                let mut request = template.clone();
+                request.wallpaper_id = id;
+                async move { (id, self.get_wallpaper(&request).await) }
+            })
+            .buffer_unordered(concurrency.max(1))
+            .collect()
+            .await
     }
 
     fn get_wallpaper_query(request: &GetWallpaperRequest) -> Vec<(&str, String)> {
@@ -177,7 +416,144 @@ impl DigitalBlasphemyClient {
         &self,
         filename: &impl AsRef<Path>,
         request: &DownloadWallpaperRequest,
-    ) -> Result<(), ErrorResponse> {
+    ) -> Result<(), ClientError> {
+        let file_response = self.get_download_response(request).await?;
+        Self::write_response_atomically(file_response, filename.as_ref()).await
+    }
+
+    async fn write_response_atomically(
+        response: Response,
+        filename: &Path,
+    ) -> Result<(), ClientError> {
+        let (file, temp_path) = atomic_write::temp_file_beside(filename)?.into_parts();
+
+        let mut file = File::from_std(file);
+        Self::write_response(response, &mut file).await?;
+        file.sync_all().await?;
+        drop(file);
+
+        temp_path
+            .persist(filename)
+            .map_err(|error| ClientError::Io(error.error))?;
+        Ok(())
+    }
+
+    /// Fetches the wallpaper's thumbnail from the thumb endpoint advertised by the most recent
+    /// response, or the production CDN before any has been seen.
+    pub async fn get_thumbnail(&self, wallpaper: &Wallpaper) -> Result<Bytes, ClientError> {
+        let mut thumbnail = Vec::new();
+        self.get_thumbnail_to(&mut thumbnail, wallpaper).await?;
+        Ok(Bytes::from(thumbnail))
+    }
+
+    /// Streams the wallpaper's thumbnail into `writer`. When a thumbnail cache directory is
+    /// configured, the thumbnail is served from, or first stored in, the cache.
+    pub async fn get_thumbnail_to(
+        &self,
+        mut writer: impl AsyncWrite + Unpin,
+        wallpaper: &Wallpaper,
+    ) -> Result<(), ClientError> {
+        let url = self.thumbnail_url(wallpaper)?;
+        let Some(cache_path) = self.thumbnail_cache_path(&url) else {
+            let response = self.get_request(&vec![], url.to_string()).await?;
+            return Self::write_response(response, writer).await;
+        };
+
+        if !tokio::fs::try_exists(&cache_path).await? {
+            let response = self.get_request(&vec![], url.to_string()).await?;
+            if let Some(directory) = cache_path.parent() {
+                tokio::fs::create_dir_all(directory).await?;
+            }
+            Self::write_response_atomically(response, &cache_path).await?;
+        } else {
+            debug!("Serving thumbnail {} from cache", url.path());
+        }
+        let mut file = File::open(&cache_path).await?;
+        tokio::io::copy(&mut file, &mut writer).await?;
+        writer.flush().await?;
+        Ok(())
+    }
+
+    /// Fetches the thumbnails of `wallpapers` with at most `concurrency` requests in flight.
+    /// Results are returned in the same order as `wallpapers`.
+    pub async fn get_thumbnails(
+        &self,
+        wallpapers: &[Wallpaper],
+        concurrency: usize,
+    ) -> Vec<Result<Bytes, ClientError>> {
+        stream::iter(wallpapers)
+            .map(|wallpaper| self.get_thumbnail(wallpaper))
+            .buffered(concurrency.max(1))
+            .collect()
+            .await
+    }
+
+    fn thumbnail_url(&self, wallpaper: &Wallpaper) -> Result<Url, ClientError> {
+        let thumb_endpoint = self
+            .thumb_endpoint
+            .read()
+            .unwrap_or_else(|error| error.into_inner())
+            .clone();
+        Ok(Endpoints::resolve(&thumb_endpoint, &wallpaper.paths.thumb)?)
+    }
+
+    /// `<cache dir>/<sha256 of url>.<extension>`, so thumbnails with the same file name on
+    /// different hosts or paths never collide.
+    fn thumbnail_cache_path(&self, url: &Url) -> Option<PathBuf> {
+        let directory = self.thumbnail_cache_dir.as_ref()?;
+        let digest = Sha256::digest(url.as_str().as_bytes());
+        let mut file_name: String = digest.iter().map(|byte| format!("{byte:02x}")).collect();
+        if let Some(extension) = Path::new(url.path()).extension() {
+            file_name.push('.');
+            file_name.push_str(&extension.to_string_lossy());
+        }
+        Some(directory.join(file_name))
+    }
+
+    /// Streams the wallpaper into `writer` chunk by chunk, so the image is never held in memory
+    /// as a whole.
+    pub async fn download_wallpaper_to(
+        &self,
+        writer: impl AsyncWrite + Unpin,
+        request: &DownloadWallpaperRequest,
+    ) -> Result<(), ClientError> {
+        let file_response = self.get_download_response(request).await?;
+        Self::write_response(file_response, writer).await
+    }
+
+    /// Downloads the wallpaper into `<filename>.part` and renames it to `filename` once complete.
+    /// If a `.part` file is left over from an interrupted attempt, only the missing bytes are
+    /// requested.
+    pub async fn download_wallpaper_resumable(
+        &self,
+        filename: &impl AsRef<Path>,
+        request: &DownloadWallpaperRequest,
+    ) -> Result<(), ClientError> {
+        let download = self.get_download(request).await?;
+        self.download_to_part_file(filename.as_ref(), download.url)
+            .await
+    }
+
+    /// Resumes a download from a previously obtained `download`, requesting a fresh signed URL
+    /// first if it has expired.
+    pub async fn resume_download(
+        &self,
+        filename: &impl AsRef<Path>,
+        request: &DownloadWallpaperRequest,
+        download: &Download,
+    ) -> Result<(), ClientError> {
+        let url = if Self::is_expired(download) {
+            self.get_download(request).await?.url
+        } else {
+            download.url.clone()
+        };
+        self.download_to_part_file(filename.as_ref(), url).await
+    }
+
+    pub async fn get_download(
+        &self,
+        request: &DownloadWallpaperRequest,
+    ) -> Result<Download, ClientError> {
         let download_wallpaper_response = self
             .get_request_json::<DownloadWallpaperResponse>(
                 &Self::download_query(request),
@@ -191,27 +567,182 @@ impl DigitalBlasphemyClient {
                 ),
             )
             .await?;
+        self.allow_endpoints(&download_wallpaper_response.db_core.endpoints);
+        Ok(download_wallpaper_response.download)
+    }
 
-        let file_response = self
-            .get_request(&vec![], download_wallpaper_response.download.url)
-            .await?;
+    async fn get_download_response(
+        &self,
+        request: &DownloadWallpaperRequest,
+    ) -> Result<Response, ClientError> {
+        let download = self.get_download(request).await?;
+        self.get_request(&vec![], download.url).await
+    }
 
-        let mut open_options = OpenOptions::new()
-            .write(true)
-            .truncate(true)
+    /// Resumes `<filename>.part` only when `<filename>.part.validator` holds the `ETag` or
+    /// `Last-Modified` of the response it came from. That value is sent as `If-Range`, so a server
+    /// whose image has changed sends the whole file again instead of appending to the old prefix.
+    async fn download_to_part_file(&self, filename: &Path, url: String) -> Result<(), ClientError> {
+        let mut part_filename = filename.as_os_str().to_owned();
+        part_filename.push(".part");
+        let part_filename = PathBuf::from(part_filename);
+        let mut validator_filename = part_filename.as_os_str().to_owned();
+        validator_filename.push(".validator");
+        let validator_filename = PathBuf::from(validator_filename);
+
+        let offset = match tokio::fs::metadata(&part_filename).await {
+            Ok(metadata) => metadata.len(),
+            Err(error) if error.kind() == ErrorKind::NotFound => 0,
+            Err(error) => return Err(error.into()),
+        };
+        let validator = match tokio::fs::read_to_string(&validator_filename).await {
+            Ok(validator) => Some(validator),
+            Err(error) if error.kind() == ErrorKind::NotFound => None,
+            Err(error) => return Err(error.into()),
+        };
+
+        let mut range_request = self.request(&vec![], url.clone());
+        let offset = match &validator {
+            Some(validator) if offset > 0 => {
+                range_request = range_request
+                    .header(RANGE, format!("bytes={offset}-"))
+                    .header(IF_RANGE, validator.as_str());
+                offset
+            }
+            _ => {
+                if offset > 0 {
+                    debug!("Partial download has no validator, restarting");
+                }
+                0
+            }
+        };
+        let mut response = self.execute(range_request).await?;
+
+        if offset > 0 && response.status() == StatusCode::RANGE_NOT_SATISFIABLE {
+            if Self::content_range_total(&response) == Some(offset) {
+                tokio::fs::rename(&part_filename, filename).await?;
+                return Self::remove_if_exists(&validator_filename).await;
+            }
+            debug!("Partial download of {offset} bytes is not usable, restarting");
+            response = self.execute(self.request(&vec![], url)).await?;
+        } else if response.status() == StatusCode::PARTIAL_CONTENT
+            && Self::content_range_start(&response) != Some(offset)
+        {
+            debug!("Server returned an unexpected Content-Range, restarting");
+            response = self.execute(self.request(&vec![], url)).await?;
+        } else if response.status() == StatusCode::PARTIAL_CONTENT
+            && !Self::range_validator_matches(&response, validator.as_deref())
+        {
+            debug!("Server returned a different validator for the range, restarting");
+            response = self.execute(self.request(&vec![], url)).await?;
+        }
+
+        if !response.status().is_success() {
+            return Err(Self::error_from_response(response).await);
+        }
+
+        let append = response.status() == StatusCode::PARTIAL_CONTENT;
+        if offset > 0 && !append {
+            debug!(
+                "Server ignored the range request (Accept-Ranges: {:?}), restarting",
+                response.headers().get(ACCEPT_RANGES)
+            );
+        }
+
+        let mut file = OpenOptions::new()
             .create(true)
-            .open(filename)
-            .expect("Unable to create file");
+            .write(true)
+            .append(append)
+            .truncate(!append)
+            .open(&part_filename)
+            .await?;
+        // Only record the validator once the old prefix is gone, so it never describes bytes from
+        // a different response.
+        if !append {
+            match Self::range_validator(&response) {
+                Some(validator) => tokio::fs::write(&validator_filename, validator).await?,
+                None => Self::remove_if_exists(&validator_filename).await?,
+            }
+        }
+        if let Err(error) = Self::write_response(response, &mut file).await {
+            // Make sure every written chunk has landed before a later attempt measures the file.
+            let _ = file.flush().await;
+            return Err(error);
+        }
+        file.sync_all().await?;
+        drop(file);
 
-        open_options
-            .write_all(
-                &file_response
-                    .bytes()
-                    .await
-                    .expect("Unable to read response as bytes"),
-            )
-            .unwrap();
+        tokio::fs::rename(&part_filename, filename).await?;
+        Self::remove_if_exists(&validator_filename).await
+    }
+
+    /// A strong `ETag`, or else `Last-Modified`, since `If-Range` does not accept weak tags.
+    fn range_validator(response: &Response) -> Option<String> {
+        let header = |name| {
+            response
+                .headers()
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+        };
+        header(ETAG)
+            .filter(|etag| !etag.starts_with("W/"))
+            .or_else(|| header(LAST_MODIFIED))
+            .map(str::to_string)
+    }
+
+    /// A `206` without the same kind of validator is trusted, since the server already evaluated
+    /// `If-Range`.
+    fn range_validator_matches(response: &Response, validator: Option<&str>) -> bool {
+        let Some(validator) = validator else {
+            return true;
+        };
+        let name = if validator.starts_with('"') {
+            ETAG
+        } else {
+            LAST_MODIFIED
+        };
+        response
+            .headers()
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+            .is_none_or(|received| received == validator)
+    }
+
+    async fn remove_if_exists(path: &Path) -> Result<(), ClientError> {
+        match tokio::fs::remove_file(path).await {
+            Err(error) if error.kind() != ErrorKind::NotFound => Err(error.into()),
+            _ => Ok(()),
+        }
+    }
+
+    fn is_expired(download: &Download) -> bool {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |duration| duration.as_secs() as i64);
+        download.expiration <= now
+    }
 
+    fn content_range_start(response: &Response) -> Option<u64> {
+        let content_range = response.headers().get(CONTENT_RANGE)?.to_str().ok()?;
+        let (start, _) = content_range.strip_prefix("bytes ")?.split_once('-')?;
+        start.parse().ok()
+    }
+
+    fn content_range_total(response: &Response) -> Option<u64> {
+        let content_range = response.headers().get(CONTENT_RANGE)?.to_str().ok()?;
+        let (_, total) = content_range.strip_prefix("bytes ")?.split_once('/')?;
+        total.parse().ok()
+    }
+
+    async fn write_response(
+        response: Response,
+        mut writer: impl AsyncWrite + Unpin,
+    ) -> Result<(), ClientError> {
+        let mut chunks = response.bytes_stream();
+        while let Some(chunk) = chunks.next().await {
+            writer.write_all(&chunk?).await?;
+        }
+        writer.flush().await?;
         Ok(())
     }
 
@@ -223,74 +754,297 @@ impl DigitalBlasphemyClient {
         &self,
         query: &Vec<(&str, String)>,
         url: String,
-    ) -> Result<T, ErrorResponse> {
+    ) -> Result<T, ClientError> {
+        let body = self.get_request(query, url).await?.text().await?;
+        serde_json::from_str::<T>(&body).map_err(|source| ClientError::Decode { body, source })
+    }
+
+    /// Serves fresh entries from the response cache without a request, and falls back to stale
+    /// ones if the API cannot be reached. Only responses that decode are stored.
+    async fn get_cached_json<T: serde::de::DeserializeOwned>(
+        &self,
+        endpoint: CacheEndpoint,
+        query: &Vec<(&str, String)>,
+        path: String,
+    ) -> Result<Cached<T>, ClientError> {
+        let key = response_cache::cache_key(&path, query);
+        let entry = self.load_entry(endpoint, &key).await;
+        if let (Some(response_cache), Some(entry)) = (&self.response_cache, &entry)
+            && entry.age() < response_cache.ttl(endpoint)
+            && let Ok(value) = serde_json::from_str(&entry.body)
+        {
+            return Ok(Cached {
+                value,
+                freshness: Freshness::Cached,
+            });
+        }
+
+        let url = format!("{}{path}", self.base_url);
+        match self
+            .get_request_revalidating(query, url, entry.as_ref())
+            .await
+        {
+            Ok((fetched, freshness)) => {
+                let value = serde_json::from_str::<T>(&fetched.body).map_err(|source| {
+                    ClientError::Decode {
+                        body: fetched.body.clone(),
+                        source,
+                    }
+                })?;
+                self.store_entry(endpoint, key, fetched).await;
+                Ok(Cached { value, freshness })
+            }
+            Err(error @ (ClientError::Transport(_) | ClientError::Timeout(_)))
+                if self.response_cache.is_some() =>
+            {
+                let Some(value) = entry.and_then(|entry| serde_json::from_str(&entry.body).ok())
+                else {
+                    return Err(error);
+                };
+                warn!("Serving stale cached response for {path}: {error}");
+                Ok(Cached {
+                    value,
+                    freshness: Freshness::Stale,
+                })
+            }
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Sends `If-None-Match`/`If-Modified-Since` from `entry`, and answers a `304 Not Modified`
+    /// with its body.
+    async fn get_request_revalidating(
+        &self,
+        query: &Vec<(&str, String)>,
+        url: String,
+        entry: Option<&CacheEntry>,
+    ) -> Result<(CacheEntry, Freshness), ClientError> {
+        let conditional_headers = entry
+            .map(CacheEntry::conditional_headers)
+            .unwrap_or_default();
         let response = self
-            .get_request(query, url)
-            .await?
-            .json::<T>()
+            .execute(self.request(query, url).headers(conditional_headers))
+            .await?;
+
+        if response.status() == StatusCode::NOT_MODIFIED
+            && let Some(entry) = entry
+        {
+            let entry = entry.clone().revalidated(response.headers());
+            return Ok((entry, Freshness::NotModified));
+        }
+        if !response.status().is_success() {
+            return Err(Self::error_from_response(response).await);
+        }
+
+        let headers = response.headers().clone();
+        let entry = CacheEntry::new(response.text().await?, &headers);
+        Ok((entry, Freshness::Network))
+    }
+
+    /// Entries live in the response cache if one is configured. Otherwise only the most recently
+    /// used responses with validators are kept, in memory, so they can be revalidated.
+    async fn load_entry(&self, endpoint: CacheEndpoint, key: &str) -> Option<CacheEntry> {
+        let Some(response_cache) = &self.response_cache else {
+            let mut revalidation_entries = self
+                .revalidation_entries
+                .lock()
+                .unwrap_or_else(|error| error.into_inner());
+            let index = revalidation_entries.get_index_of(&(endpoint, key.to_string()))?;
+            let last = revalidation_entries.len() - 1;
+            revalidation_entries.move_index(index, last);
+            return revalidation_entries
+                .get_index(last)
+                .map(|(_, entry)| entry.clone());
+        };
+        response_cache
+            .load(&response_cache.entry_path(endpoint, key))
+            .await
+    }
+
+    async fn store_entry(&self, endpoint: CacheEndpoint, key: String, entry: CacheEntry) {
+        let Some(response_cache) = &self.response_cache else {
+            let mut revalidation_entries = self
+                .revalidation_entries
+                .lock()
+                .unwrap_or_else(|error| error.into_inner());
+            revalidation_entries.shift_remove(&(endpoint, key.clone()));
+            if entry.has_validators() {
+                revalidation_entries.insert((endpoint, key), entry);
+                if revalidation_entries.len() > MAX_REVALIDATION_ENTRIES {
+                    revalidation_entries.shift_remove_index(0);
+                }
+            }
+            return;
+        };
+        let entry_path = response_cache.entry_path(endpoint, &key);
+        if let Err(error) = response_cache.store(&entry_path, &entry).await {
+            warn!("Unable to cache response for {key}: {error}");
+        }
+    }
+
+    async fn remove_entry(&self, endpoint: CacheEndpoint, key: String) -> Result<(), ClientError> {
+        let Some(response_cache) = &self.response_cache else {
+            self.revalidation_entries
+                .lock()
+                .unwrap_or_else(|error| error.into_inner())
+                .shift_remove(&(endpoint, key));
+            return Ok(());
+        };
+        response_cache
+            .remove(&response_cache.entry_path(endpoint, &key))
             .await
-            .expect("Unable to parse the body as JSON");
-        Ok(response)
     }
 
     async fn get_request(
         &self,
         query: &Vec<(&str, String)>,
         url: String,
-    ) -> Result<Response, ErrorResponse> {
-        let mut headers = reqwest::header::HeaderMap::new();
-        headers.insert("Accept", "application/json".parse().unwrap());
-        headers.insert("Authorization", self.authorization.parse().unwrap());
+    ) -> Result<Response, ClientError> {
+        let response = self.execute(self.request(query, url)).await?;
+
+        if !response.status().is_success() {
+            return Err(Self::error_from_response(response).await);
+        }
+
+        Ok(response)
+    }
 
+    fn request(&self, query: &Vec<(&str, String)>, url: String) -> RequestBuilder {
+        let send_credentials = self.is_api_origin(&url);
         let request = self
             .client
             .request(reqwest::Method::GET, url)
             .query(&query)
-            .headers(headers.clone());
+            .header(ACCEPT, "application/json")
+            .headers(self.default_headers.clone());
+        if send_credentials {
+            request.header(AUTHORIZATION, self.authorization())
+        } else {
+            request
+        }
+    }
+
+    fn authorization(&self) -> HeaderValue {
+        // The builder has already rejected keys that do not form a valid header.
+        Self::bearer(&self.api_key).unwrap_or_else(|_| HeaderValue::from_static(""))
+    }
 
-        Self::debug_log(&request);
+    fn bearer(api_key: &Secret) -> Result<HeaderValue, ClientError> {
+        let authorization = Zeroizing::new(format!("Bearer {}", api_key.expose()));
+        let mut authorization =
+            HeaderValue::from_str(&authorization).map_err(|_| ClientError::InvalidApiKey)?;
+        authorization.set_sensitive(true);
+        Ok(authorization)
+    }
 
-        let response = request.send().await;
+    /// Sends `request`, retrying GETs according to the configured retry policy.
+    async fn execute(&self, request: RequestBuilder) -> Result<Response, ClientError> {
+        let request = request.build()?;
+        let max_attempts = if request.method() == Method::GET {
+            self.retry_policy.max_attempts()
+        } else {
+            1
+        };
+
+        let rate_limiter = if self.is_api_origin(request.url().as_str()) {
+            self.rate_limiter.as_deref()
+        } else {
+            self.download_rate_limiter.as_deref()
+        };
+
+        let mut attempt = 1;
+        loop {
+            if let Some(rate_limiter) = rate_limiter {
+                rate_limiter.acquire().await;
+            }
+            let Some(attempt_request) = request.try_clone() else {
+                self.debug_log(&request);
+                let result = self.client.execute(request).await;
+                Self::update_rate_limiter(rate_limiter, &result);
+                return Ok(result?);
+            };
+            self.debug_log(&attempt_request);
+
+            let result = self.client.execute(attempt_request).await;
+            Self::update_rate_limiter(rate_limiter, &result);
+            let retry = attempt < max_attempts
+                && match &result {
+                    Ok(response) => self.retry_policy.should_retry_status(response.status()),
+                    Err(error) => self.retry_policy.should_retry_error(error),
+                };
+            if !retry {
+                return Ok(result?);
+            }
 
-        if let Err(error) = response {
-            let status = error.status().unwrap();
-            return Err(ErrorResponse {
-                code: status.as_u16() as u64,
-                description: status.canonical_reason().unwrap().to_string(),
-                errors: Some(vec![error.source().unwrap().to_string()]),
-            });
+            let Some(delay) = self.retry_policy.delay(attempt, result.as_ref().ok()) else {
+                warn!(
+                    "Attempt {attempt}/{max_attempts} for {} {} asked to retry later than {:?}, giving up",
+                    request.method(),
+                    request.url().path(),
+                    self.retry_policy.max_retry_after()
+                );
+                return Ok(result?);
+            };
+            let outcome = match &result {
+                Ok(response) => response.status().to_string(),
+                Err(error) if error.is_timeout() => "timed out".to_string(),
+                Err(error) if error.is_connect() => "could not connect".to_string(),
+                Err(_) => "failed to send".to_string(),
+            };
+            warn!(
+                "Attempt {attempt}/{max_attempts} for {} {} {outcome}, retrying in {delay:?}",
+                request.method(),
+                request.url().path()
+            );
+            tokio::time::sleep(delay).await;
+            attempt += 1;
         }
+    }
 
-        let unwrapped_response = response.unwrap();
-        if !unwrapped_response.status().is_success() {
-            if unwrapped_response.status() == StatusCode::NOT_FOUND {
-                return Err(ErrorResponse {
-                    code: unwrapped_response.status().as_u16() as u64,
-                    description: unwrapped_response
-                        .status()
-                        .canonical_reason()
-                        .unwrap()
-                        .to_string(),
-                    errors: Some(vec![
-                        unwrapped_response
-                            .text()
-                            .await
-                            .expect("Unable to parse the body as text"),
-                    ]),
-                });
-            }
-            return Err(unwrapped_response
-                .json::<ErrorResponse>()
-                .await
-                .expect("Unable to parse the body as JSON ErrorResponse"));
+    fn update_rate_limiter(
+        rate_limiter: Option<&RateLimiter>,
+        result: &Result<Response, reqwest::Error>,
+    ) {
+        let (Some(rate_limiter), Ok(response)) = (rate_limiter, result) else {
+            return;
+        };
+        rate_limiter.update(response.headers());
+        if response.status() == StatusCode::TOO_MANY_REQUESTS
+            && let Some(retry_after) = retry::retry_after(response)
+        {
+            rate_limiter.pause(retry_after);
         }
+    }
 
-        Ok(unwrapped_response)
+    async fn error_from_response(response: Response) -> ClientError {
+        let status = response.status();
+        let body = match response.text().await {
+            Ok(body) => body,
+            Err(error) => return error.into(),
+        };
+        let error_response =
+            serde_json::from_str::<ErrorResponse>(&body).unwrap_or_else(|_| ErrorResponse {
+                code: status.as_u16() as u64,
+                description: status.canonical_reason().unwrap_or_default().to_string(),
+                errors: if body.is_empty() {
+                    None
+                } else {
+                    Some(vec![body])
+                },
+            });
+        match status {
+            StatusCode::BAD_REQUEST => ClientError::BadRequest(error_response),
+            StatusCode::UNAUTHORIZED => ClientError::Unauthorized(error_response),
+            StatusCode::NOT_FOUND => ClientError::NotFound(error_response),
+            StatusCode::TOO_MANY_REQUESTS => ClientError::RateLimited(error_response),
+            _ => ClientError::Api(error_response),
+        }
     }
 
-    fn debug_log(request: &RequestBuilder) {
+    fn debug_log(&self, request: &Request) {
         if log_enabled!(Level::Debug) {
-            debug!("{:?}", request.try_clone().unwrap().build().unwrap());
+            let mask_query = !self.is_api_origin(request.url().as_str());
+            debug!("{}", request_log::describe(request, mask_query));
         }
     }
 }
@@ -298,21 +1052,60 @@ impl DigitalBlasphemyClient {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use indexmap::IndexMap;
     use mockito::Matcher;
     use std::collections::HashMap;
+    use std::error::Error;
     use std::fs;
 
-    mod get_account_information {
+    mod client {
+        use super::*;
+
+        #[test]
+        fn client_debug_does_not_contain_api_key() -> Result<(), Box<dyn Error>> {
+            let client = DigitalBlasphemyClient::new("secret_api_key".to_string())?;
+
+            let debug = format!("{client:?}");
+
+            assert!(!debug.contains("secret_api_key"));
+            assert!(debug.contains("[REDACTED]"));
+
+            Ok(())
+        }
+    }
+
+    mod builder {
         use super::*;
+        use reqwest::header::HeaderName;
+        use std::time::Duration;
+
+        #[test]
+        fn builder_allows_redirects_to_api_and_default_thumb_hosts() -> Result<(), Box<dyn Error>> {
+            let client = DigitalBlasphemyClient::builder("api_key".to_string())
+                .base_url("https://api.example.com")
+                .build()?;
+
+            let allowed_hosts = client.allowed_hosts.read().unwrap();
+            assert!(allowed_hosts.contains("api.example.com"));
+            assert!(allowed_hosts.contains("cdn.digitalblasphemy.com"));
+
+            Ok(())
+        }
+
+        #[test]
+        fn builder_rejects_api_key_that_is_not_a_valid_header() {
+            let result = DigitalBlasphemyClient::builder("api\nkey".to_string()).build();
+
+            assert!(matches!(result, Err(ClientError::InvalidApiKey)));
+        }
 
         #[tokio::test]
-        async fn get_account_information_can_map_successful_response() -> Result<(), Box<dyn Error>>
-        {
+        async fn builder_sends_user_agent() -> Result<(), Box<dyn Error>> {
             let mut server = mockito::Server::new_async().await;
 
             let mock = server
                 .mock("GET", "/v2/core/account")
-                .match_header("Authorization", "Bearer api_key")
+                .match_header("User-Agent", "wallpaper-sync/1.0")
                 .with_status(200)
                 .with_header("content-type", "application/json")
                 .with_body(fs::read_to_string(
@@ -321,16 +1114,12 @@ mod tests {
                 .create_async()
                 .await;
 
-            let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
-
-            let user_information = client.get_account_information().await.unwrap();
+            let client = DigitalBlasphemyClient::builder("api_key".to_string())
+                .base_url(server.url())
+                .user_agent(HeaderValue::from_static("wallpaper-sync/1.0"))
+                .build()?;
 
-            assert_eq!(user_information.db_core.timestamp, 1);
-            assert!(user_information.user.active);
-            assert_eq!(user_information.user.display_name, "username".to_string());
-            assert_eq!(user_information.user.id, 2);
-            assert!(user_information.user.lifetime);
-            assert!(user_information.user.plus);
+            client.get_account_information().await?;
 
             mock.assert_async().await;
 
@@ -338,25 +1127,33 @@ mod tests {
         }
 
         #[tokio::test]
-        async fn get_account_information_can_map_unauthorised_response()
-        -> Result<(), Box<dyn Error>> {
+        async fn builder_sends_default_headers() -> Result<(), Box<dyn Error>> {
             let mut server = mockito::Server::new_async().await;
 
             let mock = server
                 .mock("GET", "/v2/core/account")
+                .match_header("X-Request-Source", "nightly-sync")
                 .match_header("Authorization", "Bearer api_key")
-                .with_status(401)
+                .with_status(200)
                 .with_header("content-type", "application/json")
-                .with_body(fs::read_to_string("resources/unauthorised_response.json")?)
+                .with_body(fs::read_to_string(
+                    "resources/get_account_information_success.json",
+                )?)
                 .create_async()
                 .await;
 
-            let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
+            let mut default_headers = HeaderMap::new();
+            default_headers.insert(
+                HeaderName::from_static("x-request-source"),
+                HeaderValue::from_static("nightly-sync"),
+            );
 
-            let error = client.get_account_information().await.unwrap_err();
+            let client = DigitalBlasphemyClient::builder("api_key".to_string())
+                .base_url(server.url())
+                .default_headers(default_headers)
+                .build()?;
 
-            assert_eq!(error.code, 401);
-            assert_eq!(error.description, "Unauthorized".to_string());
+            client.get_account_information().await?;
 
             mock.assert_async().await;
 
@@ -364,89 +1161,83 @@ mod tests {
         }
 
         #[tokio::test]
-        #[should_panic(expected = "Unable to parse the body as JSON ErrorResponse")]
-        async fn get_account_information_can_map_unknown_error_response() {
+        async fn builder_ignores_trailing_slash_in_base_url() -> Result<(), Box<dyn Error>> {
             let mut server = mockito::Server::new_async().await;
 
-            server
+            let mock = server
                 .mock("GET", "/v2/core/account")
-                .match_header("Authorization", "Bearer api_key")
-                .with_status(405)
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(fs::read_to_string(
+                    "resources/get_account_information_success.json",
+                )?)
                 .create_async()
                 .await;
 
-            let client =
-                DigitalBlasphemyClient::new_test("api_key".to_string(), server.url()).unwrap();
+            let client = DigitalBlasphemyClient::builder("api_key".to_string())
+                .base_url(format!("{}/", server.url()))
+                .build()?;
 
-            let _ = client.get_account_information().await.unwrap_err();
-        }
-    }
+            client.get_account_information().await?;
 
-    mod get_wallpapers {
-        use super::*;
+            mock.assert_async().await;
+
+            Ok(())
+        }
 
         #[tokio::test]
-        async fn get_wallpapers_does_not_send_filter_date_day_if_not_provided()
-        -> Result<(), Box<dyn Error>> {
+        async fn builder_applies_timeout() -> Result<(), Box<dyn Error>> {
             let mut server = mockito::Server::new_async().await;
 
-            let get_wallpapers_request = GetWallpapersRequest::builder().build();
-
-            let mock = server
-                .mock(
-                    "GET",
-                    Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
-                )
-                .match_query(Matcher::Regex("[^filter_date_day]".to_string()))
-                .match_header("Authorization", "Bearer api_key")
+            server
+                .mock("GET", "/v2/core/account")
                 .with_status(200)
                 .with_header("content-type", "application/json")
-                .with_body(fs::read_to_string(
-                    "resources/get_wallpapers_success_fully_populated.json",
-                )?)
+                .with_chunked_body(|writer| {
+                    std::thread::sleep(Duration::from_secs(1));
+                    writer.write_all(b"{}")
+                })
                 .create_async()
                 .await;
 
-            let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
+            let client = DigitalBlasphemyClient::builder("api_key".to_string())
+                .base_url(server.url())
+                .timeout(Duration::from_millis(100))
+                .build()?;
 
-            client
-                .get_wallpapers(&get_wallpapers_request)
-                .await
-                .unwrap();
+            let error = client.get_account_information().await.unwrap_err();
 
-            mock.assert_async().await;
+            assert!(matches!(error, ClientError::Timeout(_)));
 
             Ok(())
         }
 
         #[tokio::test]
-        async fn get_wallpapers_does_send_filter_date_day_if_provided() -> Result<(), Box<dyn Error>>
-        {
+        async fn builder_uses_provided_client() -> Result<(), Box<dyn Error>> {
             let mut server = mockito::Server::new_async().await;
 
-            let get_wallpapers_request = GetWallpapersRequest::builder().filter_date_day(1).build();
-
             let mock = server
-                .mock(
-                    "GET",
-                    Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
-                )
-                .match_query(Matcher::Regex("filter_date_day=1".to_string()))
+                .mock("GET", "/v2/core/account")
+                .match_header("User-Agent", "provided-client")
                 .match_header("Authorization", "Bearer api_key")
                 .with_status(200)
                 .with_header("content-type", "application/json")
                 .with_body(fs::read_to_string(
-                    "resources/get_wallpapers_success_fully_populated.json",
+                    "resources/get_account_information_success.json",
                 )?)
                 .create_async()
                 .await;
 
-            let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
+            let client = DigitalBlasphemyClient::builder("api_key".to_string())
+                .base_url(server.url())
+                .client(
+                    reqwest::Client::builder()
+                        .user_agent("provided-client")
+                        .build()?,
+                )
+                .build()?;
 
-            client
-                .get_wallpapers(&get_wallpapers_request)
-                .await
-                .unwrap();
+            client.get_account_information().await?;
 
             mock.assert_async().await;
 
@@ -454,172 +1245,174 @@ mod tests {
         }
 
         #[tokio::test]
-        async fn get_wallpapers_does_not_send_filter_date_month_if_not_provided()
-        -> Result<(), Box<dyn Error>> {
-            let mut server = mockito::Server::new_async().await;
-
-            let get_wallpapers_request = GetWallpapersRequest::builder().build();
+        async fn builder_sends_requests_through_proxy() -> Result<(), Box<dyn Error>> {
+            let mut proxy_server = mockito::Server::new_async().await;
 
-            let mock = server
-                .mock(
-                    "GET",
-                    Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
-                )
-                .match_query(Matcher::Regex("[^filter_date_month]".to_string()))
+            let mock = proxy_server
+                .mock("GET", "/v2/core/account")
                 .match_header("Authorization", "Bearer api_key")
                 .with_status(200)
                 .with_header("content-type", "application/json")
                 .with_body(fs::read_to_string(
-                    "resources/get_wallpapers_success_fully_populated.json",
+                    "resources/get_account_information_success.json",
                 )?)
                 .create_async()
                 .await;
 
-            let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
+            let client = DigitalBlasphemyClient::builder("api_key".to_string())
+                .base_url("http://api.digitalblasphemy.invalid")
+                .proxy(reqwest::Proxy::http(proxy_server.url())?)
+                .build()?;
 
-            client
-                .get_wallpapers(&get_wallpapers_request)
-                .await
-                .unwrap();
+            client.get_account_information().await?;
 
             mock.assert_async().await;
 
             Ok(())
         }
+    }
+
+    mod retry {
+        use super::*;
+        use std::time::{Duration, Instant};
+
+        fn retry_policy() -> RetryPolicy {
+            RetryPolicy::builder()
+                .max_attempts(3)
+                .initial_backoff(Duration::from_millis(1))
+                .jitter(false)
+                .build()
+        }
 
         #[tokio::test]
-        async fn get_wallpapers_does_send_filter_date_month_if_provided()
-        -> Result<(), Box<dyn Error>> {
+        async fn client_retries_server_errors() -> Result<(), Box<dyn Error>> {
             let mut server = mockito::Server::new_async().await;
 
-            let get_wallpapers_request =
-                GetWallpapersRequest::builder().filter_date_month(1).build();
+            let unavailable_mock = server
+                .mock("GET", "/v2/core/account")
+                .with_status(503)
+                .expect(2)
+                .create_async()
+                .await;
 
             let mock = server
-                .mock(
-                    "GET",
-                    Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
-                )
-                .match_query(Matcher::Regex("filter_date_month=1".to_string()))
-                .match_header("Authorization", "Bearer api_key")
+                .mock("GET", "/v2/core/account")
                 .with_status(200)
                 .with_header("content-type", "application/json")
                 .with_body(fs::read_to_string(
-                    "resources/get_wallpapers_success_fully_populated.json",
+                    "resources/get_account_information_success.json",
                 )?)
                 .create_async()
                 .await;
 
-            let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
+            let client = DigitalBlasphemyClient::builder("api_key".to_string())
+                .base_url(server.url())
+                .retry_policy(retry_policy())
+                .build()?;
 
-            client
-                .get_wallpapers(&get_wallpapers_request)
-                .await
-                .unwrap();
+            let user_information = client.get_account_information().await?;
+
+            assert_eq!(user_information.user.id, 2);
 
+            unavailable_mock.assert_async().await;
             mock.assert_async().await;
 
             Ok(())
         }
 
         #[tokio::test]
-        async fn get_wallpapers_does_not_send_filter_date_year_if_not_provided()
-        -> Result<(), Box<dyn Error>> {
+        async fn client_honours_retry_after() -> Result<(), Box<dyn Error>> {
             let mut server = mockito::Server::new_async().await;
 
-            let get_wallpapers_request = GetWallpapersRequest::builder().build();
+            let rate_limited_mock = server
+                .mock("GET", "/v2/core/account")
+                .with_status(429)
+                .with_header("Retry-After", "1")
+                .expect(1)
+                .create_async()
+                .await;
 
             let mock = server
-                .mock(
-                    "GET",
-                    Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
-                )
-                .match_query(Matcher::Regex("[^filter_date_year]".to_string()))
-                .match_header("Authorization", "Bearer api_key")
+                .mock("GET", "/v2/core/account")
                 .with_status(200)
                 .with_header("content-type", "application/json")
                 .with_body(fs::read_to_string(
-                    "resources/get_wallpapers_success_fully_populated.json",
+                    "resources/get_account_information_success.json",
                 )?)
                 .create_async()
                 .await;
 
-            let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
+            let client = DigitalBlasphemyClient::builder("api_key".to_string())
+                .base_url(server.url())
+                .retry_policy(retry_policy())
+                .build()?;
 
-            client
-                .get_wallpapers(&get_wallpapers_request)
-                .await
-                .unwrap();
+            let start = Instant::now();
+            client.get_account_information().await?;
 
+            assert!(start.elapsed() >= Duration::from_secs(1));
+
+            rate_limited_mock.assert_async().await;
             mock.assert_async().await;
 
             Ok(())
         }
 
         #[tokio::test]
-        async fn get_wallpapers_does_send_filter_date_year_if_provided()
+        async fn client_gives_up_when_retry_after_exceeds_max_retry_after()
         -> Result<(), Box<dyn Error>> {
             let mut server = mockito::Server::new_async().await;
 
-            let get_wallpapers_request = GetWallpapersRequest::builder()
-                .filter_date_year(1997)
-                .build();
-
-            let mock = server
-                .mock(
-                    "GET",
-                    Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
-                )
-                .match_query(Matcher::Regex("filter_date_year=1997".to_string()))
-                .match_header("Authorization", "Bearer api_key")
-                .with_status(200)
-                .with_header("content-type", "application/json")
-                .with_body(fs::read_to_string(
-                    "resources/get_wallpapers_success_fully_populated.json",
-                )?)
+            let rate_limited_mock = server
+                .mock("GET", "/v2/core/account")
+                .with_status(429)
+                .with_header("Retry-After", "3600")
+                .expect(1)
                 .create_async()
                 .await;
 
-            let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
+            let client = DigitalBlasphemyClient::builder("api_key".to_string())
+                .base_url(server.url())
+                .retry_policy(
+                    RetryPolicy::builder()
+                        .max_retry_after(Duration::from_secs(5))
+                        .build(),
+                )
+                .build()?;
 
-            client
-                .get_wallpapers(&get_wallpapers_request)
-                .await
-                .unwrap();
+            let start = Instant::now();
+            let result = client.get_account_information().await;
 
-            mock.assert_async().await;
+            assert!(result.is_err());
+            assert!(start.elapsed() < Duration::from_secs(5));
+
+            rate_limited_mock.assert_async().await;
 
             Ok(())
         }
 
         #[tokio::test]
-        async fn get_wallpapers_does_send_filter_date_operator_if_not_provided()
-        -> Result<(), Box<dyn Error>> {
+        async fn client_gives_up_after_max_attempts() -> Result<(), Box<dyn Error>> {
             let mut server = mockito::Server::new_async().await;
 
-            let get_wallpapers_request = GetWallpapersRequest::builder().build();
-
             let mock = server
-                .mock(
-                    "GET",
-                    Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
-                )
-                .match_query(Matcher::Regex("filter_date_operator=%3E%3D".to_string()))
-                .match_header("Authorization", "Bearer api_key")
-                .with_status(200)
-                .with_header("content-type", "application/json")
-                .with_body(fs::read_to_string(
-                    "resources/get_wallpapers_success_fully_populated.json",
-                )?)
+                .mock("GET", "/v2/core/account")
+                .with_status(502)
+                .expect(3)
                 .create_async()
                 .await;
 
-            let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
+            let client = DigitalBlasphemyClient::builder("api_key".to_string())
+                .base_url(server.url())
+                .retry_policy(retry_policy())
+                .build()?;
 
-            client
-                .get_wallpapers(&get_wallpapers_request)
-                .await
-                .unwrap();
+            let ClientError::Api(error) = client.get_account_information().await.unwrap_err()
+            else {
+                panic!("Expected an API error");
+            };
+
+            assert_eq!(error.code, 502);
 
             mock.assert_async().await;
 
@@ -627,35 +1420,24 @@ mod tests {
         }
 
         #[tokio::test]
-        async fn get_wallpapers_does_send_filter_date_operator_if_provided()
-        -> Result<(), Box<dyn Error>> {
+        async fn client_does_not_retry_client_errors() -> Result<(), Box<dyn Error>> {
             let mut server = mockito::Server::new_async().await;
 
-            let get_wallpapers_request = GetWallpapersRequest::builder()
-                .filter_date_operator(Operator::Equal)
-                .build();
-
             let mock = server
-                .mock(
-                    "GET",
-                    Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
-                )
-                .match_query(Matcher::Regex("filter_date_operator=%3D".to_string()))
-                .match_header("Authorization", "Bearer api_key")
-                .with_status(200)
-                .with_header("content-type", "application/json")
-                .with_body(fs::read_to_string(
-                    "resources/get_wallpapers_success_fully_populated.json",
-                )?)
+                .mock("GET", "/v2/core/account")
+                .with_status(404)
+                .expect(1)
                 .create_async()
                 .await;
 
-            let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
+            let client = DigitalBlasphemyClient::builder("api_key".to_string())
+                .base_url(server.url())
+                .retry_policy(retry_policy())
+                .build()?;
 
-            client
-                .get_wallpapers(&get_wallpapers_request)
-                .await
-                .unwrap();
+            let error = client.get_account_information().await.unwrap_err();
+
+            assert!(matches!(error, ClientError::NotFound(_)));
 
             mock.assert_async().await;
 
@@ -663,71 +1445,63 @@ mod tests {
         }
 
         #[tokio::test]
-        async fn get_wallpapers_does_not_send_filter_gallery_if_not_provided()
-        -> Result<(), Box<dyn Error>> {
+        async fn client_does_not_retry_by_default() -> Result<(), Box<dyn Error>> {
             let mut server = mockito::Server::new_async().await;
 
-            let get_wallpapers_request = GetWallpapersRequest::builder().build();
-
             let mock = server
-                .mock(
-                    "GET",
-                    Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
-                )
-                .match_query(Matcher::Regex("[^filter_gallery]".to_string()))
-                .match_header("Authorization", "Bearer api_key")
-                .with_status(200)
-                .with_header("content-type", "application/json")
-                .with_body(fs::read_to_string(
-                    "resources/get_wallpapers_success_fully_populated.json",
-                )?)
+                .mock("GET", "/v2/core/account")
+                .with_status(503)
+                .expect(1)
                 .create_async()
                 .await;
 
             let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
 
-            client
-                .get_wallpapers(&get_wallpapers_request)
-                .await
-                .unwrap();
+            let error = client.get_account_information().await.unwrap_err();
+
+            assert!(matches!(error, ClientError::Api(_)));
 
             mock.assert_async().await;
 
             Ok(())
         }
+    }
 
-        #[tokio::test]
-        async fn get_wallpapers_does_send_filter_gallery_if_provided() -> Result<(), Box<dyn Error>>
-        {
-            let mut server = mockito::Server::new_async().await;
-
-            let get_wallpapers_request = GetWallpapersRequest::builder()
-                .filter_gallery(vec![1, 2])
-                .build();
+    mod rate_limit {
+        use super::*;
+        use std::time::{Duration, Instant};
 
-            let mock = server
-                .mock(
-                    "GET",
-                    Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
-                )
-                .match_query(Matcher::Regex(
-                    "filter_gallery=1&filter_gallery=2".to_string(),
-                ))
-                .match_header("Authorization", "Bearer api_key")
+        async fn account_mock(server: &mut mockito::ServerGuard) -> mockito::Mock {
+            server
+                .mock("GET", "/v2/core/account")
                 .with_status(200)
                 .with_header("content-type", "application/json")
-                .with_body(fs::read_to_string(
-                    "resources/get_wallpapers_success_fully_populated.json",
-                )?)
+                .with_body(
+                    fs::read_to_string("resources/get_account_information_success.json").unwrap(),
+                )
+                .expect(3)
                 .create_async()
-                .await;
+                .await
+        }
 
-            let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
+        #[tokio::test]
+        async fn client_waits_for_rate_limit_capacity_across_clones() -> Result<(), Box<dyn Error>>
+        {
+            let mut server = mockito::Server::new_async().await;
+            let mock = account_mock(&mut server).await;
 
-            client
-                .get_wallpapers(&get_wallpapers_request)
-                .await
-                .unwrap();
+            let client = DigitalBlasphemyClient::builder("api_key".to_string())
+                .base_url(server.url())
+                .rate_limit(RateLimit::new(1, Duration::from_millis(200)))
+                .build()?;
+            let clone = client.clone();
+
+            let start = Instant::now();
+            client.get_account_information().await?;
+            clone.get_account_information().await?;
+            client.get_account_information().await?;
+
+            assert!(start.elapsed() >= Duration::from_millis(400));
 
             mock.assert_async().await;
 
@@ -735,67 +1509,133 @@ mod tests {
         }
 
         #[tokio::test]
-        async fn get_wallpapers_does_not_send_filter_rating_if_not_provided()
+        async fn client_does_not_apply_download_rate_limit_to_api_requests()
         -> Result<(), Box<dyn Error>> {
             let mut server = mockito::Server::new_async().await;
+            let mock = account_mock(&mut server).await;
 
-            let get_wallpapers_request = GetWallpapersRequest::builder().build();
+            let client = DigitalBlasphemyClient::builder("api_key".to_string())
+                .base_url(server.url())
+                .download_rate_limit(RateLimit::new(1, Duration::from_secs(60)))
+                .build()?;
 
-            let mock = server
-                .mock(
-                    "GET",
-                    Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
-                )
-                .match_query(Matcher::Regex("[^filter_rating]".to_string()))
-                .match_header("Authorization", "Bearer api_key")
+            let start = Instant::now();
+            for _ in 0..3 {
+                client.get_account_information().await?;
+            }
+
+            assert!(start.elapsed() < Duration::from_secs(30));
+
+            mock.assert_async().await;
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn client_applies_download_rate_limit_to_other_hosts() -> Result<(), Box<dyn Error>> {
+            let api_server = mockito::Server::new_async().await;
+            let mut download_server = mockito::Server::new_async().await;
+
+            let download_mock = download_server
+                .mock("GET", "/wallpaper.jpg")
+                .with_status(200)
+                .with_body("image")
+                .expect(2)
+                .create_async()
+                .await;
+
+            let client = DigitalBlasphemyClient::builder("api_key".to_string())
+                .base_url(api_server.url())
+                .download_rate_limit(RateLimit::new(1, Duration::from_millis(200)))
+                .build()?;
+            let url = format!("{}/wallpaper.jpg", download_server.url());
+
+            let start = Instant::now();
+            for _ in 0..2 {
+                client.get_request(&vec![], url.clone()).await?;
+            }
+
+            assert!(start.elapsed() >= Duration::from_millis(200));
+
+            download_mock.assert_async().await;
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn client_waits_for_rate_limit_reset_header() -> Result<(), Box<dyn Error>> {
+            let mut server = mockito::Server::new_async().await;
+
+            let exhausted_mock = server
+                .mock("GET", "/v2/core/account")
                 .with_status(200)
                 .with_header("content-type", "application/json")
+                .with_header("X-RateLimit-Remaining", "0")
+                .with_header("X-RateLimit-Reset", "1")
                 .with_body(fs::read_to_string(
-                    "resources/get_wallpapers_success_fully_populated.json",
+                    "resources/get_account_information_success.json",
                 )?)
+                .expect(1)
                 .create_async()
                 .await;
 
-            let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
+            let mock = server
+                .mock("GET", "/v2/core/account")
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(fs::read_to_string(
+                    "resources/get_account_information_success.json",
+                )?)
+                .create_async()
+                .await;
 
-            client
-                .get_wallpapers(&get_wallpapers_request)
-                .await
-                .unwrap();
+            let client = DigitalBlasphemyClient::builder("api_key".to_string())
+                .base_url(server.url())
+                .rate_limit(RateLimit::new(100, Duration::from_secs(1)))
+                .build()?;
 
+            let start = Instant::now();
+            client.get_account_information().await?;
+            client.get_account_information().await?;
+
+            assert!(start.elapsed() >= Duration::from_secs(1));
+
+            exhausted_mock.assert_async().await;
             mock.assert_async().await;
 
             Ok(())
         }
+    }
+
+    mod get_account_information {
+        use super::*;
 
         #[tokio::test]
-        async fn get_wallpapers_does_send_filter_rating_if_provided() -> Result<(), Box<dyn Error>>
+        async fn get_account_information_can_map_successful_response() -> Result<(), Box<dyn Error>>
         {
             let mut server = mockito::Server::new_async().await;
 
-            let get_wallpapers_request = GetWallpapersRequest::builder().filter_rating(1.2).build();
-
             let mock = server
-                .mock(
-                    "GET",
-                    Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
-                )
-                .match_query(Matcher::Regex("filter_rating=1.2".to_string()))
+                .mock("GET", "/v2/core/account")
                 .match_header("Authorization", "Bearer api_key")
                 .with_status(200)
                 .with_header("content-type", "application/json")
                 .with_body(fs::read_to_string(
-                    "resources/get_wallpapers_success_fully_populated.json",
+                    "resources/get_account_information_success.json",
                 )?)
                 .create_async()
                 .await;
 
             let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
 
-            client
-                .get_wallpapers(&get_wallpapers_request)
-                .await
-                .unwrap();
+            let user_information = client.get_account_information().await.unwrap();
+
+            assert_eq!(user_information.db_core.timestamp, 1);
+            assert!(user_information.user.active);
+            assert_eq!(user_information.user.display_name, "username".to_string());
+            assert_eq!(user_information.user.id, 2);
+            assert!(user_information.user.lifetime);
+            assert!(user_information.user.plus);
 
             mock.assert_async().await;
 
@@ -803,33 +1643,29 @@ mod tests {
         }
 
         #[tokio::test]
-        async fn get_wallpapers_does_send_filter_rating_operator_if_not_provided()
+        async fn get_account_information_can_map_unauthorised_response()
         -> Result<(), Box<dyn Error>> {
             let mut server = mockito::Server::new_async().await;
 
-            let get_wallpapers_request = GetWallpapersRequest::builder().build();
-
             let mock = server
-                .mock(
-                    "GET",
-                    Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
-                )
-                .match_query(Matcher::Regex("filter_rating_operator=%3E%3D".to_string()))
+                .mock("GET", "/v2/core/account")
                 .match_header("Authorization", "Bearer api_key")
-                .with_status(200)
+                .with_status(401)
                 .with_header("content-type", "application/json")
-                .with_body(fs::read_to_string(
-                    "resources/get_wallpapers_success_fully_populated.json",
-                )?)
+                .with_body(fs::read_to_string("resources/unauthorised_response.json")?)
                 .create_async()
                 .await;
 
             let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
 
-            client
-                .get_wallpapers(&get_wallpapers_request)
-                .await
-                .unwrap();
+            let ClientError::Unauthorized(error) =
+                client.get_account_information().await.unwrap_err()
+            else {
+                panic!("Expected an unauthorized error");
+            };
+
+            assert_eq!(error.code, 401);
+            assert_eq!(error.description, "Unauthorized".to_string());
 
             mock.assert_async().await;
 
@@ -837,35 +1673,85 @@ mod tests {
         }
 
         #[tokio::test]
-        async fn get_wallpapers_does_send_filter_rating_operator_if_provided()
+        async fn get_account_information_can_map_unknown_error_response()
         -> Result<(), Box<dyn Error>> {
             let mut server = mockito::Server::new_async().await;
 
-            let get_wallpapers_request = GetWallpapersRequest::builder()
-                .filter_rating_operator(Operator::Equal)
-                .build();
+            server
+                .mock("GET", "/v2/core/account")
+                .match_header("Authorization", "Bearer api_key")
+                .with_status(405)
+                .create_async()
+                .await;
+
+            let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
+
+            let ClientError::Api(error) = client.get_account_information().await.unwrap_err()
+            else {
+                panic!("Expected an API error");
+            };
+
+            assert_eq!(error.code, 405);
+            assert_eq!(error.description, "Method Not Allowed".to_string());
+            assert_eq!(error.errors, None);
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn get_account_information_can_map_rate_limited_response()
+        -> Result<(), Box<dyn Error>> {
+            let mut server = mockito::Server::new_async().await;
 
             let mock = server
-                .mock(
-                    "GET",
-                    Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
-                )
-                .match_query(Matcher::Regex("filter_rating_operator=%3D".to_string()))
+                .mock("GET", "/v2/core/account")
+                .match_header("Authorization", "Bearer api_key")
+                .with_status(429)
+                .with_header("content-type", "text/plain")
+                .with_body("Slow down")
+                .create_async()
+                .await;
+
+            let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
+
+            let ClientError::RateLimited(error) =
+                client.get_account_information().await.unwrap_err()
+            else {
+                panic!("Expected a rate limited error");
+            };
+
+            assert_eq!(error.code, 429);
+            assert_eq!(error.description, "Too Many Requests".to_string());
+            assert_eq!(error.errors.unwrap(), vec!["Slow down"]);
+
+            mock.assert_async().await;
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn get_account_information_can_map_undecodable_successful_response()
+        -> Result<(), Box<dyn Error>> {
+            let mut server = mockito::Server::new_async().await;
+
+            let mock = server
+                .mock("GET", "/v2/core/account")
                 .match_header("Authorization", "Bearer api_key")
                 .with_status(200)
-                .with_header("content-type", "application/json")
-                .with_body(fs::read_to_string(
-                    "resources/get_wallpapers_success_fully_populated.json",
-                )?)
+                .with_header("content-type", "text/html")
+                .with_body("<html>Proxy error</html>")
                 .create_async()
                 .await;
 
             let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
 
-            client
-                .get_wallpapers(&get_wallpapers_request)
-                .await
-                .unwrap();
+            let ClientError::Decode { body, .. } =
+                client.get_account_information().await.unwrap_err()
+            else {
+                panic!("Expected a decode error");
+            };
+
+            assert_eq!(body, "<html>Proxy error</html>".to_string());
 
             mock.assert_async().await;
 
@@ -873,7 +1759,24 @@ mod tests {
         }
 
         #[tokio::test]
-        async fn get_wallpapers_does_not_send_filter_res_height_if_not_provided()
+        async fn get_account_information_can_map_transport_error() -> Result<(), Box<dyn Error>> {
+            let client = DigitalBlasphemyClient::builder("api_key".to_string())
+                .base_url("http://127.0.0.1:1".to_string())
+                .build()?;
+
+            let error = client.get_account_information().await.unwrap_err();
+
+            assert!(matches!(error, ClientError::Transport(_)));
+
+            Ok(())
+        }
+    }
+
+    mod get_wallpapers {
+        use super::*;
+
+        #[tokio::test]
+        async fn get_wallpapers_does_not_send_filter_date_day_if_not_provided()
         -> Result<(), Box<dyn Error>> {
             let mut server = mockito::Server::new_async().await;
 
@@ -884,7 +1787,7 @@ mod tests {
                     "GET",
                     Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
                 )
-                .match_query(Matcher::Regex("[^filter_res_height]".to_string()))
+                .match_query(Matcher::Regex("[^filter_date_day]".to_string()))
                 .match_header("Authorization", "Bearer api_key")
                 .with_status(200)
                 .with_header("content-type", "application/json")
@@ -907,19 +1810,18 @@ mod tests {
         }
 
         #[tokio::test]
-        async fn get_wallpapers_does_send_filter_res_height_if_provided()
-        -> Result<(), Box<dyn Error>> {
+        async fn get_wallpapers_does_send_filter_date_day_if_provided() -> Result<(), Box<dyn Error>>
+        {
             let mut server = mockito::Server::new_async().await;
 
-            let get_wallpapers_request =
-                GetWallpapersRequest::builder().filter_res_height(1).build();
+            let get_wallpapers_request = GetWallpapersRequest::builder().filter_date_day(1).build();
 
             let mock = server
                 .mock(
                     "GET",
                     Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
                 )
-                .match_query(Matcher::Regex("filter_res_height=1".to_string()))
+                .match_query(Matcher::Regex("filter_date_day=1".to_string()))
                 .match_header("Authorization", "Bearer api_key")
                 .with_status(200)
                 .with_header("content-type", "application/json")
@@ -942,7 +1844,7 @@ mod tests {
         }
 
         #[tokio::test]
-        async fn get_wallpapers_does_send_filter_res_operator_if_not_provided()
+        async fn get_wallpapers_does_not_send_filter_date_month_if_not_provided()
         -> Result<(), Box<dyn Error>> {
             let mut server = mockito::Server::new_async().await;
 
@@ -953,7 +1855,7 @@ mod tests {
                     "GET",
                     Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
                 )
-                .match_query(Matcher::Regex("filter_res_operator=%3E%3D".to_string()))
+                .match_query(Matcher::Regex("[^filter_date_month]".to_string()))
                 .match_header("Authorization", "Bearer api_key")
                 .with_status(200)
                 .with_header("content-type", "application/json")
@@ -976,20 +1878,19 @@ mod tests {
         }
 
         #[tokio::test]
-        async fn get_wallpapers_does_send_filter_res_operator_if_provided()
+        async fn get_wallpapers_does_send_filter_date_month_if_provided()
         -> Result<(), Box<dyn Error>> {
             let mut server = mockito::Server::new_async().await;
 
-            let get_wallpapers_request = GetWallpapersRequest::builder()
-                .filter_res_operator(Operator::Equal)
-                .build();
+            let get_wallpapers_request =
+                GetWallpapersRequest::builder().filter_date_month(1).build();
 
             let mock = server
                 .mock(
                     "GET",
                     Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
                 )
-                .match_query(Matcher::Regex("filter_res_operator=%3D".to_string()))
+                .match_query(Matcher::Regex("filter_date_month=1".to_string()))
                 .match_header("Authorization", "Bearer api_key")
                 .with_status(200)
                 .with_header("content-type", "application/json")
@@ -1012,7 +1913,7 @@ mod tests {
         }
 
         #[tokio::test]
-        async fn get_wallpapers_does_send_filter_res_operator_height_if_not_provided()
+        async fn get_wallpapers_does_not_send_filter_date_year_if_not_provided()
         -> Result<(), Box<dyn Error>> {
             let mut server = mockito::Server::new_async().await;
 
@@ -1023,9 +1924,7 @@ mod tests {
                     "GET",
                     Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
                 )
-                .match_query(Matcher::Regex(
-                    "filter_res_operator_height=%3E%3D".to_string(),
-                ))
+                .match_query(Matcher::Regex("[^filter_date_year]".to_string()))
                 .match_header("Authorization", "Bearer api_key")
                 .with_status(200)
                 .with_header("content-type", "application/json")
@@ -1048,12 +1947,12 @@ mod tests {
         }
 
         #[tokio::test]
-        async fn get_wallpapers_does_send_filter_res_operator_height_if_provided()
+        async fn get_wallpapers_does_send_filter_date_year_if_provided()
         -> Result<(), Box<dyn Error>> {
             let mut server = mockito::Server::new_async().await;
 
             let get_wallpapers_request = GetWallpapersRequest::builder()
-                .filter_res_operator_height(Operator::Equal)
+                .filter_date_year(1997)
                 .build();
 
             let mock = server
@@ -1061,7 +1960,7 @@ mod tests {
                     "GET",
                     Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
                 )
-                .match_query(Matcher::Regex("filter_res_operator_height=%3D".to_string()))
+                .match_query(Matcher::Regex("filter_date_year=1997".to_string()))
                 .match_header("Authorization", "Bearer api_key")
                 .with_status(200)
                 .with_header("content-type", "application/json")
@@ -1084,7 +1983,7 @@ mod tests {
         }
 
         #[tokio::test]
-        async fn get_wallpapers_does_send_filter_res_operator_width_if_not_provided()
+        async fn get_wallpapers_does_send_filter_date_operator_if_not_provided()
         -> Result<(), Box<dyn Error>> {
             let mut server = mockito::Server::new_async().await;
 
@@ -1095,9 +1994,7 @@ mod tests {
                     "GET",
                     Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
                 )
-                .match_query(Matcher::Regex(
-                    "filter_res_operator_width=%3E%3D".to_string(),
-                ))
+                .match_query(Matcher::Regex("filter_date_operator=%3E%3D".to_string()))
                 .match_header("Authorization", "Bearer api_key")
                 .with_status(200)
                 .with_header("content-type", "application/json")
@@ -1120,12 +2017,12 @@ mod tests {
         }
 
         #[tokio::test]
-        async fn get_wallpapers_does_send_filter_res_operator_width_if_provided()
+        async fn get_wallpapers_does_send_filter_date_operator_if_provided()
         -> Result<(), Box<dyn Error>> {
             let mut server = mockito::Server::new_async().await;
 
             let get_wallpapers_request = GetWallpapersRequest::builder()
-                .filter_res_operator_width(Operator::Equal)
+                .filter_date_operator(Operator::Equal)
                 .build();
 
             let mock = server
@@ -1133,7 +2030,7 @@ mod tests {
                     "GET",
                     Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
                 )
-                .match_query(Matcher::Regex("filter_res_operator_width=%3D".to_string()))
+                .match_query(Matcher::Regex("filter_date_operator=%3D".to_string()))
                 .match_header("Authorization", "Bearer api_key")
                 .with_status(200)
                 .with_header("content-type", "application/json")
@@ -1156,7 +2053,7 @@ mod tests {
         }
 
         #[tokio::test]
-        async fn get_wallpapers_does_not_send_filter_res_width_if_not_provided()
+        async fn get_wallpapers_does_not_send_filter_gallery_if_not_provided()
         -> Result<(), Box<dyn Error>> {
             let mut server = mockito::Server::new_async().await;
 
@@ -1167,7 +2064,7 @@ mod tests {
                     "GET",
                     Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
                 )
-                .match_query(Matcher::Regex("[^filter_res_width]".to_string()))
+                .match_query(Matcher::Regex("[^filter_gallery]".to_string()))
                 .match_header("Authorization", "Bearer api_key")
                 .with_status(200)
                 .with_header("content-type", "application/json")
@@ -1190,19 +2087,22 @@ mod tests {
         }
 
         #[tokio::test]
-        async fn get_wallpapers_does_send_filter_res_width_if_provided()
-        -> Result<(), Box<dyn Error>> {
+        async fn get_wallpapers_does_send_filter_gallery_if_provided() -> Result<(), Box<dyn Error>>
+        {
             let mut server = mockito::Server::new_async().await;
 
-            let get_wallpapers_request =
-                GetWallpapersRequest::builder().filter_res_width(1).build();
+            let get_wallpapers_request = GetWallpapersRequest::builder()
+                .filter_gallery(vec![1, 2])
+                .build();
 
             let mock = server
                 .mock(
                     "GET",
                     Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
                 )
-                .match_query(Matcher::Regex("filter_res_width=1".to_string()))
+                .match_query(Matcher::Regex(
+                    "filter_gallery=1&filter_gallery=2".to_string(),
+                ))
                 .match_header("Authorization", "Bearer api_key")
                 .with_status(200)
                 .with_header("content-type", "application/json")
@@ -1225,7 +2125,7 @@ mod tests {
         }
 
         #[tokio::test]
-        async fn get_wallpapers_does_not_send_filter_tag_if_not_provided()
+        async fn get_wallpapers_does_not_send_filter_rating_if_not_provided()
         -> Result<(), Box<dyn Error>> {
             let mut server = mockito::Server::new_async().await;
 
@@ -1236,7 +2136,7 @@ mod tests {
                     "GET",
                     Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
                 )
-                .match_query(Matcher::Regex("[^filter_tag]".to_string()))
+                .match_query(Matcher::Regex("[^filter_rating]".to_string()))
                 .match_header("Authorization", "Bearer api_key")
                 .with_status(200)
                 .with_header("content-type", "application/json")
@@ -1259,19 +2159,18 @@ mod tests {
         }
 
         #[tokio::test]
-        async fn get_wallpapers_does_send_filter_tag_if_provided() -> Result<(), Box<dyn Error>> {
+        async fn get_wallpapers_does_send_filter_rating_if_provided() -> Result<(), Box<dyn Error>>
+        {
             let mut server = mockito::Server::new_async().await;
 
-            let get_wallpapers_request = GetWallpapersRequest::builder()
-                .filter_tag(vec![1, 2])
-                .build();
+            let get_wallpapers_request = GetWallpapersRequest::builder().filter_rating(1.2).build();
 
             let mock = server
                 .mock(
                     "GET",
                     Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
                 )
-                .match_query(Matcher::Regex("filter_tag=1&filter_tag=2".to_string()))
+                .match_query(Matcher::Regex("filter_rating=1.2".to_string()))
                 .match_header("Authorization", "Bearer api_key")
                 .with_status(200)
                 .with_header("content-type", "application/json")
@@ -1294,7 +2193,8 @@ mod tests {
         }
 
         #[tokio::test]
-        async fn get_wallpapers_does_send_limit_if_not_provided() -> Result<(), Box<dyn Error>> {
+        async fn get_wallpapers_does_send_filter_rating_operator_if_not_provided()
+        -> Result<(), Box<dyn Error>> {
             let mut server = mockito::Server::new_async().await;
 
             let get_wallpapers_request = GetWallpapersRequest::builder().build();
@@ -1304,7 +2204,7 @@ mod tests {
                     "GET",
                     Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
                 )
-                .match_query(Matcher::Regex("limit=10".to_string()))
+                .match_query(Matcher::Regex("filter_rating_operator=%3E%3D".to_string()))
                 .match_header("Authorization", "Bearer api_key")
                 .with_status(200)
                 .with_header("content-type", "application/json")
@@ -1327,17 +2227,20 @@ mod tests {
         }
 
         #[tokio::test]
-        async fn get_wallpapers_does_send_limit_if_provided() -> Result<(), Box<dyn Error>> {
+        async fn get_wallpapers_does_send_filter_rating_operator_if_provided()
+        -> Result<(), Box<dyn Error>> {
             let mut server = mockito::Server::new_async().await;
 
-            let get_wallpapers_request = GetWallpapersRequest::builder().limit(1).build();
+            let get_wallpapers_request = GetWallpapersRequest::builder()
+                .filter_rating_operator(Operator::Equal)
+                .build();
 
             let mock = server
                 .mock(
                     "GET",
                     Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
                 )
-                .match_query(Matcher::Regex("limit=1".to_string()))
+                .match_query(Matcher::Regex("filter_rating_operator=%3D".to_string()))
                 .match_header("Authorization", "Bearer api_key")
                 .with_status(200)
                 .with_header("content-type", "application/json")
@@ -1360,7 +2263,8 @@ mod tests {
         }
 
         #[tokio::test]
-        async fn get_wallpapers_does_send_order_if_not_provided() -> Result<(), Box<dyn Error>> {
+        async fn get_wallpapers_does_not_send_filter_res_height_if_not_provided()
+        -> Result<(), Box<dyn Error>> {
             let mut server = mockito::Server::new_async().await;
 
             let get_wallpapers_request = GetWallpapersRequest::builder().build();
@@ -1370,7 +2274,7 @@ mod tests {
                     "GET",
                     Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
                 )
-                .match_query(Matcher::Regex("order=asc".to_string()))
+                .match_query(Matcher::Regex("[^filter_res_height]".to_string()))
                 .match_header("Authorization", "Bearer api_key")
                 .with_status(200)
                 .with_header("content-type", "application/json")
@@ -1393,19 +2297,19 @@ mod tests {
         }
 
         #[tokio::test]
-        async fn get_wallpapers_does_send_order_if_provided() -> Result<(), Box<dyn Error>> {
+        async fn get_wallpapers_does_send_filter_res_height_if_provided()
+        -> Result<(), Box<dyn Error>> {
             let mut server = mockito::Server::new_async().await;
 
-            let get_wallpapers_request = GetWallpapersRequest::builder()
-                .order(Order::Descending)
-                .build();
+            let get_wallpapers_request =
+                GetWallpapersRequest::builder().filter_res_height(1).build();
 
             let mock = server
                 .mock(
                     "GET",
                     Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
                 )
-                .match_query(Matcher::Regex("order=desc".to_string()))
+                .match_query(Matcher::Regex("filter_res_height=1".to_string()))
                 .match_header("Authorization", "Bearer api_key")
                 .with_status(200)
                 .with_header("content-type", "application/json")
@@ -1428,19 +2332,18 @@ mod tests {
         }
 
         #[tokio::test]
-        async fn get_wallpapers_does_not_send_order_by_if_date() -> Result<(), Box<dyn Error>> {
+        async fn get_wallpapers_does_send_filter_res_operator_if_not_provided()
+        -> Result<(), Box<dyn Error>> {
             let mut server = mockito::Server::new_async().await;
 
-            let get_wallpapers_request = GetWallpapersRequest::builder()
-                .order_by(GetWallpapersOrderBy::Date)
-                .build();
+            let get_wallpapers_request = GetWallpapersRequest::builder().build();
 
             let mock = server
                 .mock(
                     "GET",
                     Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
                 )
-                .match_query(Matcher::Regex("[^order_by]".to_string()))
+                .match_query(Matcher::Regex("filter_res_operator=%3E%3D".to_string()))
                 .match_header("Authorization", "Bearer api_key")
                 .with_status(200)
                 .with_header("content-type", "application/json")
@@ -1463,11 +2366,12 @@ mod tests {
         }
 
         #[tokio::test]
-        async fn get_wallpapers_does_send_order_by_if_not_date() -> Result<(), Box<dyn Error>> {
+        async fn get_wallpapers_does_send_filter_res_operator_if_provided()
+        -> Result<(), Box<dyn Error>> {
             let mut server = mockito::Server::new_async().await;
 
             let get_wallpapers_request = GetWallpapersRequest::builder()
-                .order_by(GetWallpapersOrderBy::Name)
+                .filter_res_operator(Operator::Equal)
                 .build();
 
             let mock = server
@@ -1475,7 +2379,7 @@ mod tests {
                     "GET",
                     Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
                 )
-                .match_query(Matcher::Regex("order_by=name".to_string()))
+                .match_query(Matcher::Regex("filter_res_operator=%3D".to_string()))
                 .match_header("Authorization", "Bearer api_key")
                 .with_status(200)
                 .with_header("content-type", "application/json")
@@ -1498,7 +2402,8 @@ mod tests {
         }
 
         #[tokio::test]
-        async fn get_wallpapers_does_send_page_if_not_provided() -> Result<(), Box<dyn Error>> {
+        async fn get_wallpapers_does_send_filter_res_operator_height_if_not_provided()
+        -> Result<(), Box<dyn Error>> {
             let mut server = mockito::Server::new_async().await;
 
             let get_wallpapers_request = GetWallpapersRequest::builder().build();
@@ -1508,7 +2413,9 @@ mod tests {
                     "GET",
                     Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
                 )
-                .match_query(Matcher::Regex("page=1".to_string()))
+                .match_query(Matcher::Regex(
+                    "filter_res_operator_height=%3E%3D".to_string(),
+                ))
                 .match_header("Authorization", "Bearer api_key")
                 .with_status(200)
                 .with_header("content-type", "application/json")
@@ -1531,17 +2438,20 @@ mod tests {
         }
 
         #[tokio::test]
-        async fn get_wallpapers_does_send_page_if_provided() -> Result<(), Box<dyn Error>> {
+        async fn get_wallpapers_does_send_filter_res_operator_height_if_provided()
+        -> Result<(), Box<dyn Error>> {
             let mut server = mockito::Server::new_async().await;
 
-            let get_wallpapers_request = GetWallpapersRequest::builder().page(2).build();
+            let get_wallpapers_request = GetWallpapersRequest::builder()
+                .filter_res_operator_height(Operator::Equal)
+                .build();
 
             let mock = server
                 .mock(
                     "GET",
                     Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
                 )
-                .match_query(Matcher::Regex("page=2".to_string()))
+                .match_query(Matcher::Regex("filter_res_operator_height=%3D".to_string()))
                 .match_header("Authorization", "Bearer api_key")
                 .with_status(200)
                 .with_header("content-type", "application/json")
@@ -1564,7 +2474,8 @@ mod tests {
         }
 
         #[tokio::test]
-        async fn get_wallpapers_does_not_send_s_if_not_provided() -> Result<(), Box<dyn Error>> {
+        async fn get_wallpapers_does_send_filter_res_operator_width_if_not_provided()
+        -> Result<(), Box<dyn Error>> {
             let mut server = mockito::Server::new_async().await;
 
             let get_wallpapers_request = GetWallpapersRequest::builder().build();
@@ -1574,7 +2485,9 @@ mod tests {
                     "GET",
                     Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
                 )
-                .match_query(Matcher::Regex("[^s]".to_string()))
+                .match_query(Matcher::Regex(
+                    "filter_res_operator_width=%3E%3D".to_string(),
+                ))
                 .match_header("Authorization", "Bearer api_key")
                 .with_status(200)
                 .with_header("content-type", "application/json")
@@ -1597,11 +2510,12 @@ mod tests {
         }
 
         #[tokio::test]
-        async fn get_wallpapers_does_send_s_if_provided() -> Result<(), Box<dyn Error>> {
+        async fn get_wallpapers_does_send_filter_res_operator_width_if_provided()
+        -> Result<(), Box<dyn Error>> {
             let mut server = mockito::Server::new_async().await;
 
             let get_wallpapers_request = GetWallpapersRequest::builder()
-                .s("search".to_string())
+                .filter_res_operator_width(Operator::Equal)
                 .build();
 
             let mock = server
@@ -1609,7 +2523,7 @@ mod tests {
                     "GET",
                     Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
                 )
-                .match_query(Matcher::Regex("s=search".to_string()))
+                .match_query(Matcher::Regex("filter_res_operator_width=%3D".to_string()))
                 .match_header("Authorization", "Bearer api_key")
                 .with_status(200)
                 .with_header("content-type", "application/json")
@@ -1632,7 +2546,7 @@ mod tests {
         }
 
         #[tokio::test]
-        async fn get_wallpapers_does_send_show_comments_if_not_provided()
+        async fn get_wallpapers_does_not_send_filter_res_width_if_not_provided()
         -> Result<(), Box<dyn Error>> {
             let mut server = mockito::Server::new_async().await;
 
@@ -1643,7 +2557,7 @@ mod tests {
                     "GET",
                     Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
                 )
-                .match_query(Matcher::Regex("show_comments=false".to_string()))
+                .match_query(Matcher::Regex("[^filter_res_width]".to_string()))
                 .match_header("Authorization", "Bearer api_key")
                 .with_status(200)
                 .with_header("content-type", "application/json")
@@ -1666,19 +2580,19 @@ mod tests {
         }
 
         #[tokio::test]
-        async fn get_wallpapers_does_send_show_comments_if_provided() -> Result<(), Box<dyn Error>>
-        {
+        async fn get_wallpapers_does_send_filter_res_width_if_provided()
+        -> Result<(), Box<dyn Error>> {
             let mut server = mockito::Server::new_async().await;
 
             let get_wallpapers_request =
-                GetWallpapersRequest::builder().show_comments(true).build();
+                GetWallpapersRequest::builder().filter_res_width(1).build();
 
             let mock = server
                 .mock(
                     "GET",
                     Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
                 )
-                .match_query(Matcher::Regex("show_comments=true".to_string()))
+                .match_query(Matcher::Regex("filter_res_width=1".to_string()))
                 .match_header("Authorization", "Bearer api_key")
                 .with_status(200)
                 .with_header("content-type", "application/json")
@@ -1701,7 +2615,7 @@ mod tests {
         }
 
         #[tokio::test]
-        async fn get_wallpapers_does_send_show_pickle_jar_if_not_provided()
+        async fn get_wallpapers_does_not_send_filter_tag_if_not_provided()
         -> Result<(), Box<dyn Error>> {
             let mut server = mockito::Server::new_async().await;
 
@@ -1712,7 +2626,7 @@ mod tests {
                     "GET",
                     Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
                 )
-                .match_query(Matcher::Regex("show_pickle_jar=false".to_string()))
+                .match_query(Matcher::Regex("[^filter_tag]".to_string()))
                 .match_header("Authorization", "Bearer api_key")
                 .with_status(200)
                 .with_header("content-type", "application/json")
@@ -1735,12 +2649,11 @@ mod tests {
         }
 
         #[tokio::test]
-        async fn get_wallpapers_does_send_show_pickle_jar_if_provided() -> Result<(), Box<dyn Error>>
-        {
+        async fn get_wallpapers_does_send_filter_tag_if_provided() -> Result<(), Box<dyn Error>> {
             let mut server = mockito::Server::new_async().await;
 
             let get_wallpapers_request = GetWallpapersRequest::builder()
-                .show_pickle_jar(true)
+                .filter_tag(vec![1, 2])
                 .build();
 
             let mock = server
@@ -1748,7 +2661,7 @@ mod tests {
                     "GET",
                     Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
                 )
-                .match_query(Matcher::Regex("show_pickle_jar=true".to_string()))
+                .match_query(Matcher::Regex("filter_tag=1&filter_tag=2".to_string()))
                 .match_header("Authorization", "Bearer api_key")
                 .with_status(200)
                 .with_header("content-type", "application/json")
@@ -1771,8 +2684,7 @@ mod tests {
         }
 
         #[tokio::test]
-        async fn get_wallpapers_does_send_show_resolutions_if_not_provided()
-        -> Result<(), Box<dyn Error>> {
+        async fn get_wallpapers_does_send_limit_if_not_provided() -> Result<(), Box<dyn Error>> {
             let mut server = mockito::Server::new_async().await;
 
             let get_wallpapers_request = GetWallpapersRequest::builder().build();
@@ -1782,7 +2694,7 @@ mod tests {
                     "GET",
                     Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
                 )
-                .match_query(Matcher::Regex("show_resolutions=true".to_string()))
+                .match_query(Matcher::Regex("limit=10".to_string()))
                 .match_header("Authorization", "Bearer api_key")
                 .with_status(200)
                 .with_header("content-type", "application/json")
@@ -1805,12 +2717,77 @@ mod tests {
         }
 
         #[tokio::test]
-        async fn get_wallpapers_does_send_show_resolutions_if_provided()
-        -> Result<(), Box<dyn Error>> {
+        async fn get_wallpapers_does_send_limit_if_provided() -> Result<(), Box<dyn Error>> {
+            let mut server = mockito::Server::new_async().await;
+
+            let get_wallpapers_request = GetWallpapersRequest::builder().limit(1).build();
+
+            let mock = server
+                .mock(
+                    "GET",
+                    Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
+                )
+                .match_query(Matcher::Regex("limit=1".to_string()))
+                .match_header("Authorization", "Bearer api_key")
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(fs::read_to_string(
+                    "resources/get_wallpapers_success_fully_populated.json",
+                )?)
+                .create_async()
+                .await;
+
+            let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
+
+            client
+                .get_wallpapers(&get_wallpapers_request)
+                .await
+                .unwrap();
+
+            mock.assert_async().await;
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn get_wallpapers_does_send_order_if_not_provided() -> Result<(), Box<dyn Error>> {
+            let mut server = mockito::Server::new_async().await;
+
+            let get_wallpapers_request = GetWallpapersRequest::builder().build();
+
+            let mock = server
+                .mock(
+                    "GET",
+                    Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
+                )
+                .match_query(Matcher::Regex("order=asc".to_string()))
+                .match_header("Authorization", "Bearer api_key")
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(fs::read_to_string(
+                    "resources/get_wallpapers_success_fully_populated.json",
+                )?)
+                .create_async()
+                .await;
+
+            let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
+
+            client
+                .get_wallpapers(&get_wallpapers_request)
+                .await
+                .unwrap();
+
+            mock.assert_async().await;
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn get_wallpapers_does_send_order_if_provided() -> Result<(), Box<dyn Error>> {
             let mut server = mockito::Server::new_async().await;
 
             let get_wallpapers_request = GetWallpapersRequest::builder()
-                .show_resolutions(false)
+                .order(Order::Descending)
                 .build();
 
             let mock = server
@@ -1818,7 +2795,7 @@ mod tests {
                     "GET",
                     Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
                 )
-                .match_query(Matcher::Regex("show_resolutions=false".to_string()))
+                .match_query(Matcher::Regex("order=desc".to_string()))
                 .match_header("Authorization", "Bearer api_key")
                 .with_status(200)
                 .with_header("content-type", "application/json")
@@ -1841,8 +2818,77 @@ mod tests {
         }
 
         #[tokio::test]
-        async fn get_wallpapers_can_map_successful_response_fully_populated()
-        -> Result<(), Box<dyn Error>> {
+        async fn get_wallpapers_does_not_send_order_by_if_date() -> Result<(), Box<dyn Error>> {
+            let mut server = mockito::Server::new_async().await;
+
+            let get_wallpapers_request = GetWallpapersRequest::builder()
+                .order_by(GetWallpapersOrderBy::Date)
+                .build();
+
+            let mock = server
+                .mock(
+                    "GET",
+                    Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
+                )
+                .match_query(Matcher::Regex("[^order_by]".to_string()))
+                .match_header("Authorization", "Bearer api_key")
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(fs::read_to_string(
+                    "resources/get_wallpapers_success_fully_populated.json",
+                )?)
+                .create_async()
+                .await;
+
+            let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
+
+            client
+                .get_wallpapers(&get_wallpapers_request)
+                .await
+                .unwrap();
+
+            mock.assert_async().await;
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn get_wallpapers_does_send_order_by_if_not_date() -> Result<(), Box<dyn Error>> {
+            let mut server = mockito::Server::new_async().await;
+
+            let get_wallpapers_request = GetWallpapersRequest::builder()
+                .order_by(GetWallpapersOrderBy::Name)
+                .build();
+
+            let mock = server
+                .mock(
+                    "GET",
+                    Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
+                )
+                .match_query(Matcher::Regex("order_by=name".to_string()))
+                .match_header("Authorization", "Bearer api_key")
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(fs::read_to_string(
+                    "resources/get_wallpapers_success_fully_populated.json",
+                )?)
+                .create_async()
+                .await;
+
+            let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
+
+            client
+                .get_wallpapers(&get_wallpapers_request)
+                .await
+                .unwrap();
+
+            mock.assert_async().await;
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn get_wallpapers_does_send_page_if_not_provided() -> Result<(), Box<dyn Error>> {
             let mut server = mockito::Server::new_async().await;
 
             let get_wallpapers_request = GetWallpapersRequest::builder().build();
@@ -1850,8 +2896,9 @@ mod tests {
             let mock = server
                 .mock(
                     "GET",
-                    mockito::Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
+                    Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
                 )
+                .match_query(Matcher::Regex("page=1".to_string()))
                 .match_header("Authorization", "Bearer api_key")
                 .with_status(200)
                 .with_header("content-type", "application/json")
@@ -1863,279 +2910,43 @@ mod tests {
 
             let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
 
-            let get_wallpapers_response = client
+            client
                 .get_wallpapers(&get_wallpapers_request)
                 .await
                 .unwrap();
 
-            let expected_wallpaper_13 = Wallpaper {
-                id: 13,
-                all_free: Some(true),
-                comments: Some(Comments {
-                    comments: vec![
-                        Comment {
-                            id: "14".to_string(),
-                            author_id: "author ID 1".to_string(),
-                            author_display: "author display 1".to_string(),
-                            content: "Content 1".to_string(),
-                            rating: "15".to_string(),
-                            timestamp: 16,
-                        },
-                        Comment {
-                            id: "17".to_string(),
-                            author_id: "author ID 2".to_string(),
-                            author_display: "author display 2".to_string(),
-                            content: "Content 2".to_string(),
-                            rating: "18".to_string(),
-                            timestamp: 19,
-                        },
-                    ],
-                }),
-                content: Some("Content 3".to_string()),
-                free: Some(true),
-                name: "Vulcan".to_string(),
-                paths: Paths {
-                    api: "/wallpaper/13".to_string(),
-                    thumb: "/thumbnail/21x22/vulcan_thumbnail_21x22.jpg".to_string(),
-                    web: "/sec/vulcan/".to_string(),
-                },
-                pickle_jar: Some(PickleJar {
-                    parent: "parent 1".to_string(),
-                    siblings: vec!["sibling 1".to_string(), "sibling 2".to_string()],
-                }),
-                rating: Some("20".to_string()),
-                resolutions: Some(Resolutions {
-                    single: vec![
-                        Resolution {
-                            label: "21x22".to_string(),
-                            width: "21".to_string(),
-                            height: "22".to_string(),
-                            image: "/single/21x22/vulcan_single_21x22.jpg".to_string(),
-                        },
-                        Resolution {
-                            label: "23x24".to_string(),
-                            width: "23".to_string(),
-                            height: "24".to_string(),
-                            image: "/single/23x24/vulcan_single_23x24.jpg".to_string(),
-                        },
-                    ],
-                    dual: Some(vec![
-                        Resolution {
-                            label: "25x26".to_string(),
-                            width: "25".to_string(),
-                            height: "26".to_string(),
-                            image: "/dual/25x26/vulcan_dual_25x26.jpg".to_string(),
-                        },
-                        Resolution {
-                            label: "27x28".to_string(),
-                            width: "27".to_string(),
-                            height: "28".to_string(),
-                            image: "/dual/27x28/vulcan_dual_27x28.jpg".to_string(),
-                        },
-                    ]),
-                    triple: Some(vec![
-                        Resolution {
-                            label: "29x30".to_string(),
-                            width: "29".to_string(),
-                            height: "30".to_string(),
-                            image: "/triple/29x30/vulcan_triple_29x30.jpg".to_string(),
-                        },
-                        Resolution {
-                            label: "31x32".to_string(),
-                            width: "31".to_string(),
-                            height: "32".to_string(),
-                            image: "/triple/31x32/vulcan_triple_31x32.jpg".to_string(),
-                        },
-                    ]),
-                    mobile: Some(vec![
-                        Resolution {
-                            label: "33x34".to_string(),
-                            width: "33".to_string(),
-                            height: "34".to_string(),
-                            image: "/mobile/33x34/vulcan_mobile_33x34.jpg".to_string(),
-                        },
-                        Resolution {
-                            label: "35x36".to_string(),
-                            width: "35".to_string(),
-                            height: "36".to_string(),
-                            image: "/mobile/35x36/vulcan_mobile_35x36.jpg".to_string(),
-                        },
-                    ]),
-                }),
-                sku: Some("vulcan".to_string()),
-                tags: Some(HashMap::from([
-                    (
-                        "37".to_string(),
-                        Tag {
-                            id: 37,
-                            name: "Tag 1".to_string(),
-                        },
-                    ),
-                    (
-                        "38".to_string(),
-                        Tag {
-                            id: 38,
-                            name: "Tag 2".to_string(),
-                        },
-                    ),
-                ])),
-                timestamp: Some(39),
-            };
-            let expected_wallpaper_40 = Wallpaper {
-                id: 40,
-                all_free: Some(false),
-                comments: Some(Comments {
-                    comments: vec![
-                        Comment {
-                            id: "41".to_string(),
-                            author_id: "author ID 3".to_string(),
-                            author_display: "author display 3".to_string(),
-                            content: "Content 4".to_string(),
-                            rating: "42".to_string(),
-                            timestamp: 43,
-                        },
-                        Comment {
-                            id: "44".to_string(),
-                            author_id: "author ID 4".to_string(),
-                            author_display: "author display 4".to_string(),
-                            content: "Content 5".to_string(),
-                            rating: "45".to_string(),
-                            timestamp: 46,
-                        },
-                    ],
-                }),
-                content: Some("Content 6".to_string()),
-                free: Some(false),
-                name: "Valley I".to_string(),
-                paths: Paths {
-                    api: "/wallpaper/40".to_string(),
-                    thumb: "/thumbnail/48x49/valley_thumbnail_48x49.jpg".to_string(),
-                    web: "/sec/valley/".to_string(),
-                },
-                pickle_jar: Some(PickleJar {
-                    parent: "parent 2".to_string(),
-                    siblings: vec!["sibling 3".to_string(), "sibling 4".to_string()],
-                }),
-                rating: Some("47".to_string()),
-                resolutions: Some(Resolutions {
-                    single: vec![
-                        Resolution {
-                            label: "48x49".to_string(),
-                            width: "48".to_string(),
-                            height: "49".to_string(),
-                            image: "/single/48x49/valley_single_48x49.jpg".to_string(),
-                        },
-                        Resolution {
-                            label: "50x51".to_string(),
-                            width: "50".to_string(),
-                            height: "51".to_string(),
-                            image: "/single/50x51/valley_single_50x51.jpg".to_string(),
-                        },
-                    ],
-                    dual: Some(vec![
-                        Resolution {
-                            label: "52x53".to_string(),
-                            width: "52".to_string(),
-                            height: "53".to_string(),
-                            image: "/dual/52x53/valley_dual_52x53.jpg".to_string(),
-                        },
-                        Resolution {
-                            label: "54x55".to_string(),
-                            width: "54".to_string(),
-                            height: "55".to_string(),
-                            image: "/dual/54x55/valley_dual_54x55.jpg".to_string(),
-                        },
-                    ]),
-                    triple: Some(vec![
-                        Resolution {
-                            label: "56x57".to_string(),
-                            width: "56".to_string(),
-                            height: "57".to_string(),
-                            image: "/triple/56x57/valley_triple_56x57.jpg".to_string(),
-                        },
-                        Resolution {
-                            label: "58x59".to_string(),
-                            width: "58".to_string(),
-                            height: "59".to_string(),
-                            image: "/triple/58x59/valley_triple_58x59.jpg".to_string(),
-                        },
-                    ]),
-                    mobile: Some(vec![
-                        Resolution {
-                            label: "60x61".to_string(),
-                            width: "60".to_string(),
-                            height: "61".to_string(),
-                            image: "/mobile/60x61/valley_mobile_60x61.jpg".to_string(),
-                        },
-                        Resolution {
-                            label: "62x63".to_string(),
-                            width: "62".to_string(),
-                            height: "63".to_string(),
-                            image: "/mobile/62x63/valley_mobile_62x63.jpg".to_string(),
-                        },
-                    ]),
-                }),
-                sku: Some("valley".to_string()),
-                tags: Some(HashMap::from([
-                    (
-                        "65".to_string(),
-                        Tag {
-                            id: 65,
-                            name: "Tag 4".to_string(),
-                        },
-                    ),
-                    (
-                        "64".to_string(),
-                        Tag {
-                            id: 64,
-                            name: "Tag 3".to_string(),
-                        },
-                    ),
-                ])),
-                timestamp: Some(66),
-            };
-            let expected_get_wallpapers_response = GetWallpapersResponse {
-                db_core: GetWallpapersDBCore {
-                    timestamp: 1,
-                    endpoints: Endpoints {
-                        api: "https://api.digitalblasphemy.com/v2/core".to_string(),
-                        image: "https://arcadia.digitalblasphemy.com".to_string(),
-                        thumb: "https://cdn.digitalblasphemy.com".to_string(),
-                        web: "https://digitalblasphemy.com".to_string(),
-                    },
-                    request: GetWallpapersDBCoreRequest {
-                        query: GetWallpapersResponseQuery {
-                            filter_date_day: Some(2),
-                            filter_date_month: Some(3),
-                            filter_date_year: Some(4),
-                            filter_date_operator: Operator::Equal,
-                            filter_gallery: Some(vec![5]),
-                            filter_rating: Some(6_f32),
-                            filter_rating_operator: Some(Operator::GreaterThanOrEqual),
-                            filter_res_operator_height: Some(Operator::GreaterThanOrEqual),
-                            filter_res_operator_width: Some(Operator::GreaterThanOrEqual),
-                            filter_res_height: 7,
-                            filter_res_operator: Operator::GreaterThanOrEqual,
-                            filter_res_width: 8,
-                            filter_tag: Some(vec![9]),
-                            limit: 10,
-                            order: Order::Ascending,
-                            order_by: GetWallpapersOrderBy::Name,
-                            page: 11,
-                            s: Some("search".to_string()),
-                            show_comments: true,
-                            show_pickle_jar: true,
-                            show_resolutions: true,
-                        },
-                    },
-                    total_pages: 12,
-                    wallpapers: HashMap::from([
-                        ("13".to_string(), expected_wallpaper_13),
-                        ("40".to_string(), expected_wallpaper_40),
-                    ]),
-                },
-            };
-            assert_eq!(get_wallpapers_response, expected_get_wallpapers_response);
+            mock.assert_async().await;
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn get_wallpapers_does_send_page_if_provided() -> Result<(), Box<dyn Error>> {
+            let mut server = mockito::Server::new_async().await;
+
+            let get_wallpapers_request = GetWallpapersRequest::builder().page(2).build();
+
+            let mock = server
+                .mock(
+                    "GET",
+                    Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
+                )
+                .match_query(Matcher::Regex("page=2".to_string()))
+                .match_header("Authorization", "Bearer api_key")
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(fs::read_to_string(
+                    "resources/get_wallpapers_success_fully_populated.json",
+                )?)
+                .create_async()
+                .await;
+
+            let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
+
+            client
+                .get_wallpapers(&get_wallpapers_request)
+                .await
+                .unwrap();
 
             mock.assert_async().await;
 
@@ -2143,8 +2954,7 @@ mod tests {
         }
 
         #[tokio::test]
-        async fn get_wallpapers_can_map_successful_response_minimal_populated()
-        -> Result<(), Box<dyn Error>> {
+        async fn get_wallpapers_does_not_send_s_if_not_provided() -> Result<(), Box<dyn Error>> {
             let mut server = mockito::Server::new_async().await;
 
             let get_wallpapers_request = GetWallpapersRequest::builder().build();
@@ -2154,171 +2964,2257 @@ mod tests {
                     "GET",
                     Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
                 )
+                .match_query(Matcher::Regex("[^s]".to_string()))
                 .match_header("Authorization", "Bearer api_key")
                 .with_status(200)
                 .with_header("content-type", "application/json")
                 .with_body(fs::read_to_string(
-                    "resources/get_wallpapers_success_minimal_populated.json",
+                    "resources/get_wallpapers_success_fully_populated.json",
                 )?)
                 .create_async()
                 .await;
 
             let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
 
-            let get_wallpapers_response = client
+            client
                 .get_wallpapers(&get_wallpapers_request)
                 .await
                 .unwrap();
 
-            let expected_wallpaper_7 = Wallpaper {
-                id: 7,
-                all_free: None,
-                comments: None,
-                content: None,
+            mock.assert_async().await;
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn get_wallpapers_does_send_s_if_provided() -> Result<(), Box<dyn Error>> {
+            let mut server = mockito::Server::new_async().await;
+
+            let get_wallpapers_request = GetWallpapersRequest::builder()
+                .s("search".to_string())
+                .build();
+
+            let mock = server
+                .mock(
+                    "GET",
+                    Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
+                )
+                .match_query(Matcher::Regex("s=search".to_string()))
+                .match_header("Authorization", "Bearer api_key")
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(fs::read_to_string(
+                    "resources/get_wallpapers_success_fully_populated.json",
+                )?)
+                .create_async()
+                .await;
+
+            let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
+
+            client
+                .get_wallpapers(&get_wallpapers_request)
+                .await
+                .unwrap();
+
+            mock.assert_async().await;
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn get_wallpapers_does_send_show_comments_if_not_provided()
+        -> Result<(), Box<dyn Error>> {
+            let mut server = mockito::Server::new_async().await;
+
+            let get_wallpapers_request = GetWallpapersRequest::builder().build();
+
+            let mock = server
+                .mock(
+                    "GET",
+                    Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
+                )
+                .match_query(Matcher::Regex("show_comments=false".to_string()))
+                .match_header("Authorization", "Bearer api_key")
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(fs::read_to_string(
+                    "resources/get_wallpapers_success_fully_populated.json",
+                )?)
+                .create_async()
+                .await;
+
+            let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
+
+            client
+                .get_wallpapers(&get_wallpapers_request)
+                .await
+                .unwrap();
+
+            mock.assert_async().await;
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn get_wallpapers_does_send_show_comments_if_provided() -> Result<(), Box<dyn Error>>
+        {
+            let mut server = mockito::Server::new_async().await;
+
+            let get_wallpapers_request =
+                GetWallpapersRequest::builder().show_comments(true).build();
+
+            let mock = server
+                .mock(
+                    "GET",
+                    Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
+                )
+                .match_query(Matcher::Regex("show_comments=true".to_string()))
+                .match_header("Authorization", "Bearer api_key")
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(fs::read_to_string(
+                    "resources/get_wallpapers_success_fully_populated.json",
+                )?)
+                .create_async()
+                .await;
+
+            let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
+
+            client
+                .get_wallpapers(&get_wallpapers_request)
+                .await
+                .unwrap();
+
+            mock.assert_async().await;
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn get_wallpapers_does_send_show_pickle_jar_if_not_provided()
+        -> Result<(), Box<dyn Error>> {
+            let mut server = mockito::Server::new_async().await;
+
+            let get_wallpapers_request = GetWallpapersRequest::builder().build();
+
+            let mock = server
+                .mock(
+                    "GET",
+                    Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
+                )
+                .match_query(Matcher::Regex("show_pickle_jar=false".to_string()))
+                .match_header("Authorization", "Bearer api_key")
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(fs::read_to_string(
+                    "resources/get_wallpapers_success_fully_populated.json",
+                )?)
+                .create_async()
+                .await;
+
+            let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
+
+            client
+                .get_wallpapers(&get_wallpapers_request)
+                .await
+                .unwrap();
+
+            mock.assert_async().await;
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn get_wallpapers_does_send_show_pickle_jar_if_provided() -> Result<(), Box<dyn Error>>
+        {
+            let mut server = mockito::Server::new_async().await;
+
+            let get_wallpapers_request = GetWallpapersRequest::builder()
+                .show_pickle_jar(true)
+                .build();
+
+            let mock = server
+                .mock(
+                    "GET",
+                    Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
+                )
+                .match_query(Matcher::Regex("show_pickle_jar=true".to_string()))
+                .match_header("Authorization", "Bearer api_key")
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(fs::read_to_string(
+                    "resources/get_wallpapers_success_fully_populated.json",
+                )?)
+                .create_async()
+                .await;
+
+            let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
+
+            client
+                .get_wallpapers(&get_wallpapers_request)
+                .await
+                .unwrap();
+
+            mock.assert_async().await;
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn get_wallpapers_does_send_show_resolutions_if_not_provided()
+        -> Result<(), Box<dyn Error>> {
+            let mut server = mockito::Server::new_async().await;
+
+            let get_wallpapers_request = GetWallpapersRequest::builder().build();
+
+            let mock = server
+                .mock(
+                    "GET",
+                    Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
+                )
+                .match_query(Matcher::Regex("show_resolutions=true".to_string()))
+                .match_header("Authorization", "Bearer api_key")
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(fs::read_to_string(
+                    "resources/get_wallpapers_success_fully_populated.json",
+                )?)
+                .create_async()
+                .await;
+
+            let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
+
+            client
+                .get_wallpapers(&get_wallpapers_request)
+                .await
+                .unwrap();
+
+            mock.assert_async().await;
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn get_wallpapers_does_send_show_resolutions_if_provided()
+        -> Result<(), Box<dyn Error>> {
+            let mut server = mockito::Server::new_async().await;
+
+            let get_wallpapers_request = GetWallpapersRequest::builder()
+                .show_resolutions(false)
+                .build();
+
+            let mock = server
+                .mock(
+                    "GET",
+                    Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
+                )
+                .match_query(Matcher::Regex("show_resolutions=false".to_string()))
+                .match_header("Authorization", "Bearer api_key")
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(fs::read_to_string(
+                    "resources/get_wallpapers_success_fully_populated.json",
+                )?)
+                .create_async()
+                .await;
+
+            let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
+
+            client
+                .get_wallpapers(&get_wallpapers_request)
+                .await
+                .unwrap();
+
+            mock.assert_async().await;
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn get_wallpapers_can_map_successful_response_fully_populated()
+        -> Result<(), Box<dyn Error>> {
+            let mut server = mockito::Server::new_async().await;
+
+            let get_wallpapers_request = GetWallpapersRequest::builder().build();
+
+            let mock = server
+                .mock(
+                    "GET",
+                    mockito::Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
+                )
+                .match_header("Authorization", "Bearer api_key")
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(fs::read_to_string(
+                    "resources/get_wallpapers_success_fully_populated.json",
+                )?)
+                .create_async()
+                .await;
+
+            let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
+
+            let get_wallpapers_response = client
+                .get_wallpapers(&get_wallpapers_request)
+                .await
+                .unwrap();
+
+            let expected_wallpaper_13 = Wallpaper {
+                id: WallpaperId(13),
+                all_free: Some(true),
+                comments: Some(Comments {
+                    comments: vec![
+                        Comment {
+                            id: 14,
+                            author_id: "author ID 1".to_string(),
+                            author_display: "author display 1".to_string(),
+                            content: "Content 1".to_string(),
+                            rating: 15.0,
+                            timestamp: 16,
+                        },
+                        Comment {
+                            id: 17,
+                            author_id: "author ID 2".to_string(),
+                            author_display: "author display 2".to_string(),
+                            content: "Content 2".to_string(),
+                            rating: 18.0,
+                            timestamp: 19,
+                        },
+                    ],
+                }),
+                content: Some("Content 3".to_string()),
+                free: Some(true),
+                name: "Vulcan".to_string(),
+                paths: Paths {
+                    api: "/wallpaper/13".to_string(),
+                    thumb: "/thumbnail/21x22/vulcan_thumbnail_21x22.jpg".to_string(),
+                    web: "/sec/vulcan/".to_string(),
+                },
+                pickle_jar: Some(PickleJar {
+                    parent: "parent 1".to_string(),
+                    siblings: vec!["sibling 1".to_string(), "sibling 2".to_string()],
+                }),
+                rating: Some(20.0),
+                resolutions: Some(Resolutions {
+                    single: vec![
+                        Resolution {
+                            label: "21x22".to_string(),
+                            width: 21,
+                            height: 22,
+                            image: "/single/21x22/vulcan_single_21x22.jpg".to_string(),
+                        },
+                        Resolution {
+                            label: "23x24".to_string(),
+                            width: 23,
+                            height: 24,
+                            image: "/single/23x24/vulcan_single_23x24.jpg".to_string(),
+                        },
+                    ],
+                    dual: Some(vec![
+                        Resolution {
+                            label: "25x26".to_string(),
+                            width: 25,
+                            height: 26,
+                            image: "/dual/25x26/vulcan_dual_25x26.jpg".to_string(),
+                        },
+                        Resolution {
+                            label: "27x28".to_string(),
+                            width: 27,
+                            height: 28,
+                            image: "/dual/27x28/vulcan_dual_27x28.jpg".to_string(),
+                        },
+                    ]),
+                    triple: Some(vec![
+                        Resolution {
+                            label: "29x30".to_string(),
+                            width: 29,
+                            height: 30,
+                            image: "/triple/29x30/vulcan_triple_29x30.jpg".to_string(),
+                        },
+                        Resolution {
+                            label: "31x32".to_string(),
+                            width: 31,
+                            height: 32,
+                            image: "/triple/31x32/vulcan_triple_31x32.jpg".to_string(),
+                        },
+                    ]),
+                    mobile: Some(vec![
+                        Resolution {
+                            label: "33x34".to_string(),
+                            width: 33,
+                            height: 34,
+                            image: "/mobile/33x34/vulcan_mobile_33x34.jpg".to_string(),
+                        },
+                        Resolution {
+                            label: "35x36".to_string(),
+                            width: 35,
+                            height: 36,
+                            image: "/mobile/35x36/vulcan_mobile_35x36.jpg".to_string(),
+                        },
+                    ]),
+                }),
+                sku: Some("vulcan".to_string()),
+                tags: Some(HashMap::from([
+                    (
+                        "37".to_string(),
+                        Tag {
+                            id: TagId(37),
+                            name: "Tag 1".to_string(),
+                        },
+                    ),
+                    (
+                        "38".to_string(),
+                        Tag {
+                            id: TagId(38),
+                            name: "Tag 2".to_string(),
+                        },
+                    ),
+                ])),
+                timestamp: Some(39),
+            };
+            let expected_wallpaper_40 = Wallpaper {
+                id: WallpaperId(40),
+                all_free: Some(false),
+                comments: Some(Comments {
+                    comments: vec![
+                        Comment {
+                            id: 41,
+                            author_id: "author ID 3".to_string(),
+                            author_display: "author display 3".to_string(),
+                            content: "Content 4".to_string(),
+                            rating: 42.0,
+                            timestamp: 43,
+                        },
+                        Comment {
+                            id: 44,
+                            author_id: "author ID 4".to_string(),
+                            author_display: "author display 4".to_string(),
+                            content: "Content 5".to_string(),
+                            rating: 45.0,
+                            timestamp: 46,
+                        },
+                    ],
+                }),
+                content: Some("Content 6".to_string()),
+                free: Some(false),
+                name: "Valley I".to_string(),
+                paths: Paths {
+                    api: "/wallpaper/40".to_string(),
+                    thumb: "/thumbnail/48x49/valley_thumbnail_48x49.jpg".to_string(),
+                    web: "/sec/valley/".to_string(),
+                },
+                pickle_jar: Some(PickleJar {
+                    parent: "parent 2".to_string(),
+                    siblings: vec!["sibling 3".to_string(), "sibling 4".to_string()],
+                }),
+                rating: Some(47.0),
+                resolutions: Some(Resolutions {
+                    single: vec![
+                        Resolution {
+                            label: "48x49".to_string(),
+                            width: 48,
+                            height: 49,
+                            image: "/single/48x49/valley_single_48x49.jpg".to_string(),
+                        },
+                        Resolution {
+                            label: "50x51".to_string(),
+                            width: 50,
+                            height: 51,
+                            image: "/single/50x51/valley_single_50x51.jpg".to_string(),
+                        },
+                    ],
+                    dual: Some(vec![
+                        Resolution {
+                            label: "52x53".to_string(),
+                            width: 52,
+                            height: 53,
+                            image: "/dual/52x53/valley_dual_52x53.jpg".to_string(),
+                        },
+                        Resolution {
+                            label: "54x55".to_string(),
+                            width: 54,
+                            height: 55,
+                            image: "/dual/54x55/valley_dual_54x55.jpg".to_string(),
+                        },
+                    ]),
+                    triple: Some(vec![
+                        Resolution {
+                            label: "56x57".to_string(),
+                            width: 56,
+                            height: 57,
+                            image: "/triple/56x57/valley_triple_56x57.jpg".to_string(),
+                        },
+                        Resolution {
+                            label: "58x59".to_string(),
+                            width: 58,
+                            height: 59,
+                            image: "/triple/58x59/valley_triple_58x59.jpg".to_string(),
+                        },
+                    ]),
+                    mobile: Some(vec![
+                        Resolution {
+                            label: "60x61".to_string(),
+                            width: 60,
+                            height: 61,
+                            image: "/mobile/60x61/valley_mobile_60x61.jpg".to_string(),
+                        },
+                        Resolution {
+                            label: "62x63".to_string(),
+                            width: 62,
+                            height: 63,
+                            image: "/mobile/62x63/valley_mobile_62x63.jpg".to_string(),
+                        },
+                    ]),
+                }),
+                sku: Some("valley".to_string()),
+                tags: Some(HashMap::from([
+                    (
+                        "65".to_string(),
+                        Tag {
+                            id: TagId(65),
+                            name: "Tag 4".to_string(),
+                        },
+                    ),
+                    (
+                        "64".to_string(),
+                        Tag {
+                            id: TagId(64),
+                            name: "Tag 3".to_string(),
+                        },
+                    ),
+                ])),
+                timestamp: Some(66),
+            };
+            let expected_get_wallpapers_response = GetWallpapersResponse {
+                db_core: GetWallpapersDBCore {
+                    timestamp: 1,
+                    endpoints: Endpoints {
+                        api: "https://api.digitalblasphemy.com/v2/core".to_string(),
+                        image: "https://arcadia.digitalblasphemy.com".to_string(),
+                        thumb: "https://cdn.digitalblasphemy.com".to_string(),
+                        web: "https://digitalblasphemy.com".to_string(),
+                    },
+                    request: GetWallpapersDBCoreRequest {
+                        query: GetWallpapersResponseQuery {
+                            filter_date_day: Some(2),
+                            filter_date_month: Some(3),
+                            filter_date_year: Some(4),
+                            filter_date_operator: Operator::Equal,
+                            filter_gallery: Some(vec![GalleryId(5)]),
+                            filter_rating: Some(6_f32),
+                            filter_rating_operator: Some(Operator::GreaterThanOrEqual),
+                            filter_res_operator_height: Some(Operator::GreaterThanOrEqual),
+                            filter_res_operator_width: Some(Operator::GreaterThanOrEqual),
+                            filter_res_height: 7,
+                            filter_res_operator: Operator::GreaterThanOrEqual,
+                            filter_res_width: 8,
+                            filter_tag: Some(vec![TagId(9)]),
+                            limit: 10,
+                            order: Order::Ascending,
+                            order_by: GetWallpapersOrderBy::Name,
+                            page: 11,
+                            s: Some("search".to_string()),
+                            show_comments: true,
+                            show_pickle_jar: true,
+                            show_resolutions: true,
+                        },
+                    },
+                    total_pages: 12,
+                    wallpapers: IndexMap::from([
+                        ("13".to_string(), expected_wallpaper_13),
+                        ("40".to_string(), expected_wallpaper_40),
+                    ]),
+                },
+                wallpaper_ids: vec![WallpaperId(13), WallpaperId(40)],
+            };
+            assert_eq!(get_wallpapers_response, expected_get_wallpapers_response);
+
+            mock.assert_async().await;
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn get_wallpapers_can_map_successful_response_minimal_populated()
+        -> Result<(), Box<dyn Error>> {
+            let mut server = mockito::Server::new_async().await;
+
+            let get_wallpapers_request = GetWallpapersRequest::builder().build();
+
+            let mock = server
+                .mock(
+                    "GET",
+                    Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
+                )
+                .match_header("Authorization", "Bearer api_key")
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(fs::read_to_string(
+                    "resources/get_wallpapers_success_minimal_populated.json",
+                )?)
+                .create_async()
+                .await;
+
+            let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
+
+            let get_wallpapers_response = client
+                .get_wallpapers(&get_wallpapers_request)
+                .await
+                .unwrap();
+
+            let expected_wallpaper_7 = Wallpaper {
+                id: WallpaperId(7),
+                all_free: None,
+                comments: None,
+                content: None,
+                free: None,
+                name: "Vulcan".to_string(),
+                paths: Paths {
+                    api: "/wallpaper/7".to_string(),
+                    thumb: "/thumbnail/8x9/vulcan_thumbnail_8x9.jpg".to_string(),
+                    web: "/sec/vulcan/".to_string(),
+                },
+                pickle_jar: None,
+                rating: None,
+                resolutions: Some(Resolutions {
+                    single: vec![
+                        Resolution {
+                            label: "8x9".to_string(),
+                            width: 8,
+                            height: 9,
+                            image: "/single/8x9/vulcan_single_8x9.jpg".to_string(),
+                        },
+                        Resolution {
+                            label: "10x11".to_string(),
+                            width: 10,
+                            height: 11,
+                            image: "/single/10x11/vulcan_single_10x11.jpg".to_string(),
+                        },
+                    ],
+                    dual: None,
+                    triple: None,
+                    mobile: None,
+                }),
+                sku: None,
+                tags: None,
+                timestamp: None,
+            };
+            let expected_wallpaper_12 = Wallpaper {
+                id: WallpaperId(12),
+                all_free: None,
+                comments: None,
+                content: None,
                 free: None,
+                name: "Valley I".to_string(),
+                paths: Paths {
+                    api: "/wallpaper/12".to_string(),
+                    thumb: "/thumbnail/13x14/valley_thumbnail_13x14.jpg".to_string(),
+                    web: "/sec/valley/".to_string(),
+                },
+                pickle_jar: None,
+                rating: None,
+                resolutions: Some(Resolutions {
+                    single: vec![
+                        Resolution {
+                            label: "13x14".to_string(),
+                            width: 13,
+                            height: 14,
+                            image: "/single/13x14/valley_single_13x14.jpg".to_string(),
+                        },
+                        Resolution {
+                            label: "15x16".to_string(),
+                            width: 15,
+                            height: 16,
+                            image: "/single/15x16/valley_single_15x16.jpg".to_string(),
+                        },
+                    ],
+                    dual: None,
+                    triple: None,
+                    mobile: None,
+                }),
+                sku: None,
+                tags: None,
+                timestamp: None,
+            };
+            let expected_get_wallpapers_response = GetWallpapersResponse {
+                db_core: GetWallpapersDBCore {
+                    timestamp: 1,
+                    endpoints: Endpoints {
+                        api: "https://api.digitalblasphemy.com/v2/core".to_string(),
+                        image: "https://arcadia.digitalblasphemy.com".to_string(),
+                        thumb: "https://cdn.digitalblasphemy.com".to_string(),
+                        web: "https://digitalblasphemy.com".to_string(),
+                    },
+                    request: GetWallpapersDBCoreRequest {
+                        query: GetWallpapersResponseQuery {
+                            filter_date_day: None,
+                            filter_date_month: None,
+                            filter_date_year: None,
+                            filter_date_operator: Operator::Equal,
+                            filter_gallery: None,
+                            filter_rating: None,
+                            filter_rating_operator: None,
+                            filter_res_operator_height: None,
+                            filter_res_operator_width: None,
+                            filter_res_height: 2,
+                            filter_res_operator: Operator::GreaterThanOrEqual,
+                            filter_res_width: 3,
+                            filter_tag: None,
+                            limit: 4,
+                            order: Order::Ascending,
+                            order_by: GetWallpapersOrderBy::Name,
+                            page: 5,
+                            s: None,
+                            show_comments: false,
+                            show_pickle_jar: false,
+                            show_resolutions: false,
+                        },
+                    },
+                    total_pages: 6,
+                    wallpapers: IndexMap::from([
+                        ("7".to_string(), expected_wallpaper_7),
+                        ("12".to_string(), expected_wallpaper_12),
+                    ]),
+                },
+                wallpaper_ids: vec![WallpaperId(7), WallpaperId(12)],
+            };
+            assert_eq!(get_wallpapers_response, expected_get_wallpapers_response);
+
+            mock.assert_async().await;
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn get_wallpapers_can_map_unauthorised_response() -> Result<(), Box<dyn Error>> {
+            let mut server = mockito::Server::new_async().await;
+
+            let get_wallpapers_request = GetWallpapersRequest::builder().build();
+
+            let mock = server
+                .mock(
+                    "GET",
+                    Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
+                )
+                .match_header("Authorization", "Bearer api_key")
+                .with_status(401)
+                .with_header("content-type", "application/json")
+                .with_body(fs::read_to_string("resources/unauthorised_response.json")?)
+                .create_async()
+                .await;
+
+            let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
+
+            let ClientError::Unauthorized(error) = client
+                .get_wallpapers(&get_wallpapers_request)
+                .await
+                .unwrap_err()
+            else {
+                panic!("Expected an unauthorized error");
+            };
+
+            assert_eq!(error.code, 401);
+            assert_eq!(error.description, "Unauthorized".to_string());
+
+            mock.assert_async().await;
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn get_wallpapers_can_map_bad_request_response() -> Result<(), Box<dyn Error>> {
+            let mut server = mockito::Server::new_async().await;
+
+            let get_wallpapers_request = GetWallpapersRequest::builder().build();
+
+            let mock = server
+                .mock(
+                    "GET",
+                    Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
+                )
+                .match_header("Authorization", "Bearer api_key")
+                .with_status(400)
+                .with_header("content-type", "application/json")
+                .with_body(fs::read_to_string(
+                    "resources/get_wallpapers_bad_request.json",
+                )?)
+                .create_async()
+                .await;
+
+            let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
+
+            let ClientError::BadRequest(error) = client
+                .get_wallpapers(&get_wallpapers_request)
+                .await
+                .unwrap_err()
+            else {
+                panic!("Expected a bad request error");
+            };
+
+            assert_eq!(error.code, 400);
+            assert_eq!(error.description, "Bad Request".to_string());
+            assert_eq!(
+                error.errors.unwrap(),
+                vec![
+                    "\"filter_date_day\" must be less than or equal to 31",
+                    "\"limit\" must be greater than or equal to 1"
+                ]
+            );
+
+            mock.assert_async().await;
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn get_wallpapers_can_map_unknown_error_response() -> Result<(), Box<dyn Error>> {
+            let mut server = mockito::Server::new_async().await;
+
+            let get_wallpapers_request = GetWallpapersRequest::builder().build();
+
+            server
+                .mock(
+                    "GET",
+                    Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
+                )
+                .match_header("Authorization", "Bearer api_key")
+                .with_status(405)
+                .create_async()
+                .await;
+
+            let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
+
+            let ClientError::Api(error) = client
+                .get_wallpapers(&get_wallpapers_request)
+                .await
+                .unwrap_err()
+            else {
+                panic!("Expected an API error");
+            };
+
+            assert_eq!(error.code, 405);
+            assert_eq!(error.description, "Method Not Allowed".to_string());
+            assert_eq!(error.errors, None);
+
+            Ok(())
+        }
+    }
+
+    mod wallpapers_stream {
+        use super::*;
+        use futures::TryStreamExt;
+
+        async fn mock_page(server: &mut mockito::ServerGuard, page: u16) -> mockito::Mock {
+            server
+                .mock(
+                    "GET",
+                    Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
+                )
+                .match_query(Matcher::UrlEncoded("page".to_string(), page.to_string()))
+                .match_header("Authorization", "Bearer api_key")
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(
+                    fs::read_to_string(format!(
+                        "resources/get_wallpapers_success_page_{page}_of_3.json"
+                    ))
+                    .unwrap(),
+                )
+                .create_async()
+                .await
+        }
+
+        #[tokio::test]
+        async fn wallpapers_stream_fetches_every_page_until_total_pages()
+        -> Result<(), Box<dyn Error>> {
+            let mut server = mockito::Server::new_async().await;
+
+            let page_1_mock = mock_page(&mut server, 1).await;
+            let page_2_mock = mock_page(&mut server, 2).await;
+            let page_3_mock = mock_page(&mut server, 3).await;
+            let page_4_mock = server
+                .mock(
+                    "GET",
+                    Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
+                )
+                .match_query(Matcher::UrlEncoded("page".to_string(), "4".to_string()))
+                .expect(0)
+                .create_async()
+                .await;
+
+            let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
+
+            let wallpapers: Vec<Wallpaper> = client
+                .wallpapers_stream(&GetWallpapersRequest::builder().limit(2).build())
+                .try_collect()
+                .await?;
+
+            let ids: Vec<u32> = wallpapers.iter().map(|w| w.id.get()).collect();
+            assert_eq!(ids, vec![3, 1, 5, 2, 4]);
+
+            page_1_mock.assert_async().await;
+            page_2_mock.assert_async().await;
+            page_3_mock.assert_async().await;
+            page_4_mock.assert_async().await;
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn wallpapers_stream_starts_from_requested_page() -> Result<(), Box<dyn Error>> {
+            let mut server = mockito::Server::new_async().await;
+
+            let page_1_mock = server
+                .mock(
+                    "GET",
+                    Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
+                )
+                .match_query(Matcher::UrlEncoded("page".to_string(), "1".to_string()))
+                .expect(0)
+                .create_async()
+                .await;
+            let page_2_mock = mock_page(&mut server, 2).await;
+            let page_3_mock = mock_page(&mut server, 3).await;
+
+            let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
+
+            let wallpapers: Vec<Wallpaper> = client
+                .wallpapers_stream(&GetWallpapersRequest::builder().limit(2).page(2).build())
+                .try_collect()
+                .await?;
+
+            assert_eq!(wallpapers.len(), 3);
+
+            page_1_mock.assert_async().await;
+            page_2_mock.assert_async().await;
+            page_3_mock.assert_async().await;
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn wallpapers_stream_with_prefetch_keeps_page_order() -> Result<(), Box<dyn Error>> {
+            let mut server = mockito::Server::new_async().await;
+
+            let page_1_mock = mock_page(&mut server, 1).await;
+            let page_2_mock = mock_page(&mut server, 2).await;
+            let page_3_mock = mock_page(&mut server, 3).await;
+
+            let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
+
+            let wallpapers: Vec<Wallpaper> = client
+                .wallpapers_stream_with_prefetch(
+                    &GetWallpapersRequest::builder().limit(2).build(),
+                    2,
+                )
+                .try_collect()
+                .await?;
+
+            let ids: Vec<u32> = wallpapers.iter().map(|w| w.id.get()).collect();
+            assert_eq!(ids, vec![3, 1, 5, 2, 4]);
+
+            page_1_mock.assert_async().await;
+            page_2_mock.assert_async().await;
+            page_3_mock.assert_async().await;
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn wallpapers_stream_does_not_request_until_polled() -> Result<(), Box<dyn Error>> {
+            let mut server = mockito::Server::new_async().await;
+
+            let mock = server
+                .mock(
+                    "GET",
+                    Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
+                )
+                .expect(0)
+                .create_async()
+                .await;
+
+            let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
+
+            let get_wallpapers_request = GetWallpapersRequest::builder().build();
+            let wallpapers_stream = client.wallpapers_stream(&get_wallpapers_request);
+            drop(wallpapers_stream);
+
+            mock.assert_async().await;
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn wallpapers_stream_ends_after_first_error() -> Result<(), Box<dyn Error>> {
+            let mut server = mockito::Server::new_async().await;
+
+            let page_1_mock = mock_page(&mut server, 1).await;
+            let page_2_mock = server
+                .mock(
+                    "GET",
+                    Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
+                )
+                .match_query(Matcher::UrlEncoded("page".to_string(), "2".to_string()))
+                .with_status(401)
+                .with_header("content-type", "application/json")
+                .with_body(fs::read_to_string("resources/unauthorised_response.json")?)
+                .create_async()
+                .await;
+            let page_3_mock = server
+                .mock(
+                    "GET",
+                    Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
+                )
+                .match_query(Matcher::UrlEncoded("page".to_string(), "3".to_string()))
+                .expect(0)
+                .create_async()
+                .await;
+
+            let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
+
+            let results: Vec<Result<Wallpaper, ClientError>> = client
+                .wallpapers_stream(&GetWallpapersRequest::builder().limit(2).build())
+                .collect()
+                .await;
+
+            assert_eq!(results.len(), 3);
+            assert!(results[0].is_ok());
+            assert!(results[1].is_ok());
+            assert!(matches!(results[2], Err(ClientError::Unauthorized(_))));
+
+            page_1_mock.assert_async().await;
+            page_2_mock.assert_async().await;
+            page_3_mock.assert_async().await;
+
+            Ok(())
+        }
+    }
+
+    mod get_wallpaper {
+        use super::*;
+
+        #[tokio::test]
+        async fn get_wallpaper_does_not_send_filter_res_height_if_not_provided()
+        -> Result<(), Box<dyn Error>> {
+            let mut server = mockito::Server::new_async().await;
+
+            let get_wallpaper_request = GetWallpaperRequest::builder().wallpaper_id(2).build();
+
+            let mock = server
+                .mock("GET", Matcher::Regex(r"^/v2/core/wallpaper.*$".to_string()))
+                .match_query(Matcher::Regex("[^filter_res_height]".to_string()))
+                .match_header("Authorization", "Bearer api_key")
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(fs::read_to_string(
+                    "resources/get_wallpaper_success_fully_populated.json",
+                )?)
+                .create_async()
+                .await;
+
+            let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
+
+            client.get_wallpaper(&get_wallpaper_request).await.unwrap();
+
+            mock.assert_async().await;
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn get_wallpaper_does_send_filter_res_height_if_provided()
+        -> Result<(), Box<dyn Error>> {
+            let mut server = mockito::Server::new_async().await;
+
+            let get_wallpaper_request = GetWallpaperRequest::builder()
+                .wallpaper_id(2)
+                .filter_res_height(1)
+                .build();
+
+            let mock = server
+                .mock("GET", Matcher::Regex(r"^/v2/core/wallpaper.*$".to_string()))
+                .match_query(Matcher::Regex("filter_res_height=1".to_string()))
+                .match_header("Authorization", "Bearer api_key")
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(fs::read_to_string(
+                    "resources/get_wallpaper_success_fully_populated.json",
+                )?)
+                .create_async()
+                .await;
+
+            let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
+
+            client.get_wallpaper(&get_wallpaper_request).await.unwrap();
+
+            mock.assert_async().await;
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn get_wallpaper_does_not_send_filter_res_width_if_not_provided()
+        -> Result<(), Box<dyn Error>> {
+            let mut server = mockito::Server::new_async().await;
+
+            let get_wallpaper_request = GetWallpaperRequest::builder().wallpaper_id(2).build();
+
+            let mock = server
+                .mock("GET", Matcher::Regex(r"^/v2/core/wallpaper.*$".to_string()))
+                .match_query(Matcher::Regex("[^filter_res_width]".to_string()))
+                .match_header("Authorization", "Bearer api_key")
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(fs::read_to_string(
+                    "resources/get_wallpaper_success_fully_populated.json",
+                )?)
+                .create_async()
+                .await;
+
+            let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
+
+            client.get_wallpaper(&get_wallpaper_request).await.unwrap();
+
+            mock.assert_async().await;
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn get_wallpaper_does_send_filter_res_width_if_provided() -> Result<(), Box<dyn Error>>
+        {
+            let mut server = mockito::Server::new_async().await;
+
+            let get_wallpaper_request = GetWallpaperRequest::builder()
+                .wallpaper_id(2)
+                .filter_res_width(1)
+                .build();
+
+            let mock = server
+                .mock("GET", Matcher::Regex(r"^/v2/core/wallpaper.*$".to_string()))
+                .match_query(Matcher::Regex("filter_res_width=1".to_string()))
+                .match_header("Authorization", "Bearer api_key")
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(fs::read_to_string(
+                    "resources/get_wallpaper_success_fully_populated.json",
+                )?)
+                .create_async()
+                .await;
+
+            let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
+
+            client.get_wallpaper(&get_wallpaper_request).await.unwrap();
+
+            mock.assert_async().await;
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn get_wallpaper_does_send_filter_res_operator_if_not_provided()
+        -> Result<(), Box<dyn Error>> {
+            let mut server = mockito::Server::new_async().await;
+
+            let get_wallpaper_request = GetWallpaperRequest::builder().wallpaper_id(2).build();
+
+            let mock = server
+                .mock("GET", Matcher::Regex(r"^/v2/core/wallpaper.*$".to_string()))
+                .match_query(Matcher::Regex("filter_res_operator=%3E%3D".to_string()))
+                .match_header("Authorization", "Bearer api_key")
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(fs::read_to_string(
+                    "resources/get_wallpaper_success_fully_populated.json",
+                )?)
+                .create_async()
+                .await;
+
+            let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
+
+            client.get_wallpaper(&get_wallpaper_request).await.unwrap();
+
+            mock.assert_async().await;
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn get_wallpaper_does_send_filter_res_operator_if_provided()
+        -> Result<(), Box<dyn Error>> {
+            let mut server = mockito::Server::new_async().await;
+
+            let get_wallpaper_request = GetWallpaperRequest::builder()
+                .wallpaper_id(2)
+                .filter_res_operator(Operator::Equal)
+                .build();
+
+            let mock = server
+                .mock("GET", Matcher::Regex(r"^/v2/core/wallpaper.*$".to_string()))
+                .match_query(Matcher::Regex("filter_res_operator=%3D".to_string()))
+                .match_header("Authorization", "Bearer api_key")
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(fs::read_to_string(
+                    "resources/get_wallpaper_success_fully_populated.json",
+                )?)
+                .create_async()
+                .await;
+
+            let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
+
+            client.get_wallpaper(&get_wallpaper_request).await.unwrap();
+
+            mock.assert_async().await;
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn get_wallpaper_does_send_filter_res_operator_height_if_not_provided()
+        -> Result<(), Box<dyn Error>> {
+            let mut server = mockito::Server::new_async().await;
+
+            let get_wallpaper_request = GetWallpaperRequest::builder().wallpaper_id(2).build();
+
+            let mock = server
+                .mock("GET", Matcher::Regex(r"^/v2/core/wallpaper.*$".to_string()))
+                .match_query(Matcher::Regex(
+                    "filter_res_operator_height=%3E%3D".to_string(),
+                ))
+                .match_header("Authorization", "Bearer api_key")
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(fs::read_to_string(
+                    "resources/get_wallpaper_success_fully_populated.json",
+                )?)
+                .create_async()
+                .await;
+
+            let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
+
+            client.get_wallpaper(&get_wallpaper_request).await.unwrap();
+
+            mock.assert_async().await;
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn get_wallpaper_does_send_filter_res_operator_height_if_provided()
+        -> Result<(), Box<dyn Error>> {
+            let mut server = mockito::Server::new_async().await;
+
+            let get_wallpaper_request = GetWallpaperRequest::builder()
+                .wallpaper_id(2)
+                .filter_res_operator_height(Operator::Equal)
+                .build();
+
+            let mock = server
+                .mock("GET", Matcher::Regex(r"^/v2/core/wallpaper.*$".to_string()))
+                .match_query(Matcher::Regex("filter_res_operator_height=%3D".to_string()))
+                .match_header("Authorization", "Bearer api_key")
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(fs::read_to_string(
+                    "resources/get_wallpaper_success_fully_populated.json",
+                )?)
+                .create_async()
+                .await;
+
+            let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
+
+            client.get_wallpaper(&get_wallpaper_request).await.unwrap();
+
+            mock.assert_async().await;
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn get_wallpaper_does_send_filter_res_operator_width_if_not_provided()
+        -> Result<(), Box<dyn Error>> {
+            let mut server = mockito::Server::new_async().await;
+
+            let get_wallpaper_request = GetWallpaperRequest::builder().wallpaper_id(2).build();
+
+            let mock = server
+                .mock("GET", Matcher::Regex(r"^/v2/core/wallpaper.*$".to_string()))
+                .match_query(Matcher::Regex(
+                    "filter_res_operator_width=%3E%3D".to_string(),
+                ))
+                .match_header("Authorization", "Bearer api_key")
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(fs::read_to_string(
+                    "resources/get_wallpaper_success_fully_populated.json",
+                )?)
+                .create_async()
+                .await;
+
+            let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
+
+            client.get_wallpaper(&get_wallpaper_request).await.unwrap();
+
+            mock.assert_async().await;
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn get_wallpaper_does_send_filter_res_operator_width_if_provided()
+        -> Result<(), Box<dyn Error>> {
+            let mut server = mockito::Server::new_async().await;
+
+            let get_wallpaper_request = GetWallpaperRequest::builder()
+                .wallpaper_id(2)
+                .filter_res_operator_width(Operator::Equal)
+                .build();
+
+            let mock = server
+                .mock("GET", Matcher::Regex(r"^/v2/core/wallpaper.*$".to_string()))
+                .match_query(Matcher::Regex("filter_res_operator_width=%3D".to_string()))
+                .match_header("Authorization", "Bearer api_key")
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(fs::read_to_string(
+                    "resources/get_wallpaper_success_fully_populated.json",
+                )?)
+                .create_async()
+                .await;
+
+            let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
+
+            client.get_wallpaper(&get_wallpaper_request).await.unwrap();
+
+            mock.assert_async().await;
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn get_wallpaper_does_send_show_comments_if_not_provided()
+        -> Result<(), Box<dyn Error>> {
+            let mut server = mockito::Server::new_async().await;
+
+            let get_wallpaper_request = GetWallpaperRequest::builder().wallpaper_id(2).build();
+
+            let mock = server
+                .mock("GET", Matcher::Regex(r"^/v2/core/wallpaper.*$".to_string()))
+                .match_query(Matcher::Regex("show_comments=false".to_string()))
+                .match_header("Authorization", "Bearer api_key")
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(fs::read_to_string(
+                    "resources/get_wallpaper_success_fully_populated.json",
+                )?)
+                .create_async()
+                .await;
+
+            let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
+
+            client.get_wallpaper(&get_wallpaper_request).await.unwrap();
+
+            mock.assert_async().await;
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn get_wallpaper_does_send_show_comments_if_provided() -> Result<(), Box<dyn Error>> {
+            let mut server = mockito::Server::new_async().await;
+
+            let get_wallpaper_request = GetWallpaperRequest::builder()
+                .wallpaper_id(2)
+                .show_comments(true)
+                .build();
+
+            let mock = server
+                .mock("GET", Matcher::Regex(r"^/v2/core/wallpaper.*$".to_string()))
+                .match_query(Matcher::Regex("show_comments=true".to_string()))
+                .match_header("Authorization", "Bearer api_key")
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(fs::read_to_string(
+                    "resources/get_wallpaper_success_fully_populated.json",
+                )?)
+                .create_async()
+                .await;
+
+            let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
+
+            client.get_wallpaper(&get_wallpaper_request).await.unwrap();
+
+            mock.assert_async().await;
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn get_wallpaper_does_send_show_pickle_jar_if_not_provided()
+        -> Result<(), Box<dyn Error>> {
+            let mut server = mockito::Server::new_async().await;
+
+            let get_wallpaper_request = GetWallpaperRequest::builder().wallpaper_id(2).build();
+
+            let mock = server
+                .mock("GET", Matcher::Regex(r"^/v2/core/wallpaper.*$".to_string()))
+                .match_query(Matcher::Regex("show_pickle_jar=false".to_string()))
+                .match_header("Authorization", "Bearer api_key")
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(fs::read_to_string(
+                    "resources/get_wallpaper_success_fully_populated.json",
+                )?)
+                .create_async()
+                .await;
+
+            let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
+
+            client.get_wallpaper(&get_wallpaper_request).await.unwrap();
+
+            mock.assert_async().await;
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn get_wallpaper_does_send_show_pickle_jar_if_provided() -> Result<(), Box<dyn Error>>
+        {
+            let mut server = mockito::Server::new_async().await;
+
+            let get_wallpaper_request = GetWallpaperRequest::builder()
+                .wallpaper_id(2)
+                .show_pickle_jar(true)
+                .build();
+
+            let mock = server
+                .mock("GET", Matcher::Regex(r"^/v2/core/wallpaper.*$".to_string()))
+                .match_query(Matcher::Regex("show_pickle_jar=true".to_string()))
+                .match_header("Authorization", "Bearer api_key")
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(fs::read_to_string(
+                    "resources/get_wallpaper_success_fully_populated.json",
+                )?)
+                .create_async()
+                .await;
+
+            let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
+
+            client.get_wallpaper(&get_wallpaper_request).await.unwrap();
+
+            mock.assert_async().await;
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn get_wallpaper_does_send_show_resolutions_if_not_provided()
+        -> Result<(), Box<dyn Error>> {
+            let mut server = mockito::Server::new_async().await;
+
+            let get_wallpaper_request = GetWallpaperRequest::builder().wallpaper_id(2).build();
+
+            let mock = server
+                .mock("GET", Matcher::Regex(r"^/v2/core/wallpaper.*$".to_string()))
+                .match_query(Matcher::Regex("show_resolutions=true".to_string()))
+                .match_header("Authorization", "Bearer api_key")
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(fs::read_to_string(
+                    "resources/get_wallpaper_success_fully_populated.json",
+                )?)
+                .create_async()
+                .await;
+
+            let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
+
+            client.get_wallpaper(&get_wallpaper_request).await.unwrap();
+
+            mock.assert_async().await;
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn get_wallpaper_does_send_show_resolutions_if_provided() -> Result<(), Box<dyn Error>>
+        {
+            let mut server = mockito::Server::new_async().await;
+
+            let get_wallpaper_request = GetWallpaperRequest::builder()
+                .wallpaper_id(2)
+                .show_resolutions(false)
+                .build();
+
+            let mock = server
+                .mock("GET", Matcher::Regex(r"^/v2/core/wallpaper.*$".to_string()))
+                .match_query(Matcher::Regex("show_resolutions=false".to_string()))
+                .match_header("Authorization", "Bearer api_key")
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(fs::read_to_string(
+                    "resources/get_wallpaper_success_fully_populated.json",
+                )?)
+                .create_async()
+                .await;
+
+            let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
+
+            client.get_wallpaper(&get_wallpaper_request).await.unwrap();
+
+            mock.assert_async().await;
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn get_wallpaper_sends_path_parameters() -> Result<(), Box<dyn Error>> {
+            let mut server = mockito::Server::new_async().await;
+
+            let get_wallpaper_request = GetWallpaperRequest::builder().wallpaper_id(2).build();
+
+            let mock = server
+                .mock(
+                    "GET",
+                    Matcher::Regex(format!(
+                        r"^/v2/core/wallpaper/{}?.*$",
+                        get_wallpaper_request.wallpaper_id
+                    )),
+                )
+                .match_header("Authorization", "Bearer api_key")
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(fs::read_to_string(
+                    "resources/get_wallpaper_success_fully_populated.json",
+                )?)
+                .create_async()
+                .await;
+
+            let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
+
+            client.get_wallpaper(&get_wallpaper_request).await.unwrap();
+
+            mock.assert_async().await;
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn get_wallpaper_can_map_successful_response_fully_populated()
+        -> Result<(), Box<dyn Error>> {
+            let mut server = mockito::Server::new_async().await;
+
+            let get_wallpaper_request = GetWallpaperRequest::builder().wallpaper_id(2).build();
+
+            let mock = server
+                .mock("GET", Matcher::Regex(r"^/v2/core/wallpaper.*$".to_string()))
+                .match_header("Authorization", "Bearer api_key")
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(fs::read_to_string(
+                    "resources/get_wallpaper_success_fully_populated.json",
+                )?)
+                .create_async()
+                .await;
+
+            let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
+
+            let get_wallpaper_response = client
+                .get_wallpaper(&get_wallpaper_request)
+                .await
+                .unwrap()
+                .unwrap();
+
+            let expected_wallpaper = Wallpaper {
+                id: WallpaperId(2),
+                all_free: Some(true),
+                comments: Some(Comments {
+                    comments: vec![
+                        Comment {
+                            id: 5,
+                            author_id: "author ID 1".to_string(),
+                            author_display: "author display 1".to_string(),
+                            content: "Content 1".to_string(),
+                            rating: 6.0,
+                            timestamp: 7,
+                        },
+                        Comment {
+                            id: 8,
+                            author_id: "author ID 2".to_string(),
+                            author_display: "author display 2".to_string(),
+                            content: "Content 2".to_string(),
+                            rating: 9.0,
+                            timestamp: 10,
+                        },
+                    ],
+                }),
+                content: Some("Content 3".to_string()),
+                free: Some(true),
                 name: "Vulcan".to_string(),
                 paths: Paths {
-                    api: "/wallpaper/7".to_string(),
-                    thumb: "/thumbnail/8x9/vulcan_thumbnail_8x9.jpg".to_string(),
+                    api: "/wallpaper/2".to_string(),
+                    thumb: "/thumbnail/12x13/vulcan_thumbnail_12x13.jpg".to_string(),
                     web: "/sec/vulcan/".to_string(),
                 },
-                pickle_jar: None,
-                rating: None,
+                pickle_jar: Some(PickleJar {
+                    parent: "parent 1".to_string(),
+                    siblings: vec!["sibling 1".to_string(), "sibling 2".to_string()],
+                }),
+                rating: Some(11.0),
                 resolutions: Some(Resolutions {
                     single: vec![
                         Resolution {
-                            label: "8x9".to_string(),
-                            width: "8".to_string(),
-                            height: "9".to_string(),
-                            image: "/single/8x9/vulcan_single_8x9.jpg".to_string(),
+                            label: "12x13".to_string(),
+                            width: 12,
+                            height: 13,
+                            image: "/single/12x13/vulcan_single_12x13.jpg".to_string(),
                         },
                         Resolution {
-                            label: "10x11".to_string(),
-                            width: "10".to_string(),
-                            height: "11".to_string(),
-                            image: "/single/10x11/vulcan_single_10x11.jpg".to_string(),
+                            label: "14x15".to_string(),
+                            width: 14,
+                            height: 15,
+                            image: "/single/14x15/vulcan_single_14x15.jpg".to_string(),
                         },
                     ],
-                    dual: None,
-                    triple: None,
-                    mobile: None,
-                }),
-                sku: None,
-                tags: None,
-                timestamp: None,
-            };
-            let expected_wallpaper_12 = Wallpaper {
-                id: 12,
-                all_free: None,
-                comments: None,
-                content: None,
-                free: None,
-                name: "Valley I".to_string(),
-                paths: Paths {
-                    api: "/wallpaper/12".to_string(),
-                    thumb: "/thumbnail/13x14/valley_thumbnail_13x14.jpg".to_string(),
-                    web: "/sec/valley/".to_string(),
-                },
-                pickle_jar: None,
-                rating: None,
-                resolutions: Some(Resolutions {
-                    single: vec![
+                    dual: Some(vec![
                         Resolution {
-                            label: "13x14".to_string(),
-                            width: "13".to_string(),
-                            height: "14".to_string(),
-                            image: "/single/13x14/valley_single_13x14.jpg".to_string(),
+                            label: "16x17".to_string(),
+                            width: 16,
+                            height: 17,
+                            image: "/dual/16x17/vulcan_dual_16x17.jpg".to_string(),
                         },
                         Resolution {
-                            label: "15x16".to_string(),
-                            width: "15".to_string(),
-                            height: "16".to_string(),
-                            image: "/single/15x16/valley_single_15x16.jpg".to_string(),
+                            label: "18x19".to_string(),
+                            width: 18,
+                            height: 19,
+                            image: "/dual/18x19/vulcan_dual_18x19.jpg".to_string(),
                         },
-                    ],
-                    dual: None,
-                    triple: None,
-                    mobile: None,
-                }),
-                sku: None,
-                tags: None,
-                timestamp: None,
-            };
-            let expected_get_wallpapers_response = GetWallpapersResponse {
-                db_core: GetWallpapersDBCore {
-                    timestamp: 1,
-                    endpoints: Endpoints {
-                        api: "https://api.digitalblasphemy.com/v2/core".to_string(),
-                        image: "https://arcadia.digitalblasphemy.com".to_string(),
-                        thumb: "https://cdn.digitalblasphemy.com".to_string(),
-                        web: "https://digitalblasphemy.com".to_string(),
-                    },
-                    request: GetWallpapersDBCoreRequest {
-                        query: GetWallpapersResponseQuery {
-                            filter_date_day: None,
-                            filter_date_month: None,
-                            filter_date_year: None,
-                            filter_date_operator: Operator::Equal,
-                            filter_gallery: None,
-                            filter_rating: None,
-                            filter_rating_operator: None,
-                            filter_res_operator_height: None,
-                            filter_res_operator_width: None,
-                            filter_res_height: 2,
-                            filter_res_operator: Operator::GreaterThanOrEqual,
-                            filter_res_width: 3,
-                            filter_tag: None,
-                            limit: 4,
-                            order: Order::Ascending,
-                            order_by: GetWallpapersOrderBy::Name,
-                            page: 5,
-                            s: None,
-                            show_comments: false,
-                            show_pickle_jar: false,
-                            show_resolutions: false,
+                    ]),
+                    triple: Some(vec![
+                        Resolution {
+                            label: "20x21".to_string(),
+                            width: 20,
+                            height: 21,
+                            image: "/triple/20x21/vulcan_triple_20x21.jpg".to_string(),
+                        },
+                        Resolution {
+                            label: "22x23".to_string(),
+                            width: 22,
+                            height: 23,
+                            image: "/triple/22x23/vulcan_triple_22x23.jpg".to_string(),
                         },
-                    },
-                    total_pages: 6,
-                    wallpapers: HashMap::from([
-                        ("7".to_string(), expected_wallpaper_7),
-                        ("12".to_string(), expected_wallpaper_12),
                     ]),
-                },
+                    mobile: Some(vec![
+                        Resolution {
+                            label: "24x25".to_string(),
+                            width: 24,
+                            height: 25,
+                            image: "/mobile/24x25/vulcan_mobile_24x25.jpg".to_string(),
+                        },
+                        Resolution {
+                            label: "26x27".to_string(),
+                            width: 26,
+                            height: 27,
+                            image: "/mobile/26x27/vulcan_mobile_26x27.jpg".to_string(),
+                        },
+                    ]),
+                }),
+                sku: Some("vulcan".to_string()),
+                tags: Some(HashMap::from([
+                    (
+                        "28".to_string(),
+                        Tag {
+                            id: TagId(28),
+                            name: "Tag 1".to_string(),
+                        },
+                    ),
+                    (
+                        "29".to_string(),
+                        Tag {
+                            id: TagId(29),
+                            name: "Tag 2".to_string(),
+                        },
+                    ),
+                ])),
+                timestamp: Some(30),
+            };
+            assert_eq!(get_wallpaper_response, expected_wallpaper);
+
+            mock.assert_async().await;
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn get_wallpaper_can_map_successful_response_minimal_populated()
+        -> Result<(), Box<dyn Error>> {
+            let mut server = mockito::Server::new_async().await;
+
+            let get_wallpaper_request = GetWallpaperRequest::builder().wallpaper_id(2).build();
+
+            let mock = server
+                .mock("GET", Matcher::Regex(r"^/v2/core/wallpaper.*$".to_string()))
+                .match_header("Authorization", "Bearer api_key")
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(fs::read_to_string(
+                    "resources/get_wallpaper_success_minimal_populated.json",
+                )?)
+                .create_async()
+                .await;
+
+            let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
+
+            let get_wallpaper_response =
+                client.get_wallpaper(&get_wallpaper_request).await.unwrap();
+
+            assert_eq!(get_wallpaper_response, None);
+
+            mock.assert_async().await;
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn get_wallpaper_can_map_unauthorised_response() -> Result<(), Box<dyn Error>> {
+            let mut server = mockito::Server::new_async().await;
+
+            let get_wallpaper_request = GetWallpaperRequest::builder().wallpaper_id(1).build();
+
+            let mock = server
+                .mock("GET", Matcher::Regex(r"^/v2/core/wallpaper.*$".to_string()))
+                .match_header("Authorization", "Bearer api_key")
+                .with_status(401)
+                .with_header("content-type", "application/json")
+                .with_body(fs::read_to_string("resources/unauthorised_response.json")?)
+                .create_async()
+                .await;
+
+            let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
+
+            let ClientError::Unauthorized(error) = client
+                .get_wallpaper(&get_wallpaper_request)
+                .await
+                .unwrap_err()
+            else {
+                panic!("Expected an unauthorized error");
             };
-            assert_eq!(get_wallpapers_response, expected_get_wallpapers_response);
 
-            mock.assert_async().await;
+            assert_eq!(error.code, 401);
+            assert_eq!(error.description, "Unauthorized".to_string());
+
+            mock.assert_async().await;
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn get_wallpaper_can_map_bad_request_response() -> Result<(), Box<dyn Error>> {
+            let mut server = mockito::Server::new_async().await;
+
+            let get_wallpaper_request = GetWallpaperRequest::builder().wallpaper_id(1).build();
+
+            let mock = server
+                .mock("GET", Matcher::Regex(r"^/v2/core/wallpaper.*$".to_string()))
+                .match_header("Authorization", "Bearer api_key")
+                .with_status(400)
+                .with_header("content-type", "application/json")
+                .with_body(fs::read_to_string(
+                    "resources/get_wallpaper_bad_request.json",
+                )?)
+                .create_async()
+                .await;
+
+            let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
+
+            let ClientError::BadRequest(error) = client
+                .get_wallpaper(&get_wallpaper_request)
+                .await
+                .unwrap_err()
+            else {
+                panic!("Expected a bad request error");
+            };
+
+            assert_eq!(error.code, 400);
+            assert_eq!(error.description, "Bad Request".to_string());
+            assert_eq!(
+                error.errors.unwrap(),
+                vec![
+                    "\"filter_res_height\" must be greater than or equal to 1",
+                    "\"filter_res_width\" must be greater than or equal to 1"
+                ]
+            );
+
+            mock.assert_async().await;
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn get_wallpaper_can_map_unknown_error_response() -> Result<(), Box<dyn Error>> {
+            let mut server = mockito::Server::new_async().await;
+
+            let get_wallpaper_request = GetWallpaperRequest::builder().wallpaper_id(1).build();
+
+            server
+                .mock("GET", Matcher::Regex(r"^/v2/core/wallpaper.*$".to_string()))
+                .match_header("Authorization", "Bearer api_key")
+                .with_status(405)
+                .create_async()
+                .await;
+
+            let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
+
+            let ClientError::Api(error) = client
+                .get_wallpaper(&get_wallpaper_request)
+                .await
+                .unwrap_err()
+            else {
+                panic!("Expected an API error");
+            };
+
+            assert_eq!(error.code, 405);
+            assert_eq!(error.description, "Method Not Allowed".to_string());
+            assert_eq!(error.errors, None);
+
+            Ok(())
+        }
+    }
+
+    mod get_wallpapers_by_ids {
+        use super::*;
+
+        #[tokio::test]
+        async fn get_wallpapers_by_ids_reports_each_id_separately() -> Result<(), Box<dyn Error>> {
+            let mut server = mockito::Server::new_async().await;
+
+            let found_mock = server
+                .mock("GET", "/v2/core/wallpaper/1")
+                .match_query(Matcher::UrlEncoded(
+                    "show_comments".to_string(),
+                    "true".to_string(),
+                ))
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(fs::read_to_string(
+                    "resources/get_wallpaper_success_fully_populated.json",
+                )?)
+                .expect(1)
+                .create_async()
+                .await;
+            let missing_mock = server
+                .mock("GET", "/v2/core/wallpaper/2")
+                .match_query(Matcher::Any)
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(fs::read_to_string(
+                    "resources/get_wallpaper_success_minimal_populated.json",
+                )?)
+                .expect(1)
+                .create_async()
+                .await;
+            let failed_mock = server
+                .mock("GET", "/v2/core/wallpaper/3")
+                .match_query(Matcher::Any)
+                .with_status(404)
+                .with_header("content-type", "application/json")
+                .with_body(r#"{"code": 404, "description": "Not Found"}"#)
+                .expect(1)
+                .create_async()
+                .await;
+
+            let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
+            let template = GetWallpaperRequest::builder()
+                .wallpaper_id(1)
+                .show_comments(true)
+                .build();
+
+            let wallpapers = client
+                .get_wallpapers_by_ids([1, 2, 3, 1, 2], &template, 2)
+                .await;
+
+            assert_eq!(wallpapers.len(), 3);
+            assert_eq!(
+                wallpapers[&WallpaperId(1)]
+                    .as_ref()
+                    .unwrap()
+                    .as_ref()
+                    .unwrap()
+                    .name,
+                "Vulcan"
+            );
+            assert!(wallpapers[&WallpaperId(2)].as_ref().unwrap().is_none());
+            assert!(matches!(
+                wallpapers[&WallpaperId(3)],
+                Err(ClientError::NotFound(_))
+            ));
+
+            found_mock.assert_async().await;
+            missing_mock.assert_async().await;
+            failed_mock.assert_async().await;
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn get_wallpapers_by_ids_returns_empty_map_for_no_ids() -> Result<(), Box<dyn Error>>
+        {
+            let client = DigitalBlasphemyClient::builder("api_key".to_string())
+                .base_url("http://api.digitalblasphemy.invalid")
+                .build()?;
+
+            let wallpapers = client
+                .get_wallpapers_by_ids(
+                    Vec::<WallpaperId>::new(),
+                    &GetWallpaperRequest::builder().wallpaper_id(1).build(),
+                    4,
+                )
+                .await;
+
+            assert!(wallpapers.is_empty());
+
+            Ok(())
+        }
+    }
+
+    mod response_cache {
+        use super::*;
+        use std::time::Duration;
+
+        async fn wallpaper_mock(server: &mut mockito::ServerGuard, hits: usize) -> mockito::Mock {
+            server
+                .mock("GET", "/v2/core/wallpaper/1")
+                .match_query(Matcher::Any)
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(
+                    fs::read_to_string("resources/get_wallpaper_success_fully_populated.json")
+                        .unwrap(),
+                )
+                .expect(hits)
+                .create_async()
+                .await
+        }
+
+        fn client(
+            base_url: String,
+            directory: &Path,
+            ttl: Duration,
+        ) -> Result<DigitalBlasphemyClient, ClientError> {
+            DigitalBlasphemyClient::builder("api_key".to_string())
+                .base_url(base_url)
+                .response_cache(ResponseCache::new(directory, ttl))
+                .build()
+        }
+
+        fn request() -> GetWallpaperRequest {
+            GetWallpaperRequest::builder().wallpaper_id(1).build()
+        }
+
+        #[tokio::test]
+        async fn response_cache_serves_fresh_entries_without_a_request()
+        -> Result<(), Box<dyn Error>> {
+            let mut server = mockito::Server::new_async().await;
+            let directory = tempfile::tempdir()?;
+            let mock = wallpaper_mock(&mut server, 1).await;
+            let client = client(server.url(), directory.path(), Duration::from_secs(60))?;
+
+            let first = client.get_wallpaper_cached(&request()).await?;
+            let second = client.get_wallpaper_cached(&request()).await?;
+
+            assert_eq!(first.freshness, Freshness::Network);
+            assert_eq!(second.freshness, Freshness::Cached);
+            assert_eq!(second.value.unwrap().id, 2);
+
+            mock.assert_async().await;
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn response_cache_refetches_expired_entries() -> Result<(), Box<dyn Error>> {
+            let mut server = mockito::Server::new_async().await;
+            let directory = tempfile::tempdir()?;
+            let mock = wallpaper_mock(&mut server, 2).await;
+            let client = DigitalBlasphemyClient::builder("api_key".to_string())
+                .base_url(server.url())
+                .response_cache(
+                    ResponseCache::new(directory.path(), Duration::from_secs(60))
+                        .with_ttl(CacheEndpoint::Wallpaper, Duration::ZERO),
+                )
+                .build()?;
+
+            client.get_wallpaper(&request()).await?;
+            let second = client.get_wallpaper_cached(&request()).await?;
+
+            assert_eq!(second.freshness, Freshness::Network);
+
+            mock.assert_async().await;
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn response_cache_serves_stale_entries_when_offline() -> Result<(), Box<dyn Error>> {
+            let mut server = mockito::Server::new_async().await;
+            let directory = tempfile::tempdir()?;
+            let mock = wallpaper_mock(&mut server, 1).await;
+            client(server.url(), directory.path(), Duration::ZERO)?
+                .get_wallpaper(&request())
+                .await?;
+            let offline_client = client(
+                "http://127.0.0.1:1".to_string(),
+                directory.path(),
+                Duration::ZERO,
+            )?;
+
+            let wallpaper = offline_client.get_wallpaper_cached(&request()).await?;
+
+            assert_eq!(wallpaper.freshness, Freshness::Stale);
+            assert_eq!(wallpaper.value.unwrap().id, 2);
+
+            let uncached = GetWallpaperRequest::builder().wallpaper_id(2).build();
+            assert!(matches!(
+                offline_client.get_wallpaper_cached(&uncached).await,
+                Err(ClientError::Transport(_))
+            ));
+
+            mock.assert_async().await;
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn response_cache_does_not_hide_api_errors() -> Result<(), Box<dyn Error>> {
+            let mut server = mockito::Server::new_async().await;
+            let mut failing_server = mockito::Server::new_async().await;
+            let directory = tempfile::tempdir()?;
+            wallpaper_mock(&mut server, 1).await;
+            failing_server
+                .mock("GET", "/v2/core/wallpaper/1")
+                .match_query(Matcher::Any)
+                .with_status(404)
+                .with_header("content-type", "application/json")
+                .with_body(r#"{"code": 404, "description": "Not Found"}"#)
+                .create_async()
+                .await;
+            client(server.url(), directory.path(), Duration::ZERO)?
+                .get_wallpaper(&request())
+                .await?;
+
+            let result = client(failing_server.url(), directory.path(), Duration::ZERO)?
+                .get_wallpaper(&request())
+                .await;
+
+            assert!(matches!(result, Err(ClientError::NotFound(_))));
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn response_cache_does_not_store_undecodable_responses() -> Result<(), Box<dyn Error>>
+        {
+            let mut server = mockito::Server::new_async().await;
+            let directory = tempfile::tempdir()?;
+            let mock = server
+                .mock("GET", "/v2/core/wallpaper/1")
+                .match_query(Matcher::Any)
+                .with_status(200)
+                .with_body("not json")
+                .expect(2)
+                .create_async()
+                .await;
+            let client = client(server.url(), directory.path(), Duration::from_secs(60))?;
+
+            for _ in 0..2 {
+                assert!(matches!(
+                    client.get_wallpaper(&request()).await,
+                    Err(ClientError::Decode { .. })
+                ));
+            }
+
+            mock.assert_async().await;
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn response_cache_keys_on_the_query() -> Result<(), Box<dyn Error>> {
+            let mut server = mockito::Server::new_async().await;
+            let directory = tempfile::tempdir()?;
+            let mock = server
+                .mock("GET", "/v2/core/wallpapers")
+                .match_query(Matcher::Any)
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(fs::read_to_string(
+                    "resources/get_wallpapers_success_new_releases.json",
+                )?)
+                .expect(2)
+                .create_async()
+                .await;
+            let client = client(server.url(), directory.path(), Duration::from_secs(60))?;
+
+            client
+                .get_wallpapers(&GetWallpapersRequest::builder().page(1).build())
+                .await?;
+            client
+                .get_wallpapers(&GetWallpapersRequest::builder().page(2).build())
+                .await?;
+            let cached = client
+                .get_wallpapers_cached(&GetWallpapersRequest::builder().page(1).build())
+                .await?;
+
+            assert_eq!(cached.freshness, Freshness::Cached);
+
+            mock.assert_async().await;
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn response_cache_invalidate_wallpaper_forces_a_request() -> Result<(), Box<dyn Error>>
+        {
+            let mut server = mockito::Server::new_async().await;
+            let directory = tempfile::tempdir()?;
+            let mock = wallpaper_mock(&mut server, 3).await;
+            let client = client(server.url(), directory.path(), Duration::from_secs(60))?;
+
+            client.get_wallpaper(&request()).await?;
+            client.invalidate_wallpaper(&request()).await?;
+            client.get_wallpaper(&request()).await?;
+            client.invalidate_endpoint(CacheEndpoint::Wallpaper).await?;
+            client.get_wallpaper(&request()).await?;
+            client.clear_response_cache().await?;
+
+            assert!(!directory.path().join("wallpaper").exists());
+
+            mock.assert_async().await;
+
+            Ok(())
+        }
+    }
+
+    mod conditional_requests {
+        use super::*;
+        use std::time::Duration;
+
+        fn body() -> String {
+            fs::read_to_string("resources/get_wallpaper_success_fully_populated.json").unwrap()
+        }
+
+        fn request() -> GetWallpaperRequest {
+            GetWallpaperRequest::builder().wallpaper_id(1).build()
+        }
+
+        #[tokio::test]
+        async fn conditional_requests_answer_not_modified_from_stored_body()
+        -> Result<(), Box<dyn Error>> {
+            let mut server = mockito::Server::new_async().await;
+            let first_mock = server
+                .mock("GET", "/v2/core/wallpaper/1")
+                .match_query(Matcher::Any)
+                .match_header("if-none-match", Matcher::Missing)
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_header("etag", "\"v1\"")
+                .with_body(body())
+                .expect(1)
+                .create_async()
+                .await;
+            let not_modified_mock = server
+                .mock("GET", "/v2/core/wallpaper/1")
+                .match_query(Matcher::Any)
+                .match_header("if-none-match", "\"v1\"")
+                .with_status(304)
+                .expect(2)
+                .create_async()
+                .await;
+
+            let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
+
+            let first = client.get_wallpaper_cached(&request()).await?;
+            let second = client.get_wallpaper_cached(&request()).await?;
+            let third = client.get_wallpaper(&request()).await?;
+
+            assert_eq!(first.freshness, Freshness::Network);
+            assert_eq!(second.freshness, Freshness::NotModified);
+            assert_eq!(second.value.unwrap().id, 2);
+            assert_eq!(third.unwrap().id, 2);
+
+            first_mock.assert_async().await;
+            not_modified_mock.assert_async().await;
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn conditional_requests_revalidate_expired_cache_entries()
+        -> Result<(), Box<dyn Error>> {
+            let mut server = mockito::Server::new_async().await;
+            let directory = tempfile::tempdir()?;
+            let first_mock = server
+                .mock("GET", "/v2/core/wallpapers")
+                .match_query(Matcher::Any)
+                .match_header("if-modified-since", Matcher::Missing)
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_header("last-modified", "Tue, 14 Nov 2023 22:13:20 GMT")
+                .with_body(fs::read_to_string(
+                    "resources/get_wallpapers_success_new_releases.json",
+                )?)
+                .expect(1)
+                .create_async()
+                .await;
+            let not_modified_mock = server
+                .mock("GET", "/v2/core/wallpapers")
+                .match_query(Matcher::Any)
+                .match_header("if-modified-since", "Tue, 14 Nov 2023 22:13:20 GMT")
+                .with_status(304)
+                .expect(1)
+                .create_async()
+                .await;
+
+            let client = DigitalBlasphemyClient::builder("api_key".to_string())
+                .base_url(server.url())
+                .response_cache(ResponseCache::new(directory.path(), Duration::ZERO))
+                .build()?;
+            let request = GetWallpapersRequest::builder().build();
+
+            client.get_wallpapers(&request).await?;
+            let second = client.get_wallpapers_cached(&request).await?;
+
+            assert_eq!(second.freshness, Freshness::NotModified);
+            assert_eq!(second.value.into_wallpapers().len(), 4);
+
+            first_mock.assert_async().await;
+            not_modified_mock.assert_async().await;
 
             Ok(())
         }
 
         #[tokio::test]
-        async fn get_wallpapers_can_map_unauthorised_response() -> Result<(), Box<dyn Error>> {
+        async fn conditional_requests_are_not_sent_without_validators() -> Result<(), Box<dyn Error>>
+        {
             let mut server = mockito::Server::new_async().await;
-
-            let get_wallpapers_request = GetWallpapersRequest::builder().build();
-
             let mock = server
-                .mock(
-                    "GET",
-                    Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
-                )
-                .match_header("Authorization", "Bearer api_key")
-                .with_status(401)
+                .mock("GET", "/v2/core/wallpaper/1")
+                .match_query(Matcher::Any)
+                .match_header("if-none-match", Matcher::Missing)
+                .match_header("if-modified-since", Matcher::Missing)
+                .with_status(200)
                 .with_header("content-type", "application/json")
-                .with_body(fs::read_to_string("resources/unauthorised_response.json")?)
+                .with_body(body())
+                .expect(2)
                 .create_async()
                 .await;
 
             let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
 
-            let error = client
-                .get_wallpapers(&get_wallpapers_request)
-                .await
-                .unwrap_err();
+            client.get_wallpaper(&request()).await?;
+            let second = client.get_wallpaper_cached(&request()).await?;
 
-            assert_eq!(error.code, 401);
-            assert_eq!(error.description, "Unauthorized".to_string());
+            assert_eq!(second.freshness, Freshness::Network);
 
             mock.assert_async().await;
 
@@ -2326,724 +5222,1239 @@ mod tests {
         }
 
         #[tokio::test]
-        async fn get_wallpapers_can_map_bad_request_response() -> Result<(), Box<dyn Error>> {
+        async fn conditional_requests_forget_invalidated_validators() -> Result<(), Box<dyn Error>>
+        {
             let mut server = mockito::Server::new_async().await;
-
-            let get_wallpapers_request = GetWallpapersRequest::builder().build();
-
             let mock = server
-                .mock(
-                    "GET",
-                    Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
-                )
-                .match_header("Authorization", "Bearer api_key")
-                .with_status(400)
+                .mock("GET", "/v2/core/wallpaper/1")
+                .match_query(Matcher::Any)
+                .match_header("if-none-match", Matcher::Missing)
+                .with_status(200)
                 .with_header("content-type", "application/json")
-                .with_body(fs::read_to_string(
-                    "resources/get_wallpapers_bad_request.json",
-                )?)
+                .with_header("etag", "\"v1\"")
+                .with_body(body())
+                .expect(3)
                 .create_async()
                 .await;
 
             let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
 
-            let error = client
-                .get_wallpapers(&get_wallpapers_request)
-                .await
-                .unwrap_err();
+            client.get_wallpaper(&request()).await?;
+            client.invalidate_wallpaper(&request()).await?;
+            client.get_wallpaper(&request()).await?;
+            client.clear_response_cache().await?;
+            client.get_wallpaper(&request()).await?;
 
-            assert_eq!(error.code, 400);
-            assert_eq!(error.description, "Bad Request".to_string());
-            assert_eq!(
-                error.errors.unwrap(),
-                vec![
-                    "\"filter_date_day\" must be less than or equal to 31",
-                    "\"limit\" must be greater than or equal to 1"
-                ]
+            mock.assert_async().await;
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn conditional_requests_keep_only_recently_used_validators_in_memory()
+        -> Result<(), Box<dyn Error>> {
+            let client =
+                DigitalBlasphemyClient::new_test("api_key".to_string(), "http://localhost".into())?;
+            let mut headers = HeaderMap::new();
+            headers.insert(ETAG, HeaderValue::from_static("\"v1\""));
+            let entry = CacheEntry::new("body".to_string(), &headers);
+
+            for key in 0..MAX_REVALIDATION_ENTRIES {
+                client
+                    .store_entry(CacheEndpoint::Wallpaper, key.to_string(), entry.clone())
+                    .await;
+            }
+            assert!(
+                client
+                    .load_entry(CacheEndpoint::Wallpaper, "0")
+                    .await
+                    .is_some()
             );
+            client
+                .store_entry(CacheEndpoint::Wallpaper, "new".to_string(), entry.clone())
+                .await;
 
-            mock.assert_async().await;
+            assert!(
+                client
+                    .load_entry(CacheEndpoint::Wallpaper, "0")
+                    .await
+                    .is_some()
+            );
+            assert!(
+                client
+                    .load_entry(CacheEndpoint::Wallpaper, "1")
+                    .await
+                    .is_none()
+            );
+            assert!(
+                client
+                    .load_entry(CacheEndpoint::Wallpaper, "new")
+                    .await
+                    .is_some()
+            );
+            assert_eq!(
+                client.revalidation_entries.lock().unwrap().len(),
+                MAX_REVALIDATION_ENTRIES
+            );
 
             Ok(())
         }
+    }
+
+    mod download_wallpaper {
+        use super::*;
+        use uuid::Uuid;
 
         #[tokio::test]
-        #[should_panic(expected = "Unable to parse the body as JSON ErrorResponse")]
-        async fn get_wallpapers_can_map_unknown_error_response() {
+        async fn download_wallpaper_does_send_show_watermark_if_not_provided()
+        -> Result<(), Box<dyn Error>> {
             let mut server = mockito::Server::new_async().await;
 
-            let get_wallpapers_request = GetWallpapersRequest::builder().build();
+            let download_wallpaper_request = DownloadWallpaperRequest::builder()
+                .width(2)
+                .height(3)
+                .wallpaper_id(4)
+                .build();
 
-            server
+            let download_wallpaper_mock = server
                 .mock(
                     "GET",
-                    Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
+                    Matcher::Regex(r"^/v2/core/download/wallpaper.*$".to_string()),
                 )
+                .match_query(Matcher::Regex("show_watermark=true".to_string()))
                 .match_header("Authorization", "Bearer api_key")
-                .with_status(405)
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(
+                    fs::read_to_string(
+                        "resources/download_wallpaper_success_fully_populated.json",
+                    )?
+                    .replace("{{host}}", &server.url()),
+                )
                 .create_async()
                 .await;
 
-            let client =
-                DigitalBlasphemyClient::new_test("api_key".to_string(), server.url()).unwrap();
+            let download_file_mock = server
+                .mock("GET", Matcher::Regex(r"^/test.jpg$".to_string()))
+                .match_header("Authorization", "Bearer api_key")
+                .with_status(200)
+                .with_header("content-type", "image/jpg")
+                .with_body("image-content")
+                .create_async()
+                .await;
 
-            let _ = client
-                .get_wallpapers(&get_wallpapers_request)
+            let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
+
+            let filename = format!("./{}.jpg", Uuid::new_v4());
+
+            client
+                .download_wallpaper(&filename, &download_wallpaper_request)
                 .await
-                .unwrap_err();
-        }
-    }
+                .unwrap();
 
-    mod get_wallpaper {
-        use super::*;
+            download_wallpaper_mock.assert_async().await;
+            download_file_mock.assert_async().await;
+
+            fs::remove_file(&filename)?;
+
+            Ok(())
+        }
 
         #[tokio::test]
-        async fn get_wallpaper_does_not_send_filter_res_height_if_not_provided()
+        async fn download_wallpaper_does_send_show_watermark_if_provided()
         -> Result<(), Box<dyn Error>> {
             let mut server = mockito::Server::new_async().await;
 
-            let get_wallpaper_request = GetWallpaperRequest::builder().wallpaper_id(2).build();
+            let download_wallpaper_request = DownloadWallpaperRequest::builder()
+                .width(2)
+                .height(3)
+                .wallpaper_id(4)
+                .show_watermark(true)
+                .build();
 
-            let mock = server
-                .mock("GET", Matcher::Regex(r"^/v2/core/wallpaper.*$".to_string()))
-                .match_query(Matcher::Regex("[^filter_res_height]".to_string()))
+            let download_wallpaper_mock = server
+                .mock(
+                    "GET",
+                    Matcher::Regex(r"^/v2/core/download/wallpaper.*$".to_string()),
+                )
+                .match_query(Matcher::Regex("show_watermark=true".to_string()))
                 .match_header("Authorization", "Bearer api_key")
                 .with_status(200)
                 .with_header("content-type", "application/json")
-                .with_body(fs::read_to_string(
-                    "resources/get_wallpaper_success_fully_populated.json",
-                )?)
+                .with_body(
+                    fs::read_to_string(
+                        "resources/download_wallpaper_success_fully_populated.json",
+                    )?
+                    .replace("{{host}}", &server.url()),
+                )
+                .create_async()
+                .await;
+
+            let download_file_mock = server
+                .mock("GET", Matcher::Regex(r"^/test.jpg$".to_string()))
+                .match_header("Authorization", "Bearer api_key")
+                .with_status(200)
+                .with_header("content-type", "image/jpg")
+                .with_body("image-content")
                 .create_async()
                 .await;
 
             let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
 
-            client.get_wallpaper(&get_wallpaper_request).await.unwrap();
+            let filename = format!("./{}.jpg", Uuid::new_v4());
 
-            mock.assert_async().await;
+            client
+                .download_wallpaper(&filename, &download_wallpaper_request)
+                .await
+                .unwrap();
+
+            download_wallpaper_mock.assert_async().await;
+            download_file_mock.assert_async().await;
+
+            fs::remove_file(&filename)?;
 
             Ok(())
         }
 
         #[tokio::test]
-        async fn get_wallpaper_does_send_filter_res_height_if_provided()
-        -> Result<(), Box<dyn Error>> {
+        async fn download_wallpaper_sends_path_parameters() -> Result<(), Box<dyn Error>> {
             let mut server = mockito::Server::new_async().await;
 
-            let get_wallpaper_request = GetWallpaperRequest::builder()
-                .wallpaper_id(2)
-                .filter_res_height(1)
+            let download_wallpaper_request = DownloadWallpaperRequest::builder()
+                .wallpaper_type(WallpaperType::Dual)
+                .width(2)
+                .height(3)
+                .wallpaper_id(4)
                 .build();
 
-            let mock = server
-                .mock("GET", Matcher::Regex(r"^/v2/core/wallpaper.*$".to_string()))
-                .match_query(Matcher::Regex("filter_res_height=1".to_string()))
+            let download_wallpaper_mock = server
+                .mock(
+                    "GET",
+                    Matcher::Regex(format!(
+                        r"^/v2/core/download/wallpaper/{}/{}/{}/{}?.*$",
+                        download_wallpaper_request.wallpaper_type.as_str(),
+                        download_wallpaper_request.width,
+                        download_wallpaper_request.height,
+                        download_wallpaper_request.wallpaper_id
+                    )),
+                )
                 .match_header("Authorization", "Bearer api_key")
                 .with_status(200)
                 .with_header("content-type", "application/json")
-                .with_body(fs::read_to_string(
-                    "resources/get_wallpaper_success_fully_populated.json",
-                )?)
+                .with_body(
+                    fs::read_to_string(
+                        "resources/download_wallpaper_success_fully_populated.json",
+                    )?
+                    .replace("{{host}}", &server.url()),
+                )
+                .create_async()
+                .await;
+
+            let download_file_mock = server
+                .mock("GET", Matcher::Regex(r"^/test.jpg$".to_string()))
+                .match_header("Authorization", "Bearer api_key")
+                .with_status(200)
+                .with_header("content-type", "image/jpg")
+                .with_body("image-content")
                 .create_async()
                 .await;
 
             let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
 
-            client.get_wallpaper(&get_wallpaper_request).await.unwrap();
+            let filename = format!("./{}.jpg", Uuid::new_v4());
 
-            mock.assert_async().await;
+            client
+                .download_wallpaper(&filename, &download_wallpaper_request)
+                .await
+                .unwrap();
+
+            download_wallpaper_mock.assert_async().await;
+            download_file_mock.assert_async().await;
+
+            fs::remove_file(&filename)?;
 
             Ok(())
         }
 
         #[tokio::test]
-        async fn get_wallpaper_does_not_send_filter_res_width_if_not_provided()
+        async fn download_wallpaper_can_map_successful_response_fully_populated()
         -> Result<(), Box<dyn Error>> {
             let mut server = mockito::Server::new_async().await;
 
-            let get_wallpaper_request = GetWallpaperRequest::builder().wallpaper_id(2).build();
+            let download_wallpaper_request = DownloadWallpaperRequest::builder()
+                .width(2)
+                .height(3)
+                .wallpaper_id(4)
+                .build();
+
+            let download_wallpaper_mock = server
+                .mock(
+                    "GET",
+                    Matcher::Regex(r"^/v2/core/download/wallpaper.*$".to_string()),
+                )
+                .match_query(Matcher::Regex("show_watermark=true".to_string()))
+                .match_header("Authorization", "Bearer api_key")
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(
+                    fs::read_to_string(
+                        "resources/download_wallpaper_success_fully_populated.json",
+                    )?
+                    .replace("{{host}}", &server.url()),
+                )
+                .create_async()
+                .await;
 
-            let mock = server
-                .mock("GET", Matcher::Regex(r"^/v2/core/wallpaper.*$".to_string()))
-                .match_query(Matcher::Regex("[^filter_res_width]".to_string()))
+            let download_file_mock = server
+                .mock("GET", Matcher::Regex(r"^/test.jpg$".to_string()))
                 .match_header("Authorization", "Bearer api_key")
                 .with_status(200)
-                .with_header("content-type", "application/json")
-                .with_body(fs::read_to_string(
-                    "resources/get_wallpaper_success_fully_populated.json",
-                )?)
+                .with_header("content-type", "image/jpg")
+                .with_body("image-content")
                 .create_async()
                 .await;
 
             let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
 
-            client.get_wallpaper(&get_wallpaper_request).await.unwrap();
+            let filename = format!("./{}.jpg", Uuid::new_v4());
 
-            mock.assert_async().await;
+            client
+                .download_wallpaper(&filename, &download_wallpaper_request)
+                .await
+                .unwrap();
+
+            assert_eq!(fs::read_to_string(&filename)?, "image-content");
+
+            download_wallpaper_mock.assert_async().await;
+            download_file_mock.assert_async().await;
+
+            fs::remove_file(&filename)?;
 
             Ok(())
         }
 
         #[tokio::test]
-        async fn get_wallpaper_does_send_filter_res_width_if_provided() -> Result<(), Box<dyn Error>>
-        {
+        async fn download_wallpaper_can_map_successful_response_minimal_populated()
+        -> Result<(), Box<dyn Error>> {
             let mut server = mockito::Server::new_async().await;
 
-            let get_wallpaper_request = GetWallpaperRequest::builder()
-                .wallpaper_id(2)
-                .filter_res_width(1)
+            let download_wallpaper_request = DownloadWallpaperRequest::builder()
+                .width(2)
+                .height(3)
+                .wallpaper_id(4)
                 .build();
 
-            let mock = server
-                .mock("GET", Matcher::Regex(r"^/v2/core/wallpaper.*$".to_string()))
-                .match_query(Matcher::Regex("filter_res_width=1".to_string()))
+            let download_wallpaper_mock = server
+                .mock(
+                    "GET",
+                    Matcher::Regex(r"^/v2/core/download/wallpaper.*$".to_string()),
+                )
+                .match_query(Matcher::Regex("show_watermark=true".to_string()))
                 .match_header("Authorization", "Bearer api_key")
                 .with_status(200)
                 .with_header("content-type", "application/json")
-                .with_body(fs::read_to_string(
-                    "resources/get_wallpaper_success_fully_populated.json",
-                )?)
+                .with_body(
+                    fs::read_to_string(
+                        "resources/download_wallpaper_success_fully_populated.json",
+                    )?
+                    .replace("{{host}}", &server.url()),
+                )
                 .create_async()
                 .await;
 
-            let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
-
-            client.get_wallpaper(&get_wallpaper_request).await.unwrap();
-
-            mock.assert_async().await;
-
-            Ok(())
-        }
-
-        #[tokio::test]
-        async fn get_wallpaper_does_send_filter_res_operator_if_not_provided()
-        -> Result<(), Box<dyn Error>> {
-            let mut server = mockito::Server::new_async().await;
-
-            let get_wallpaper_request = GetWallpaperRequest::builder().wallpaper_id(2).build();
-
-            let mock = server
-                .mock("GET", Matcher::Regex(r"^/v2/core/wallpaper.*$".to_string()))
-                .match_query(Matcher::Regex("filter_res_operator=%3E%3D".to_string()))
+            let download_file_mock = server
+                .mock("GET", Matcher::Regex(r"^/test.jpg$".to_string()))
                 .match_header("Authorization", "Bearer api_key")
                 .with_status(200)
-                .with_header("content-type", "application/json")
-                .with_body(fs::read_to_string(
-                    "resources/get_wallpaper_success_fully_populated.json",
-                )?)
+                .with_header("content-type", "image/jpg")
+                .with_body("image-content")
                 .create_async()
                 .await;
 
             let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
 
-            client.get_wallpaper(&get_wallpaper_request).await.unwrap();
+            let filename = format!("./{}.jpg", Uuid::new_v4());
 
-            mock.assert_async().await;
+            client
+                .download_wallpaper(&filename, &download_wallpaper_request)
+                .await
+                .unwrap();
+
+            assert_eq!(fs::read_to_string(&filename)?, "image-content");
+
+            download_wallpaper_mock.assert_async().await;
+            download_file_mock.assert_async().await;
+
+            fs::remove_file(&filename)?;
 
             Ok(())
         }
 
         #[tokio::test]
-        async fn get_wallpaper_does_send_filter_res_operator_if_provided()
+        async fn download_wallpaper_can_map_unauthorised_response_when_getting_download_wallpaper_response()
         -> Result<(), Box<dyn Error>> {
             let mut server = mockito::Server::new_async().await;
 
-            let get_wallpaper_request = GetWallpaperRequest::builder()
-                .wallpaper_id(2)
-                .filter_res_operator(Operator::Equal)
+            let download_wallpaper_request = DownloadWallpaperRequest::builder()
+                .width(2)
+                .height(3)
+                .wallpaper_id(4)
                 .build();
 
-            let mock = server
-                .mock("GET", Matcher::Regex(r"^/v2/core/wallpaper.*$".to_string()))
-                .match_query(Matcher::Regex("filter_res_operator=%3D".to_string()))
+            let download_wallpaper_mock = server
+                .mock(
+                    "GET",
+                    Matcher::Regex(r"^/v2/core/download/wallpaper.*$".to_string()),
+                )
                 .match_header("Authorization", "Bearer api_key")
-                .with_status(200)
+                .with_status(401)
                 .with_header("content-type", "application/json")
-                .with_body(fs::read_to_string(
-                    "resources/get_wallpaper_success_fully_populated.json",
-                )?)
+                .with_body(fs::read_to_string("resources/unauthorised_response.json")?)
                 .create_async()
                 .await;
 
             let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
 
-            client.get_wallpaper(&get_wallpaper_request).await.unwrap();
+            let filename = format!("./{}.jpg", Uuid::new_v4());
 
-            mock.assert_async().await;
+            let ClientError::Unauthorized(error) = client
+                .download_wallpaper(&filename, &download_wallpaper_request)
+                .await
+                .unwrap_err()
+            else {
+                panic!("Expected an unauthorized error");
+            };
+
+            assert_eq!(error.code, 401);
+            assert_eq!(error.description, "Unauthorized".to_string());
+
+            assert!(!fs::exists(&filename)?);
+
+            download_wallpaper_mock.assert_async().await;
 
             Ok(())
         }
 
         #[tokio::test]
-        async fn get_wallpaper_does_send_filter_res_operator_height_if_not_provided()
+        async fn download_wallpaper_can_map_unauthorised_response_when_downloading_file()
         -> Result<(), Box<dyn Error>> {
             let mut server = mockito::Server::new_async().await;
 
-            let get_wallpaper_request = GetWallpaperRequest::builder().wallpaper_id(2).build();
+            let download_wallpaper_request = DownloadWallpaperRequest::builder()
+                .width(2)
+                .height(3)
+                .wallpaper_id(4)
+                .build();
 
-            let mock = server
-                .mock("GET", Matcher::Regex(r"^/v2/core/wallpaper.*$".to_string()))
-                .match_query(Matcher::Regex(
-                    "filter_res_operator_height=%3E%3D".to_string(),
-                ))
+            let download_wallpaper_mock = server
+                .mock(
+                    "GET",
+                    Matcher::Regex(r"^/v2/core/download/wallpaper.*$".to_string()),
+                )
+                .match_query(Matcher::Regex("show_watermark=true".to_string()))
                 .match_header("Authorization", "Bearer api_key")
                 .with_status(200)
                 .with_header("content-type", "application/json")
-                .with_body(fs::read_to_string(
-                    "resources/get_wallpaper_success_fully_populated.json",
-                )?)
+                .with_body(
+                    fs::read_to_string(
+                        "resources/download_wallpaper_success_fully_populated.json",
+                    )?
+                    .replace("{{host}}", &server.url()),
+                )
+                .create_async()
+                .await;
+
+            let download_file_mock = server
+                .mock("GET", Matcher::Regex(r"^/test.jpg$".to_string()))
+                .match_header("Authorization", "Bearer api_key")
+                .with_status(401)
+                .with_header("content-type", "application/json")
+                .with_body(fs::read_to_string("resources/unauthorised_response.json")?)
                 .create_async()
                 .await;
 
             let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
 
-            client.get_wallpaper(&get_wallpaper_request).await.unwrap();
+            let filename = format!("./{}.jpg", Uuid::new_v4());
 
-            mock.assert_async().await;
+            let ClientError::Unauthorized(error) = client
+                .download_wallpaper(&filename, &download_wallpaper_request)
+                .await
+                .unwrap_err()
+            else {
+                panic!("Expected an unauthorized error");
+            };
+
+            assert_eq!(error.code, 401);
+            assert_eq!(error.description, "Unauthorized".to_string());
+
+            assert!(!fs::exists(&filename)?);
+
+            download_wallpaper_mock.assert_async().await;
+            download_file_mock.assert_async().await;
 
             Ok(())
         }
 
         #[tokio::test]
-        async fn get_wallpaper_does_send_filter_res_operator_height_if_provided()
+        async fn download_wallpaper_can_map_bad_request_response_when_getting_download_wallpaper_response()
         -> Result<(), Box<dyn Error>> {
             let mut server = mockito::Server::new_async().await;
 
-            let get_wallpaper_request = GetWallpaperRequest::builder()
-                .wallpaper_id(2)
-                .filter_res_operator_height(Operator::Equal)
+            let download_wallpaper_request = DownloadWallpaperRequest::builder()
+                .width(2)
+                .height(3)
+                .wallpaper_id(4)
                 .build();
 
-            let mock = server
-                .mock("GET", Matcher::Regex(r"^/v2/core/wallpaper.*$".to_string()))
-                .match_query(Matcher::Regex("filter_res_operator_height=%3D".to_string()))
+            let download_wallpaper_mock = server
+                .mock(
+                    "GET",
+                    Matcher::Regex(r"^/v2/core/download/wallpaper.*$".to_string()),
+                )
                 .match_header("Authorization", "Bearer api_key")
-                .with_status(200)
+                .with_status(400)
                 .with_header("content-type", "application/json")
                 .with_body(fs::read_to_string(
-                    "resources/get_wallpaper_success_fully_populated.json",
+                    "resources/download_wallpaper_bad_request.json",
                 )?)
                 .create_async()
                 .await;
 
             let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
 
-            client.get_wallpaper(&get_wallpaper_request).await.unwrap();
+            let filename = format!("./{}.jpg", Uuid::new_v4());
 
-            mock.assert_async().await;
+            let ClientError::BadRequest(error) = client
+                .download_wallpaper(&filename, &download_wallpaper_request)
+                .await
+                .unwrap_err()
+            else {
+                panic!("Expected a bad request error");
+            };
+
+            assert_eq!(error.code, 400);
+            assert_eq!(error.description, "Bad Request".to_string());
+            assert_eq!(
+                error.errors.unwrap(),
+                vec![
+                    "\"type\" must be one of [single, dual, triple, mobile]",
+                    "\"width\" must be a number"
+                ]
+            );
+
+            assert!(!fs::exists(&filename)?);
+
+            download_wallpaper_mock.assert_async().await;
 
             Ok(())
         }
 
         #[tokio::test]
-        async fn get_wallpaper_does_send_filter_res_operator_width_if_not_provided()
+        async fn download_wallpaper_can_map_not_found_response_when_downloading_file()
         -> Result<(), Box<dyn Error>> {
             let mut server = mockito::Server::new_async().await;
 
-            let get_wallpaper_request = GetWallpaperRequest::builder().wallpaper_id(2).build();
+            let download_wallpaper_request = DownloadWallpaperRequest::builder()
+                .width(2)
+                .height(3)
+                .wallpaper_id(4)
+                .build();
 
-            let mock = server
-                .mock("GET", Matcher::Regex(r"^/v2/core/wallpaper.*$".to_string()))
-                .match_query(Matcher::Regex(
-                    "filter_res_operator_width=%3E%3D".to_string(),
-                ))
+            let download_wallpaper_mock = server
+                .mock(
+                    "GET",
+                    Matcher::Regex(r"^/v2/core/download/wallpaper.*$".to_string()),
+                )
+                .match_query(Matcher::Regex("show_watermark=true".to_string()))
+                .match_header("Authorization", "Bearer api_key")
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(
+                    fs::read_to_string(
+                        "resources/download_wallpaper_success_fully_populated.json",
+                    )?
+                    .replace("{{host}}", &server.url()),
+                )
+                .create_async()
+                .await;
+
+            let download_file_mock = server
+                .mock("GET", Matcher::Regex(r"^/test.jpg$".to_string()))
                 .match_header("Authorization", "Bearer api_key")
-                .with_status(200)
-                .with_header("content-type", "application/json")
-                .with_body(fs::read_to_string(
-                    "resources/get_wallpaper_success_fully_populated.json",
-                )?)
+                .with_status(404)
+                .with_header("content-type", "text/plain")
+                .with_body("Object Not Found")
                 .create_async()
                 .await;
 
             let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
 
-            client.get_wallpaper(&get_wallpaper_request).await.unwrap();
+            let filename = format!("./{}.jpg", Uuid::new_v4());
 
-            mock.assert_async().await;
+            let ClientError::NotFound(error) = client
+                .download_wallpaper(&filename, &download_wallpaper_request)
+                .await
+                .unwrap_err()
+            else {
+                panic!("Expected a not found error");
+            };
+
+            assert_eq!(error.code, 404);
+            assert_eq!(error.description, "Not Found".to_string());
+            assert_eq!(error.errors.unwrap(), vec!["Object Not Found"]);
+
+            assert!(!fs::exists(&filename)?);
+
+            download_wallpaper_mock.assert_async().await;
+            download_file_mock.assert_async().await;
 
             Ok(())
         }
 
         #[tokio::test]
-        async fn get_wallpaper_does_send_filter_res_operator_width_if_provided()
+        async fn download_wallpaper_can_map_unknown_error_response_when_downloading_file()
         -> Result<(), Box<dyn Error>> {
             let mut server = mockito::Server::new_async().await;
 
-            let get_wallpaper_request = GetWallpaperRequest::builder()
-                .wallpaper_id(2)
-                .filter_res_operator_width(Operator::Equal)
+            let download_wallpaper_request = DownloadWallpaperRequest::builder()
+                .width(2)
+                .height(3)
+                .wallpaper_id(4)
                 .build();
 
-            let mock = server
-                .mock("GET", Matcher::Regex(r"^/v2/core/wallpaper.*$".to_string()))
-                .match_query(Matcher::Regex("filter_res_operator_width=%3D".to_string()))
+            server
+                .mock(
+                    "GET",
+                    Matcher::Regex(r"^/v2/core/download/wallpaper.*$".to_string()),
+                )
+                .match_query(Matcher::Regex("show_watermark=true".to_string()))
                 .match_header("Authorization", "Bearer api_key")
                 .with_status(200)
                 .with_header("content-type", "application/json")
-                .with_body(fs::read_to_string(
-                    "resources/get_wallpaper_success_fully_populated.json",
-                )?)
+                .with_body(
+                    fs::read_to_string(
+                        "resources/download_wallpaper_success_fully_populated.json",
+                    )?
+                    .replace("{{host}}", &server.url()),
+                )
+                .create_async()
+                .await;
+
+            server
+                .mock("GET", Matcher::Regex(r"^/test.jpg$".to_string()))
+                .match_header("Authorization", "Bearer api_key")
+                .with_status(405)
                 .create_async()
                 .await;
 
             let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
 
-            client.get_wallpaper(&get_wallpaper_request).await.unwrap();
+            let filename = format!("./{}.jpg", Uuid::new_v4());
 
-            mock.assert_async().await;
+            let ClientError::Api(error) = client
+                .download_wallpaper(&filename, &download_wallpaper_request)
+                .await
+                .unwrap_err()
+            else {
+                panic!("Expected an API error");
+            };
+
+            assert_eq!(error.code, 405);
+            assert_eq!(error.description, "Method Not Allowed".to_string());
+            assert_eq!(error.errors, None);
+
+            assert!(!fs::exists(&filename)?);
 
             Ok(())
         }
 
         #[tokio::test]
-        async fn get_wallpaper_does_send_show_comments_if_not_provided()
-        -> Result<(), Box<dyn Error>> {
+        async fn download_wallpaper_can_write_large_file() -> Result<(), Box<dyn Error>> {
             let mut server = mockito::Server::new_async().await;
 
-            let get_wallpaper_request = GetWallpaperRequest::builder().wallpaper_id(2).build();
+            let download_wallpaper_request = DownloadWallpaperRequest::builder()
+                .width(2)
+                .height(3)
+                .wallpaper_id(4)
+                .build();
 
-            let mock = server
-                .mock("GET", Matcher::Regex(r"^/v2/core/wallpaper.*$".to_string()))
-                .match_query(Matcher::Regex("show_comments=false".to_string()))
-                .match_header("Authorization", "Bearer api_key")
+            let image_content: Vec<u8> = (0..4 * 1024 * 1024).map(|i| (i % 251) as u8).collect();
+
+            let download_wallpaper_mock = server
+                .mock(
+                    "GET",
+                    Matcher::Regex(r"^/v2/core/download/wallpaper.*$".to_string()),
+                )
                 .with_status(200)
                 .with_header("content-type", "application/json")
-                .with_body(fs::read_to_string(
-                    "resources/get_wallpaper_success_fully_populated.json",
-                )?)
+                .with_body(
+                    fs::read_to_string(
+                        "resources/download_wallpaper_success_fully_populated.json",
+                    )?
+                    .replace("{{host}}", &server.url()),
+                )
+                .create_async()
+                .await;
+
+            let download_file_mock = server
+                .mock("GET", Matcher::Regex(r"^/test.jpg$".to_string()))
+                .with_status(200)
+                .with_header("content-type", "image/jpg")
+                .with_body(&image_content)
                 .create_async()
                 .await;
 
             let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
 
-            client.get_wallpaper(&get_wallpaper_request).await.unwrap();
+            let filename = format!("./{}.jpg", Uuid::new_v4());
 
-            mock.assert_async().await;
+            client
+                .download_wallpaper(&filename, &download_wallpaper_request)
+                .await
+                .unwrap();
+
+            assert_eq!(fs::read(&filename)?, image_content);
+
+            download_wallpaper_mock.assert_async().await;
+            download_file_mock.assert_async().await;
+
+            fs::remove_file(&filename)?;
 
             Ok(())
         }
 
+        fn temporary_files_for(filename: &str) -> Vec<String> {
+            let prefix = format!(".{}.", filename.trim_start_matches("./"));
+            fs::read_dir(".")
+                .unwrap()
+                .map(|entry| entry.unwrap().file_name().to_string_lossy().to_string())
+                .filter(|name| name.starts_with(&prefix))
+                .collect()
+        }
+
         #[tokio::test]
-        async fn get_wallpaper_does_send_show_comments_if_provided() -> Result<(), Box<dyn Error>> {
+        async fn download_wallpaper_replaces_existing_file() -> Result<(), Box<dyn Error>> {
             let mut server = mockito::Server::new_async().await;
 
-            let get_wallpaper_request = GetWallpaperRequest::builder()
-                .wallpaper_id(2)
-                .show_comments(true)
+            let download_wallpaper_request = DownloadWallpaperRequest::builder()
+                .width(2)
+                .height(3)
+                .wallpaper_id(4)
                 .build();
 
-            let mock = server
-                .mock("GET", Matcher::Regex(r"^/v2/core/wallpaper.*$".to_string()))
-                .match_query(Matcher::Regex("show_comments=true".to_string()))
-                .match_header("Authorization", "Bearer api_key")
+            let download_wallpaper_mock = server
+                .mock(
+                    "GET",
+                    Matcher::Regex(r"^/v2/core/download/wallpaper.*$".to_string()),
+                )
                 .with_status(200)
                 .with_header("content-type", "application/json")
-                .with_body(fs::read_to_string(
-                    "resources/get_wallpaper_success_fully_populated.json",
-                )?)
+                .with_body(
+                    fs::read_to_string(
+                        "resources/download_wallpaper_success_fully_populated.json",
+                    )?
+                    .replace("{{host}}", &server.url()),
+                )
+                .create_async()
+                .await;
+
+            let download_file_mock = server
+                .mock("GET", Matcher::Regex(r"^/test.jpg$".to_string()))
+                .with_status(200)
+                .with_header("content-type", "image/jpg")
+                .with_body("image-content")
                 .create_async()
                 .await;
 
             let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
 
-            client.get_wallpaper(&get_wallpaper_request).await.unwrap();
+            let filename = format!("./{}.jpg", Uuid::new_v4());
+            fs::write(&filename, "previous-image-content")?;
 
-            mock.assert_async().await;
+            client
+                .download_wallpaper(&filename, &download_wallpaper_request)
+                .await
+                .unwrap();
+
+            assert_eq!(fs::read_to_string(&filename)?, "image-content");
+            assert!(temporary_files_for(&filename).is_empty());
+
+            download_wallpaper_mock.assert_async().await;
+            download_file_mock.assert_async().await;
+
+            fs::remove_file(&filename)?;
 
             Ok(())
         }
 
         #[tokio::test]
-        async fn get_wallpaper_does_send_show_pickle_jar_if_not_provided()
+        async fn download_wallpaper_keeps_existing_file_when_download_fails()
         -> Result<(), Box<dyn Error>> {
             let mut server = mockito::Server::new_async().await;
 
-            let get_wallpaper_request = GetWallpaperRequest::builder().wallpaper_id(2).build();
+            let download_wallpaper_request = DownloadWallpaperRequest::builder()
+                .width(2)
+                .height(3)
+                .wallpaper_id(4)
+                .build();
 
-            let mock = server
-                .mock("GET", Matcher::Regex(r"^/v2/core/wallpaper.*$".to_string()))
-                .match_query(Matcher::Regex("show_pickle_jar=false".to_string()))
-                .match_header("Authorization", "Bearer api_key")
+            let download_wallpaper_mock = server
+                .mock(
+                    "GET",
+                    Matcher::Regex(r"^/v2/core/download/wallpaper.*$".to_string()),
+                )
                 .with_status(200)
                 .with_header("content-type", "application/json")
-                .with_body(fs::read_to_string(
-                    "resources/get_wallpaper_success_fully_populated.json",
-                )?)
+                .with_body(
+                    fs::read_to_string(
+                        "resources/download_wallpaper_success_fully_populated.json",
+                    )?
+                    .replace("{{host}}", &server.url()),
+                )
+                .create_async()
+                .await;
+
+            let download_file_mock = server
+                .mock("GET", Matcher::Regex(r"^/test.jpg$".to_string()))
+                .with_status(404)
+                .with_header("content-type", "text/plain")
+                .with_body("Object Not Found")
                 .create_async()
                 .await;
 
             let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
 
-            client.get_wallpaper(&get_wallpaper_request).await.unwrap();
+            let filename = format!("./{}.jpg", Uuid::new_v4());
+            fs::write(&filename, "previous-image-content")?;
 
-            mock.assert_async().await;
+            client
+                .download_wallpaper(&filename, &download_wallpaper_request)
+                .await
+                .unwrap_err();
+
+            assert_eq!(fs::read_to_string(&filename)?, "previous-image-content");
+            assert!(temporary_files_for(&filename).is_empty());
+
+            download_wallpaper_mock.assert_async().await;
+            download_file_mock.assert_async().await;
+
+            fs::remove_file(&filename)?;
 
             Ok(())
         }
 
         #[tokio::test]
-        async fn get_wallpaper_does_send_show_pickle_jar_if_provided() -> Result<(), Box<dyn Error>>
-        {
+        async fn download_wallpaper_keeps_existing_file_when_body_is_interrupted()
+        -> Result<(), Box<dyn Error>> {
             let mut server = mockito::Server::new_async().await;
 
-            let get_wallpaper_request = GetWallpaperRequest::builder()
-                .wallpaper_id(2)
-                .show_pickle_jar(true)
+            let download_wallpaper_request = DownloadWallpaperRequest::builder()
+                .width(2)
+                .height(3)
+                .wallpaper_id(4)
                 .build();
 
-            let mock = server
-                .mock("GET", Matcher::Regex(r"^/v2/core/wallpaper.*$".to_string()))
-                .match_query(Matcher::Regex("show_pickle_jar=true".to_string()))
-                .match_header("Authorization", "Bearer api_key")
+            let download_wallpaper_mock = server
+                .mock(
+                    "GET",
+                    Matcher::Regex(r"^/v2/core/download/wallpaper.*$".to_string()),
+                )
                 .with_status(200)
                 .with_header("content-type", "application/json")
-                .with_body(fs::read_to_string(
-                    "resources/get_wallpaper_success_fully_populated.json",
-                )?)
+                .with_body(
+                    fs::read_to_string(
+                        "resources/download_wallpaper_success_fully_populated.json",
+                    )?
+                    .replace("{{host}}", &server.url()),
+                )
+                .create_async()
+                .await;
+
+            let download_file_mock = server
+                .mock("GET", Matcher::Regex(r"^/test.jpg$".to_string()))
+                .with_status(200)
+                .with_header("content-type", "image/jpg")
+                .with_chunked_body(|writer| {
+                    writer.write_all(b"image-")?;
+                    Err(std::io::Error::other("connection reset"))
+                })
                 .create_async()
                 .await;
 
             let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
 
-            client.get_wallpaper(&get_wallpaper_request).await.unwrap();
+            let filename = format!("./{}.jpg", Uuid::new_v4());
+            fs::write(&filename, "previous-image-content")?;
+
+            let error = client
+                .download_wallpaper(&filename, &download_wallpaper_request)
+                .await
+                .unwrap_err();
+
+            assert!(matches!(error, ClientError::Transport(_)));
+            assert_eq!(fs::read_to_string(&filename)?, "previous-image-content");
+            assert!(temporary_files_for(&filename).is_empty());
+
+            download_wallpaper_mock.assert_async().await;
+            download_file_mock.assert_async().await;
 
-            mock.assert_async().await;
+            fs::remove_file(&filename)?;
 
             Ok(())
         }
 
         #[tokio::test]
-        async fn get_wallpaper_does_send_show_resolutions_if_not_provided()
+        async fn download_wallpaper_removes_temporary_file_when_cancelled()
         -> Result<(), Box<dyn Error>> {
             let mut server = mockito::Server::new_async().await;
 
-            let get_wallpaper_request = GetWallpaperRequest::builder().wallpaper_id(2).build();
+            let download_wallpaper_request = DownloadWallpaperRequest::builder()
+                .width(2)
+                .height(3)
+                .wallpaper_id(4)
+                .build();
 
-            let mock = server
-                .mock("GET", Matcher::Regex(r"^/v2/core/wallpaper.*$".to_string()))
-                .match_query(Matcher::Regex("show_resolutions=true".to_string()))
-                .match_header("Authorization", "Bearer api_key")
+            let download_wallpaper_mock = server
+                .mock(
+                    "GET",
+                    Matcher::Regex(r"^/v2/core/download/wallpaper.*$".to_string()),
+                )
                 .with_status(200)
                 .with_header("content-type", "application/json")
-                .with_body(fs::read_to_string(
-                    "resources/get_wallpaper_success_fully_populated.json",
-                )?)
+                .with_body(
+                    fs::read_to_string(
+                        "resources/download_wallpaper_success_fully_populated.json",
+                    )?
+                    .replace("{{host}}", &server.url()),
+                )
+                .create_async()
+                .await;
+
+            server
+                .mock("GET", Matcher::Regex(r"^/test.jpg$".to_string()))
+                .with_status(200)
+                .with_header("content-type", "image/jpg")
+                .with_chunked_body(|writer| {
+                    writer.write_all(b"image-")?;
+                    writer.flush()?;
+                    std::thread::sleep(std::time::Duration::from_secs(2));
+                    writer.write_all(b"content")
+                })
                 .create_async()
                 .await;
 
             let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
 
-            client.get_wallpaper(&get_wallpaper_request).await.unwrap();
+            let filename = format!("./{}.jpg", Uuid::new_v4());
 
-            mock.assert_async().await;
+            let result = tokio::time::timeout(
+                std::time::Duration::from_millis(500),
+                client.download_wallpaper(&filename, &download_wallpaper_request),
+            )
+            .await;
+
+            assert!(result.is_err());
+            assert!(!fs::exists(&filename)?);
+            assert!(temporary_files_for(&filename).is_empty());
+
+            download_wallpaper_mock.assert_async().await;
 
             Ok(())
         }
+    }
+
+    mod download_wallpaper_to {
+        use super::*;
 
         #[tokio::test]
-        async fn get_wallpaper_does_send_show_resolutions_if_provided() -> Result<(), Box<dyn Error>>
-        {
+        async fn download_wallpaper_to_streams_file_into_writer() -> Result<(), Box<dyn Error>> {
             let mut server = mockito::Server::new_async().await;
 
-            let get_wallpaper_request = GetWallpaperRequest::builder()
-                .wallpaper_id(2)
-                .show_resolutions(false)
+            let download_wallpaper_request = DownloadWallpaperRequest::builder()
+                .width(2)
+                .height(3)
+                .wallpaper_id(4)
                 .build();
 
-            let mock = server
-                .mock("GET", Matcher::Regex(r"^/v2/core/wallpaper.*$".to_string()))
-                .match_query(Matcher::Regex("show_resolutions=false".to_string()))
+            let download_wallpaper_mock = server
+                .mock(
+                    "GET",
+                    Matcher::Regex(r"^/v2/core/download/wallpaper.*$".to_string()),
+                )
+                .match_query(Matcher::Regex("show_watermark=true".to_string()))
                 .match_header("Authorization", "Bearer api_key")
                 .with_status(200)
                 .with_header("content-type", "application/json")
-                .with_body(fs::read_to_string(
-                    "resources/get_wallpaper_success_fully_populated.json",
-                )?)
+                .with_body(
+                    fs::read_to_string(
+                        "resources/download_wallpaper_success_fully_populated.json",
+                    )?
+                    .replace("{{host}}", &server.url()),
+                )
+                .create_async()
+                .await;
+
+            let download_file_mock = server
+                .mock("GET", Matcher::Regex(r"^/test.jpg$".to_string()))
+                .with_status(200)
+                .with_header("content-type", "image/jpg")
+                .with_body("image-content")
                 .create_async()
                 .await;
 
             let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
 
-            client.get_wallpaper(&get_wallpaper_request).await.unwrap();
+            let mut image_content: Vec<u8> = vec![];
 
-            mock.assert_async().await;
+            client
+                .download_wallpaper_to(&mut image_content, &download_wallpaper_request)
+                .await
+                .unwrap();
+
+            assert_eq!(image_content, b"image-content");
+
+            download_wallpaper_mock.assert_async().await;
+            download_file_mock.assert_async().await;
 
             Ok(())
         }
 
         #[tokio::test]
-        async fn get_wallpaper_sends_path_parameters() -> Result<(), Box<dyn Error>> {
+        async fn download_wallpaper_to_can_map_not_found_response_when_downloading_file()
+        -> Result<(), Box<dyn Error>> {
             let mut server = mockito::Server::new_async().await;
 
-            let get_wallpaper_request = GetWallpaperRequest::builder().wallpaper_id(2).build();
+            let download_wallpaper_request = DownloadWallpaperRequest::builder()
+                .width(2)
+                .height(3)
+                .wallpaper_id(4)
+                .build();
 
-            let mock = server
+            let download_wallpaper_mock = server
                 .mock(
                     "GET",
-                    Matcher::Regex(format!(
-                        r"^/v2/core/wallpaper/{}?.*$",
-                        get_wallpaper_request.wallpaper_id
-                    )),
+                    Matcher::Regex(r"^/v2/core/download/wallpaper.*$".to_string()),
                 )
-                .match_header("Authorization", "Bearer api_key")
                 .with_status(200)
                 .with_header("content-type", "application/json")
-                .with_body(fs::read_to_string(
-                    "resources/get_wallpaper_success_fully_populated.json",
-                )?)
+                .with_body(
+                    fs::read_to_string(
+                        "resources/download_wallpaper_success_fully_populated.json",
+                    )?
+                    .replace("{{host}}", &server.url()),
+                )
+                .create_async()
+                .await;
+
+            let download_file_mock = server
+                .mock("GET", Matcher::Regex(r"^/test.jpg$".to_string()))
+                .with_status(404)
+                .with_header("content-type", "text/plain")
+                .with_body("Object Not Found")
                 .create_async()
                 .await;
 
             let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
 
-            client.get_wallpaper(&get_wallpaper_request).await.unwrap();
+            let mut image_content: Vec<u8> = vec![];
 
-            mock.assert_async().await;
+            let ClientError::NotFound(error) = client
+                .download_wallpaper_to(&mut image_content, &download_wallpaper_request)
+                .await
+                .unwrap_err()
+            else {
+                panic!("Expected a not found error");
+            };
+
+            assert_eq!(error.code, 404);
+            assert!(image_content.is_empty());
+
+            download_wallpaper_mock.assert_async().await;
+            download_file_mock.assert_async().await;
 
             Ok(())
         }
+    }
 
-        #[tokio::test]
-        async fn get_wallpaper_can_map_successful_response_fully_populated()
-        -> Result<(), Box<dyn Error>> {
-            let mut server = mockito::Server::new_async().await;
+    mod get_thumbnail {
+        use super::*;
 
-            let get_wallpaper_request = GetWallpaperRequest::builder().wallpaper_id(2).build();
+        const THUMBNAIL_PATH: &str = "/thumbnail/12x13/vulcan_thumbnail_12x13.jpg";
 
-            let mock = server
+        /// Fetches a wallpaper from `api_server` whose response points the thumb endpoint at
+        /// `thumb_server`.
+        async fn get_wallpaper(
+            client: &DigitalBlasphemyClient,
+            api_server: &mut mockito::ServerGuard,
+            thumb_server: &mockito::ServerGuard,
+        ) -> Result<Wallpaper, Box<dyn Error>> {
+            api_server
                 .mock("GET", Matcher::Regex(r"^/v2/core/wallpaper.*$".to_string()))
-                .match_header("Authorization", "Bearer api_key")
                 .with_status(200)
                 .with_header("content-type", "application/json")
-                .with_body(fs::read_to_string(
-                    "resources/get_wallpaper_success_fully_populated.json",
-                )?)
+                .with_body(
+                    fs::read_to_string("resources/get_wallpaper_success_fully_populated.json")?
+                        .replace("https://cdn.digitalblasphemy.com", &thumb_server.url()),
+                )
                 .create_async()
                 .await;
+            let wallpaper = client
+                .get_wallpaper(&GetWallpaperRequest::builder().wallpaper_id(2).build())
+                .await?
+                .unwrap();
+            Ok(wallpaper)
+        }
 
-            let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
+        #[tokio::test]
+        async fn get_thumbnail_uses_thumb_endpoint_without_credentials()
+        -> Result<(), Box<dyn Error>> {
+            let mut api_server = mockito::Server::new_async().await;
+            let mut thumb_server = mockito::Server::new_async().await;
 
-            let get_wallpaper_response = client
-                .get_wallpaper(&get_wallpaper_request)
-                .await
-                .unwrap()
-                .unwrap();
+            let mock = thumb_server
+                .mock("GET", THUMBNAIL_PATH)
+                .match_header("Authorization", Matcher::Missing)
+                .with_status(200)
+                .with_body("thumbnail")
+                .create_async()
+                .await;
 
-            let expected_wallpaper = Wallpaper {
-                id: 2,
-                all_free: Some(true),
-                comments: Some(Comments {
-                    comments: vec![
-                        Comment {
-                            id: "5".to_string(),
-                            author_id: "author ID 1".to_string(),
-                            author_display: "author display 1".to_string(),
-                            content: "Content 1".to_string(),
-                            rating: "6".to_string(),
-                            timestamp: 7,
-                        },
-                        Comment {
-                            id: "8".to_string(),
-                            author_id: "author ID 2".to_string(),
-                            author_display: "author display 2".to_string(),
-                            content: "Content 2".to_string(),
-                            rating: "9".to_string(),
-                            timestamp: 10,
-                        },
-                    ],
-                }),
-                content: Some("Content 3".to_string()),
-                free: Some(true),
-                name: "Vulcan".to_string(),
-                paths: Paths {
-                    api: "/wallpaper/2".to_string(),
-                    thumb: "/thumbnail/12x13/vulcan_thumbnail_12x13.jpg".to_string(),
-                    web: "/sec/vulcan/".to_string(),
-                },
-                pickle_jar: Some(PickleJar {
-                    parent: "parent 1".to_string(),
-                    siblings: vec!["sibling 1".to_string(), "sibling 2".to_string()],
-                }),
-                rating: Some("11".to_string()),
-                resolutions: Some(Resolutions {
-                    single: vec![
-                        Resolution {
-                            label: "12x13".to_string(),
-                            width: "12".to_string(),
-                            height: "13".to_string(),
-                            image: "/single/12x13/vulcan_single_12x13.jpg".to_string(),
-                        },
-                        Resolution {
-                            label: "14x15".to_string(),
-                            width: "14".to_string(),
-                            height: "15".to_string(),
-                            image: "/single/14x15/vulcan_single_14x15.jpg".to_string(),
-                        },
-                    ],
-                    dual: Some(vec![
-                        Resolution {
-                            label: "16x17".to_string(),
-                            width: "16".to_string(),
-                            height: "17".to_string(),
-                            image: "/dual/16x17/vulcan_dual_16x17.jpg".to_string(),
-                        },
-                        Resolution {
-                            label: "18x19".to_string(),
-                            width: "18".to_string(),
-                            height: "19".to_string(),
-                            image: "/dual/18x19/vulcan_dual_18x19.jpg".to_string(),
-                        },
-                    ]),
-                    triple: Some(vec![
-                        Resolution {
-                            label: "20x21".to_string(),
-                            width: "20".to_string(),
-                            height: "21".to_string(),
-                            image: "/triple/20x21/vulcan_triple_20x21.jpg".to_string(),
-                        },
-                        Resolution {
-                            label: "22x23".to_string(),
-                            width: "22".to_string(),
-                            height: "23".to_string(),
-                            image: "/triple/22x23/vulcan_triple_22x23.jpg".to_string(),
-                        },
-                    ]),
-                    mobile: Some(vec![
-                        Resolution {
-                            label: "24x25".to_string(),
-                            width: "24".to_string(),
-                            height: "25".to_string(),
-                            image: "/mobile/24x25/vulcan_mobile_24x25.jpg".to_string(),
-                        },
-                        Resolution {
-                            label: "26x27".to_string(),
-                            width: "26".to_string(),
-                            height: "27".to_string(),
-                            image: "/mobile/26x27/vulcan_mobile_26x27.jpg".to_string(),
-                        },
-                    ]),
-                }),
-                sku: Some("vulcan".to_string()),
-                tags: Some(HashMap::from([
-                    (
-                        "28".to_string(),
-                        Tag {
-                            id: 28,
-                            name: "Tag 1".to_string(),
-                        },
-                    ),
-                    (
-                        "29".to_string(),
-                        Tag {
-                            id: 29,
-                            name: "Tag 2".to_string(),
-                        },
-                    ),
-                ])),
-                timestamp: Some(30),
+            let client = DigitalBlasphemyClient::builder("api_key".to_string())
+                .base_url(api_server.url())
+                .build()?;
+            let wallpaper = get_wallpaper(&client, &mut api_server, &thumb_server).await?;
+
+            let thumbnail = client.get_thumbnail(&wallpaper).await?;
+
+            assert_eq!(thumbnail, Bytes::from("thumbnail"));
+
+            mock.assert_async().await;
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn get_thumbnail_follows_redirect_before_any_api_call() -> Result<(), Box<dyn Error>>
+        {
+            let mut thumb_server = mockito::Server::new_async().await;
+
+            let redirect_mock = thumb_server
+                .mock("GET", THUMBNAIL_PATH)
+                .with_status(302)
+                .with_header("Location", "/moved.jpg")
+                .create_async()
+                .await;
+            let mock = thumb_server
+                .mock("GET", "/moved.jpg")
+                .with_status(200)
+                .with_body("thumbnail")
+                .create_async()
+                .await;
+
+            let client = DigitalBlasphemyClient::builder("api_key".to_string())
+                .base_url("http://api.invalid")
+                .build()?;
+            *client.thumb_endpoint.write().unwrap() = thumb_server.url();
+
+            let thumbnail = client
+                .get_thumbnail(&crate::test_support::wallpaper())
+                .await?;
+
+            assert_eq!(thumbnail, Bytes::from("thumbnail"));
+
+            redirect_mock.assert_async().await;
+            mock.assert_async().await;
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn get_thumbnail_to_streams_into_writer() -> Result<(), Box<dyn Error>> {
+            let mut api_server = mockito::Server::new_async().await;
+            let mut thumb_server = mockito::Server::new_async().await;
+
+            let mock = thumb_server
+                .mock("GET", THUMBNAIL_PATH)
+                .with_status(200)
+                .with_body("thumbnail")
+                .create_async()
+                .await;
+
+            let client = DigitalBlasphemyClient::builder("api_key".to_string())
+                .base_url(api_server.url())
+                .build()?;
+            let wallpaper = get_wallpaper(&client, &mut api_server, &thumb_server).await?;
+
+            let mut thumbnail = Vec::new();
+            client.get_thumbnail_to(&mut thumbnail, &wallpaper).await?;
+
+            assert_eq!(thumbnail, b"thumbnail");
+
+            mock.assert_async().await;
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn get_thumbnail_serves_cached_thumbnail() -> Result<(), Box<dyn Error>> {
+            let mut api_server = mockito::Server::new_async().await;
+            let mut thumb_server = mockito::Server::new_async().await;
+            let cache_dir = tempfile::tempdir()?;
+
+            let mock = thumb_server
+                .mock("GET", THUMBNAIL_PATH)
+                .with_status(200)
+                .with_body("thumbnail")
+                .expect(1)
+                .create_async()
+                .await;
+
+            let client = DigitalBlasphemyClient::builder("api_key".to_string())
+                .base_url(api_server.url())
+                .thumbnail_cache_dir(cache_dir.path().join("thumbnails"))
+                .build()?;
+            let wallpaper = get_wallpaper(&client, &mut api_server, &thumb_server).await?;
+
+            let first = client.get_thumbnail(&wallpaper).await?;
+            let second = client.get_thumbnail(&wallpaper).await?;
+
+            assert_eq!(first, Bytes::from("thumbnail"));
+            assert_eq!(second, Bytes::from("thumbnail"));
+            let cached: Vec<String> = fs::read_dir(cache_dir.path().join("thumbnails"))?
+                .map(|entry| entry.unwrap().file_name().to_string_lossy().to_string())
+                .collect();
+            assert_eq!(cached.len(), 1);
+            assert!(cached[0].ends_with(".jpg"));
+
+            mock.assert_async().await;
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn get_thumbnail_does_not_cache_failures() -> Result<(), Box<dyn Error>> {
+            let mut api_server = mockito::Server::new_async().await;
+            let mut thumb_server = mockito::Server::new_async().await;
+            let cache_dir = tempfile::tempdir()?;
+
+            let mock = thumb_server
+                .mock("GET", THUMBNAIL_PATH)
+                .with_status(404)
+                .create_async()
+                .await;
+
+            let client = DigitalBlasphemyClient::builder("api_key".to_string())
+                .base_url(api_server.url())
+                .thumbnail_cache_dir(cache_dir.path())
+                .build()?;
+            let wallpaper = get_wallpaper(&client, &mut api_server, &thumb_server).await?;
+
+            let ClientError::NotFound(_) = client.get_thumbnail(&wallpaper).await.unwrap_err()
+            else {
+                panic!("Expected a not found error");
             };
-            assert_eq!(get_wallpaper_response, expected_wallpaper);
+            assert_eq!(fs::read_dir(cache_dir.path())?.count(), 0);
 
             mock.assert_async().await;
 
@@ -3051,134 +6462,243 @@ mod tests {
         }
 
         #[tokio::test]
-        async fn get_wallpaper_can_map_successful_response_minimal_populated()
+        async fn get_thumbnails_keeps_order_and_reports_failures_separately()
         -> Result<(), Box<dyn Error>> {
-            let mut server = mockito::Server::new_async().await;
+            let mut api_server = mockito::Server::new_async().await;
+            let mut thumb_server = mockito::Server::new_async().await;
 
-            let get_wallpaper_request = GetWallpaperRequest::builder().wallpaper_id(2).build();
-
-            let mock = server
-                .mock("GET", Matcher::Regex(r"^/v2/core/wallpaper.*$".to_string()))
-                .match_header("Authorization", "Bearer api_key")
+            let mock = thumb_server
+                .mock("GET", THUMBNAIL_PATH)
                 .with_status(200)
-                .with_header("content-type", "application/json")
-                .with_body(fs::read_to_string(
-                    "resources/get_wallpaper_success_minimal_populated.json",
-                )?)
+                .with_body("thumbnail")
+                .expect(2)
+                .create_async()
+                .await;
+            let missing_mock = thumb_server
+                .mock("GET", "/thumbnail/missing.jpg")
+                .with_status(404)
                 .create_async()
                 .await;
 
-            let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
+            let client = DigitalBlasphemyClient::builder("api_key".to_string())
+                .base_url(api_server.url())
+                .build()?;
+            let wallpaper = get_wallpaper(&client, &mut api_server, &thumb_server).await?;
+            let mut missing = wallpaper.clone();
+            missing.paths.thumb = "/thumbnail/missing.jpg".to_string();
 
-            let get_wallpaper_response =
-                client.get_wallpaper(&get_wallpaper_request).await.unwrap();
+            let thumbnails = client
+                .get_thumbnails(&[wallpaper.clone(), missing, wallpaper], 2)
+                .await;
 
-            assert_eq!(get_wallpaper_response, None);
+            assert_eq!(thumbnails.len(), 3);
+            assert_eq!(thumbnails[0].as_ref().unwrap(), &Bytes::from("thumbnail"));
+            assert!(matches!(thumbnails[1], Err(ClientError::NotFound(_))));
+            assert_eq!(thumbnails[2].as_ref().unwrap(), &Bytes::from("thumbnail"));
 
             mock.assert_async().await;
+            missing_mock.assert_async().await;
 
             Ok(())
         }
 
         #[tokio::test]
-        async fn get_wallpaper_can_map_unauthorised_response() -> Result<(), Box<dyn Error>> {
+        async fn get_thumbnail_defaults_to_production_cdn() -> Result<(), Box<dyn Error>> {
+            let client = DigitalBlasphemyClient::new("api_key".to_string())?;
+            let response: GetWallpaperResponse = serde_json::from_str(&fs::read_to_string(
+                "resources/get_wallpaper_success_fully_populated.json",
+            )?)?;
+
+            let url = client.thumbnail_url(&response.wallpaper.unwrap())?;
+
+            assert_eq!(
+                url.as_str(),
+                "https://cdn.digitalblasphemy.com/thumbnail/12x13/vulcan_thumbnail_12x13.jpg"
+            );
+
+            Ok(())
+        }
+    }
+
+    mod download_wallpaper_resumable {
+        use super::*;
+        use uuid::Uuid;
+
+        #[tokio::test]
+        async fn download_wallpaper_resumable_downloads_whole_file_without_part_file()
+        -> Result<(), Box<dyn Error>> {
             let mut server = mockito::Server::new_async().await;
 
-            let get_wallpaper_request = GetWallpaperRequest::builder().wallpaper_id(1).build();
+            let download_wallpaper_request = DownloadWallpaperRequest::builder()
+                .width(2)
+                .height(3)
+                .wallpaper_id(4)
+                .build();
 
-            let mock = server
-                .mock("GET", Matcher::Regex(r"^/v2/core/wallpaper.*$".to_string()))
-                .match_header("Authorization", "Bearer api_key")
-                .with_status(401)
+            let download_wallpaper_mock = server
+                .mock(
+                    "GET",
+                    Matcher::Regex(r"^/v2/core/download/wallpaper.*$".to_string()),
+                )
+                .with_status(200)
                 .with_header("content-type", "application/json")
-                .with_body(fs::read_to_string("resources/unauthorised_response.json")?)
+                .with_body(
+                    fs::read_to_string(
+                        "resources/download_wallpaper_success_fully_populated.json",
+                    )?
+                    .replace("{{host}}", &server.url()),
+                )
+                .create_async()
+                .await;
+
+            let download_file_mock = server
+                .mock("GET", Matcher::Regex(r"^/test.jpg$".to_string()))
+                .match_header("Range", Matcher::Missing)
+                .with_status(200)
+                .with_header("content-type", "image/jpg")
+                .with_body("image-content")
                 .create_async()
                 .await;
 
             let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
 
-            let error = client
-                .get_wallpaper(&get_wallpaper_request)
+            let filename = format!("./{}.jpg", Uuid::new_v4());
+
+            client
+                .download_wallpaper_resumable(&filename, &download_wallpaper_request)
                 .await
-                .unwrap_err();
+                .unwrap();
 
-            assert_eq!(error.code, 401);
-            assert_eq!(error.description, "Unauthorized".to_string());
+            assert_eq!(fs::read_to_string(&filename)?, "image-content");
+            assert!(!fs::exists(format!("{filename}.part"))?);
 
-            mock.assert_async().await;
+            download_wallpaper_mock.assert_async().await;
+            download_file_mock.assert_async().await;
+
+            fs::remove_file(&filename)?;
 
             Ok(())
         }
 
         #[tokio::test]
-        async fn get_wallpaper_can_map_bad_request_response() -> Result<(), Box<dyn Error>> {
+        async fn download_wallpaper_resumable_appends_to_part_file() -> Result<(), Box<dyn Error>> {
             let mut server = mockito::Server::new_async().await;
 
-            let get_wallpaper_request = GetWallpaperRequest::builder().wallpaper_id(1).build();
+            let download_wallpaper_request = DownloadWallpaperRequest::builder()
+                .width(2)
+                .height(3)
+                .wallpaper_id(4)
+                .build();
 
-            let mock = server
-                .mock("GET", Matcher::Regex(r"^/v2/core/wallpaper.*$".to_string()))
-                .match_header("Authorization", "Bearer api_key")
-                .with_status(401)
+            let download_wallpaper_mock = server
+                .mock(
+                    "GET",
+                    Matcher::Regex(r"^/v2/core/download/wallpaper.*$".to_string()),
+                )
+                .with_status(200)
                 .with_header("content-type", "application/json")
-                .with_body(fs::read_to_string(
-                    "resources/get_wallpaper_bad_request.json",
-                )?)
+                .with_body(
+                    fs::read_to_string(
+                        "resources/download_wallpaper_success_fully_populated.json",
+                    )?
+                    .replace("{{host}}", &server.url()),
+                )
+                .create_async()
+                .await;
+
+            let download_file_mock = server
+                .mock("GET", Matcher::Regex(r"^/test.jpg$".to_string()))
+                .match_header("Range", "bytes=6-")
+                .match_header("If-Range", "\"v1\"")
+                .with_status(206)
+                .with_header("content-type", "image/jpg")
+                .with_header("Content-Range", "bytes 6-12/13")
+                .with_body("content")
                 .create_async()
                 .await;
 
             let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
 
-            let error = client
-                .get_wallpaper(&get_wallpaper_request)
+            let filename = format!("./{}.jpg", Uuid::new_v4());
+            fs::write(format!("{filename}.part"), "image-")?;
+            fs::write(format!("{filename}.part.validator"), "\"v1\"")?;
+
+            client
+                .download_wallpaper_resumable(&filename, &download_wallpaper_request)
                 .await
-                .unwrap_err();
+                .unwrap();
 
-            assert_eq!(error.code, 400);
-            assert_eq!(error.description, "Bad Request".to_string());
-            assert_eq!(
-                error.errors.unwrap(),
-                vec![
-                    "\"filter_res_height\" must be greater than or equal to 1",
-                    "\"filter_res_width\" must be greater than or equal to 1"
-                ]
-            );
+            assert_eq!(fs::read_to_string(&filename)?, "image-content");
+            assert!(!fs::exists(format!("{filename}.part"))?);
 
-            mock.assert_async().await;
+            download_wallpaper_mock.assert_async().await;
+            download_file_mock.assert_async().await;
+
+            fs::remove_file(&filename)?;
 
             Ok(())
         }
 
         #[tokio::test]
-        #[should_panic(expected = "Unable to parse the body as JSON ErrorResponse")]
-        async fn get_wallpaper_can_map_unknown_error_response() {
+        async fn download_wallpaper_resumable_restarts_when_server_ignores_range()
+        -> Result<(), Box<dyn Error>> {
             let mut server = mockito::Server::new_async().await;
 
-            let get_wallpaper_request = GetWallpaperRequest::builder().wallpaper_id(1).build();
+            let download_wallpaper_request = DownloadWallpaperRequest::builder()
+                .width(2)
+                .height(3)
+                .wallpaper_id(4)
+                .build();
 
-            server
-                .mock("GET", Matcher::Regex(r"^/v2/core/wallpaper.*$".to_string()))
-                .match_header("Authorization", "Bearer api_key")
-                .with_status(405)
+            let download_wallpaper_mock = server
+                .mock(
+                    "GET",
+                    Matcher::Regex(r"^/v2/core/download/wallpaper.*$".to_string()),
+                )
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(
+                    fs::read_to_string(
+                        "resources/download_wallpaper_success_fully_populated.json",
+                    )?
+                    .replace("{{host}}", &server.url()),
+                )
                 .create_async()
                 .await;
 
-            let client =
-                DigitalBlasphemyClient::new_test("api_key".to_string(), server.url()).unwrap();
+            let download_file_mock = server
+                .mock("GET", Matcher::Regex(r"^/test.jpg$".to_string()))
+                .match_header("Range", "bytes=6-")
+                .match_header("If-Range", "\"v1\"")
+                .with_status(200)
+                .with_header("content-type", "image/jpg")
+                .with_body("image-content")
+                .create_async()
+                .await;
 
-            let _ = client
-                .get_wallpaper(&get_wallpaper_request)
+            let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
+
+            let filename = format!("./{}.jpg", Uuid::new_v4());
+            fs::write(format!("{filename}.part"), "image-")?;
+            fs::write(format!("{filename}.part.validator"), "\"v1\"")?;
+
+            client
+                .download_wallpaper_resumable(&filename, &download_wallpaper_request)
                 .await
-                .unwrap_err();
-        }
-    }
+                .unwrap();
 
-    mod download_wallpaper {
-        use super::*;
-        use uuid::Uuid;
+            assert_eq!(fs::read_to_string(&filename)?, "image-content");
+
+            download_wallpaper_mock.assert_async().await;
+            download_file_mock.assert_async().await;
+
+            fs::remove_file(&filename)?;
+
+            Ok(())
+        }
 
         #[tokio::test]
-        async fn download_wallpaper_does_send_show_watermark_if_not_provided()
+        async fn download_wallpaper_resumable_restarts_when_content_range_does_not_match()
         -> Result<(), Box<dyn Error>> {
             let mut server = mockito::Server::new_async().await;
 
@@ -3193,8 +6713,6 @@ mod tests {
                     "GET",
                     Matcher::Regex(r"^/v2/core/download/wallpaper.*$".to_string()),
                 )
-                .match_query(Matcher::Regex("show_watermark=true".to_string()))
-                .match_header("Authorization", "Bearer api_key")
                 .with_status(200)
                 .with_header("content-type", "application/json")
                 .with_body(
@@ -3206,9 +6724,20 @@ mod tests {
                 .create_async()
                 .await;
 
-            let download_file_mock = server
+            let range_mock = server
                 .mock("GET", Matcher::Regex(r"^/test.jpg$".to_string()))
-                .match_header("Authorization", "Bearer api_key")
+                .match_header("Range", "bytes=6-")
+                .match_header("If-Range", "\"v1\"")
+                .with_status(206)
+                .with_header("content-type", "image/jpg")
+                .with_header("Content-Range", "bytes 0-12/13")
+                .with_body("image-content")
+                .create_async()
+                .await;
+
+            let full_mock = server
+                .mock("GET", Matcher::Regex(r"^/test.jpg$".to_string()))
+                .match_header("Range", Matcher::Missing)
                 .with_status(200)
                 .with_header("content-type", "image/jpg")
                 .with_body("image-content")
@@ -3218,14 +6747,19 @@ mod tests {
             let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
 
             let filename = format!("./{}.jpg", Uuid::new_v4());
+            fs::write(format!("{filename}.part"), "image-")?;
+            fs::write(format!("{filename}.part.validator"), "\"v1\"")?;
 
             client
-                .download_wallpaper(&filename, &download_wallpaper_request)
+                .download_wallpaper_resumable(&filename, &download_wallpaper_request)
                 .await
                 .unwrap();
 
+            assert_eq!(fs::read_to_string(&filename)?, "image-content");
+
             download_wallpaper_mock.assert_async().await;
-            download_file_mock.assert_async().await;
+            range_mock.assert_async().await;
+            full_mock.assert_async().await;
 
             fs::remove_file(&filename)?;
 
@@ -3233,7 +6767,7 @@ mod tests {
         }
 
         #[tokio::test]
-        async fn download_wallpaper_does_send_show_watermark_if_provided()
+        async fn download_wallpaper_resumable_completes_when_part_file_is_already_whole()
         -> Result<(), Box<dyn Error>> {
             let mut server = mockito::Server::new_async().await;
 
@@ -3241,7 +6775,6 @@ mod tests {
                 .width(2)
                 .height(3)
                 .wallpaper_id(4)
-                .show_watermark(true)
                 .build();
 
             let download_wallpaper_mock = server
@@ -3249,8 +6782,6 @@ mod tests {
                     "GET",
                     Matcher::Regex(r"^/v2/core/download/wallpaper.*$".to_string()),
                 )
-                .match_query(Matcher::Regex("show_watermark=true".to_string()))
-                .match_header("Authorization", "Bearer api_key")
                 .with_status(200)
                 .with_header("content-type", "application/json")
                 .with_body(
@@ -3264,22 +6795,27 @@ mod tests {
 
             let download_file_mock = server
                 .mock("GET", Matcher::Regex(r"^/test.jpg$".to_string()))
-                .match_header("Authorization", "Bearer api_key")
-                .with_status(200)
-                .with_header("content-type", "image/jpg")
-                .with_body("image-content")
+                .match_header("Range", "bytes=13-")
+                .match_header("If-Range", "\"v1\"")
+                .with_status(416)
+                .with_header("Content-Range", "bytes */13")
                 .create_async()
                 .await;
 
             let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
 
             let filename = format!("./{}.jpg", Uuid::new_v4());
+            fs::write(format!("{filename}.part"), "image-content")?;
+            fs::write(format!("{filename}.part.validator"), "\"v1\"")?;
 
             client
-                .download_wallpaper(&filename, &download_wallpaper_request)
+                .download_wallpaper_resumable(&filename, &download_wallpaper_request)
                 .await
                 .unwrap();
 
+            assert_eq!(fs::read_to_string(&filename)?, "image-content");
+            assert!(!fs::exists(format!("{filename}.part"))?);
+
             download_wallpaper_mock.assert_async().await;
             download_file_mock.assert_async().await;
 
@@ -3289,11 +6825,11 @@ mod tests {
         }
 
         #[tokio::test]
-        async fn download_wallpaper_sends_path_parameters() -> Result<(), Box<dyn Error>> {
+        async fn download_wallpaper_resumable_keeps_part_file_on_error()
+        -> Result<(), Box<dyn Error>> {
             let mut server = mockito::Server::new_async().await;
 
             let download_wallpaper_request = DownloadWallpaperRequest::builder()
-                .wallpaper_type(WallpaperType::Dual)
                 .width(2)
                 .height(3)
                 .wallpaper_id(4)
@@ -3302,15 +6838,8 @@ mod tests {
             let download_wallpaper_mock = server
                 .mock(
                     "GET",
-                    Matcher::Regex(format!(
-                        r"^/v2/core/download/wallpaper/{}/{}/{}/{}?.*$",
-                        download_wallpaper_request.wallpaper_type.as_str(),
-                        download_wallpaper_request.width,
-                        download_wallpaper_request.height,
-                        download_wallpaper_request.wallpaper_id
-                    )),
+                    Matcher::Regex(r"^/v2/core/download/wallpaper.*$".to_string()),
                 )
-                .match_header("Authorization", "Bearer api_key")
                 .with_status(200)
                 .with_header("content-type", "application/json")
                 .with_body(
@@ -3324,48 +6853,44 @@ mod tests {
 
             let download_file_mock = server
                 .mock("GET", Matcher::Regex(r"^/test.jpg$".to_string()))
-                .match_header("Authorization", "Bearer api_key")
-                .with_status(200)
-                .with_header("content-type", "image/jpg")
-                .with_body("image-content")
+                .with_status(404)
+                .with_header("content-type", "text/plain")
+                .with_body("Object Not Found")
                 .create_async()
                 .await;
 
             let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
 
             let filename = format!("./{}.jpg", Uuid::new_v4());
+            fs::write(format!("{filename}.part"), "image-")?;
+            fs::write(format!("{filename}.part.validator"), "\"v1\"")?;
 
-            client
-                .download_wallpaper(&filename, &download_wallpaper_request)
+            let error = client
+                .download_wallpaper_resumable(&filename, &download_wallpaper_request)
                 .await
-                .unwrap();
+                .unwrap_err();
+
+            assert!(matches!(error, ClientError::NotFound(_)));
+            assert!(!fs::exists(&filename)?);
+            assert_eq!(fs::read_to_string(format!("{filename}.part"))?, "image-");
 
             download_wallpaper_mock.assert_async().await;
             download_file_mock.assert_async().await;
 
-            fs::remove_file(&filename)?;
+            fs::remove_file(format!("{filename}.part"))?;
+            fs::remove_file(format!("{filename}.part.validator"))?;
 
             Ok(())
         }
 
-        #[tokio::test]
-        async fn download_wallpaper_can_map_successful_response_fully_populated()
-        -> Result<(), Box<dyn Error>> {
-            let mut server = mockito::Server::new_async().await;
-
-            let download_wallpaper_request = DownloadWallpaperRequest::builder()
-                .width(2)
-                .height(3)
-                .wallpaper_id(4)
-                .build();
-
-            let download_wallpaper_mock = server
+        async fn download_mock(
+            server: &mut mockito::ServerGuard,
+        ) -> Result<mockito::Mock, Box<dyn Error>> {
+            Ok(server
                 .mock(
                     "GET",
                     Matcher::Regex(r"^/v2/core/download/wallpaper.*$".to_string()),
                 )
-                .match_query(Matcher::Regex("show_watermark=true".to_string()))
-                .match_header("Authorization", "Bearer api_key")
                 .with_status(200)
                 .with_header("content-type", "application/json")
                 .with_body(
@@ -3375,11 +6900,26 @@ mod tests {
                     .replace("{{host}}", &server.url()),
                 )
                 .create_async()
-                .await;
+                .await)
+        }
+
+        #[tokio::test]
+        async fn download_wallpaper_resumable_restarts_without_validator()
+        -> Result<(), Box<dyn Error>> {
+            let mut server = mockito::Server::new_async().await;
+
+            let download_wallpaper_request = DownloadWallpaperRequest::builder()
+                .width(2)
+                .height(3)
+                .wallpaper_id(4)
+                .build();
+
+            let download_wallpaper_mock = download_mock(&mut server).await?;
 
             let download_file_mock = server
                 .mock("GET", Matcher::Regex(r"^/test.jpg$".to_string()))
-                .match_header("Authorization", "Bearer api_key")
+                .match_header("Range", Matcher::Missing)
+                .match_header("If-Range", Matcher::Missing)
                 .with_status(200)
                 .with_header("content-type", "image/jpg")
                 .with_body("image-content")
@@ -3389,9 +6929,10 @@ mod tests {
             let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
 
             let filename = format!("./{}.jpg", Uuid::new_v4());
+            fs::write(format!("{filename}.part"), "stale-")?;
 
             client
-                .download_wallpaper(&filename, &download_wallpaper_request)
+                .download_wallpaper_resumable(&filename, &download_wallpaper_request)
                 .await
                 .unwrap();
 
@@ -3406,7 +6947,7 @@ mod tests {
         }
 
         #[tokio::test]
-        async fn download_wallpaper_can_map_successful_response_minimal_populated()
+        async fn download_wallpaper_resumable_restarts_when_validator_changed()
         -> Result<(), Box<dyn Error>> {
             let mut server = mockito::Server::new_async().await;
 
@@ -3416,29 +6957,26 @@ mod tests {
                 .wallpaper_id(4)
                 .build();
 
-            let download_wallpaper_mock = server
-                .mock(
-                    "GET",
-                    Matcher::Regex(r"^/v2/core/download/wallpaper.*$".to_string()),
-                )
-                .match_query(Matcher::Regex("show_watermark=true".to_string()))
-                .match_header("Authorization", "Bearer api_key")
-                .with_status(200)
-                .with_header("content-type", "application/json")
-                .with_body(
-                    fs::read_to_string(
-                        "resources/download_wallpaper_success_fully_populated.json",
-                    )?
-                    .replace("{{host}}", &server.url()),
-                )
+            let download_wallpaper_mock = download_mock(&mut server).await?;
+
+            let range_mock = server
+                .mock("GET", Matcher::Regex(r"^/test.jpg$".to_string()))
+                .match_header("Range", "bytes=6-")
+                .match_header("If-Range", "\"v1\"")
+                .with_status(206)
+                .with_header("content-type", "image/jpg")
+                .with_header("Content-Range", "bytes 6-12/13")
+                .with_header("ETag", "\"v2\"")
+                .with_body("content")
                 .create_async()
                 .await;
 
-            let download_file_mock = server
+            let full_mock = server
                 .mock("GET", Matcher::Regex(r"^/test.jpg$".to_string()))
-                .match_header("Authorization", "Bearer api_key")
+                .match_header("Range", Matcher::Missing)
                 .with_status(200)
                 .with_header("content-type", "image/jpg")
+                .with_header("ETag", "\"v2\"")
                 .with_body("image-content")
                 .create_async()
                 .await;
@@ -3446,16 +6984,20 @@ mod tests {
             let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
 
             let filename = format!("./{}.jpg", Uuid::new_v4());
+            fs::write(format!("{filename}.part"), "stale-")?;
+            fs::write(format!("{filename}.part.validator"), "\"v1\"")?;
 
             client
-                .download_wallpaper(&filename, &download_wallpaper_request)
+                .download_wallpaper_resumable(&filename, &download_wallpaper_request)
                 .await
                 .unwrap();
 
             assert_eq!(fs::read_to_string(&filename)?, "image-content");
+            assert!(!fs::exists(format!("{filename}.part.validator"))?);
 
             download_wallpaper_mock.assert_async().await;
-            download_file_mock.assert_async().await;
+            range_mock.assert_async().await;
+            full_mock.assert_async().await;
 
             fs::remove_file(&filename)?;
 
@@ -3463,8 +7005,57 @@ mod tests {
         }
 
         #[tokio::test]
-        async fn download_wallpaper_can_map_unauthorised_response_when_getting_download_wallpaper_response()
+        async fn download_wallpaper_resumable_records_validator_for_interrupted_download()
         -> Result<(), Box<dyn Error>> {
+            let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await?;
+            let url = format!("http://{}/test.jpg", listener.local_addr()?);
+            let server = tokio::spawn(async move {
+                let (mut stream, _) = listener.accept().await?;
+                let mut request = [0; 1024];
+                let _ = tokio::io::AsyncReadExt::read(&mut stream, &mut request).await?;
+                stream
+                    .write_all(
+                        b"HTTP/1.1 200 OK\r\nContent-Length: 13\r\nETag: W/\"weak\"\r\n\
+                        Last-Modified: Tue, 14 Nov 2023 22:13:20 GMT\r\n\r\nimage-",
+                    )
+                    .await?;
+                stream.shutdown().await
+            });
+
+            let client = DigitalBlasphemyClient::builder("api_key".to_string()).build()?;
+            let download_wallpaper_request = DownloadWallpaperRequest::builder()
+                .width(2)
+                .height(3)
+                .wallpaper_id(4)
+                .build();
+            let download = Download {
+                expiration: i64::MAX,
+                url,
+            };
+
+            let filename = format!("./{}.jpg", Uuid::new_v4());
+
+            let result = client
+                .resume_download(&filename, &download_wallpaper_request, &download)
+                .await;
+
+            assert!(result.is_err());
+            assert_eq!(fs::read_to_string(format!("{filename}.part"))?, "image-");
+            assert_eq!(
+                fs::read_to_string(format!("{filename}.part.validator"))?,
+                "Tue, 14 Nov 2023 22:13:20 GMT"
+            );
+
+            server.await??;
+
+            fs::remove_file(format!("{filename}.part"))?;
+            fs::remove_file(format!("{filename}.part.validator"))?;
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn resume_download_uses_unexpired_download_url() -> Result<(), Box<dyn Error>> {
             let mut server = mockito::Server::new_async().await;
 
             let download_wallpaper_request = DownloadWallpaperRequest::builder()
@@ -3478,35 +7069,49 @@ mod tests {
                     "GET",
                     Matcher::Regex(r"^/v2/core/download/wallpaper.*$".to_string()),
                 )
-                .match_header("Authorization", "Bearer api_key")
-                .with_status(401)
-                .with_header("content-type", "application/json")
-                .with_body(fs::read_to_string("resources/unauthorised_response.json")?)
+                .expect(0)
+                .create_async()
+                .await;
+
+            let download_file_mock = server
+                .mock("GET", Matcher::Regex(r"^/test.jpg$".to_string()))
+                .match_header("Range", "bytes=6-")
+                .match_header("If-Range", "\"v1\"")
+                .with_status(206)
+                .with_header("content-type", "image/jpg")
+                .with_header("Content-Range", "bytes 6-12/13")
+                .with_body("content")
                 .create_async()
                 .await;
 
             let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
 
             let filename = format!("./{}.jpg", Uuid::new_v4());
+            fs::write(format!("{filename}.part"), "image-")?;
+            fs::write(format!("{filename}.part.validator"), "\"v1\"")?;
 
-            let error = client
-                .download_wallpaper(&filename, &download_wallpaper_request)
-                .await
-                .unwrap_err();
+            let download = Download {
+                expiration: i64::MAX,
+                url: format!("{}/test.jpg", server.url()),
+            };
 
-            assert_eq!(error.code, 401);
-            assert_eq!(error.description, "Unauthorized".to_string());
+            client
+                .resume_download(&filename, &download_wallpaper_request, &download)
+                .await
+                .unwrap();
 
-            assert!(!fs::exists(&filename)?);
+            assert_eq!(fs::read_to_string(&filename)?, "image-content");
 
             download_wallpaper_mock.assert_async().await;
+            download_file_mock.assert_async().await;
+
+            fs::remove_file(&filename)?;
 
             Ok(())
         }
 
         #[tokio::test]
-        async fn download_wallpaper_can_map_unauthorised_response_when_downloading_file()
-        -> Result<(), Box<dyn Error>> {
+        async fn resume_download_refreshes_expired_download_url() -> Result<(), Box<dyn Error>> {
             let mut server = mockito::Server::new_async().await;
 
             let download_wallpaper_request = DownloadWallpaperRequest::builder()
@@ -3520,8 +7125,6 @@ mod tests {
                     "GET",
                     Matcher::Regex(r"^/v2/core/download/wallpaper.*$".to_string()),
                 )
-                .match_query(Matcher::Regex("show_watermark=true".to_string()))
-                .match_header("Authorization", "Bearer api_key")
                 .with_status(200)
                 .with_header("content-type", "application/json")
                 .with_body(
@@ -3533,39 +7136,59 @@ mod tests {
                 .create_async()
                 .await;
 
+            let expired_file_mock = server
+                .mock("GET", Matcher::Regex(r"^/expired.jpg$".to_string()))
+                .expect(0)
+                .create_async()
+                .await;
+
             let download_file_mock = server
                 .mock("GET", Matcher::Regex(r"^/test.jpg$".to_string()))
-                .match_header("Authorization", "Bearer api_key")
-                .with_status(401)
-                .with_header("content-type", "application/json")
-                .with_body(fs::read_to_string("resources/unauthorised_response.json")?)
+                .match_header("Range", "bytes=6-")
+                .match_header("If-Range", "\"v1\"")
+                .with_status(206)
+                .with_header("content-type", "image/jpg")
+                .with_header("Content-Range", "bytes 6-12/13")
+                .with_body("content")
                 .create_async()
                 .await;
 
             let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
 
             let filename = format!("./{}.jpg", Uuid::new_v4());
+            fs::write(format!("{filename}.part"), "image-")?;
+            fs::write(format!("{filename}.part.validator"), "\"v1\"")?;
 
-            let error = client
-                .download_wallpaper(&filename, &download_wallpaper_request)
-                .await
-                .unwrap_err();
+            let download = Download {
+                expiration: 1,
+                url: format!("{}/expired.jpg", server.url()),
+            };
 
-            assert_eq!(error.code, 401);
-            assert_eq!(error.description, "Unauthorized".to_string());
+            client
+                .resume_download(&filename, &download_wallpaper_request, &download)
+                .await
+                .unwrap();
 
-            assert!(!fs::exists(&filename)?);
+            assert_eq!(fs::read_to_string(&filename)?, "image-content");
 
             download_wallpaper_mock.assert_async().await;
+            expired_file_mock.assert_async().await;
             download_file_mock.assert_async().await;
 
+            fs::remove_file(&filename)?;
+
             Ok(())
         }
+    }
+
+    mod credentials {
+        use super::*;
 
         #[tokio::test]
-        async fn download_wallpaper_can_map_bad_request_response_when_getting_download_wallpaper_response()
+        async fn download_wallpaper_does_not_send_credentials_to_download_host()
         -> Result<(), Box<dyn Error>> {
             let mut server = mockito::Server::new_async().await;
+            let mut cdn_server = mockito::Server::new_async().await;
 
             let download_wallpaper_request = DownloadWallpaperRequest::builder()
                 .width(2)
@@ -3579,44 +7202,48 @@ mod tests {
                     Matcher::Regex(r"^/v2/core/download/wallpaper.*$".to_string()),
                 )
                 .match_header("Authorization", "Bearer api_key")
-                .with_status(400)
+                .with_status(200)
                 .with_header("content-type", "application/json")
-                .with_body(fs::read_to_string(
-                    "resources/download_wallpaper_bad_request.json",
-                )?)
+                .with_body(
+                    fs::read_to_string(
+                        "resources/download_wallpaper_success_fully_populated.json",
+                    )?
+                    .replace("{{host}}", &cdn_server.url()),
+                )
+                .create_async()
+                .await;
+
+            let download_file_mock = cdn_server
+                .mock("GET", "/test.jpg")
+                .match_header("Authorization", Matcher::Missing)
+                .with_status(200)
+                .with_header("content-type", "image/jpg")
+                .with_body("image-content")
                 .create_async()
                 .await;
 
             let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
 
-            let filename = format!("./{}.jpg", Uuid::new_v4());
+            let mut image_content: Vec<u8> = vec![];
 
-            let error = client
-                .download_wallpaper(&filename, &download_wallpaper_request)
+            client
+                .download_wallpaper_to(&mut image_content, &download_wallpaper_request)
                 .await
-                .unwrap_err();
-
-            assert_eq!(error.code, 400);
-            assert_eq!(error.description, "Bad Request".to_string());
-            assert_eq!(
-                error.errors.unwrap(),
-                vec![
-                    "\"type\" must be one of [single, dual, triple, mobile]",
-                    "\"width\" must be a number"
-                ]
-            );
+                .unwrap();
 
-            assert!(!fs::exists(&filename)?);
+            assert_eq!(image_content, b"image-content");
 
             download_wallpaper_mock.assert_async().await;
+            download_file_mock.assert_async().await;
 
             Ok(())
         }
 
         #[tokio::test]
-        async fn download_wallpaper_can_map_not_found_response_when_downloading_file()
+        async fn download_wallpaper_does_not_send_credentials_after_cross_origin_redirect()
         -> Result<(), Box<dyn Error>> {
             let mut server = mockito::Server::new_async().await;
+            let mut cdn_server = mockito::Server::new_async().await;
 
             let download_wallpaper_request = DownloadWallpaperRequest::builder()
                 .width(2)
@@ -3629,7 +7256,6 @@ mod tests {
                     "GET",
                     Matcher::Regex(r"^/v2/core/download/wallpaper.*$".to_string()),
                 )
-                .match_query(Matcher::Regex("show_watermark=true".to_string()))
                 .match_header("Authorization", "Bearer api_key")
                 .with_status(200)
                 .with_header("content-type", "application/json")
@@ -3642,40 +7268,46 @@ mod tests {
                 .create_async()
                 .await;
 
-            let download_file_mock = server
-                .mock("GET", Matcher::Regex(r"^/test.jpg$".to_string()))
+            let redirect_mock = server
+                .mock("GET", "/test.jpg")
                 .match_header("Authorization", "Bearer api_key")
-                .with_status(404)
-                .with_header("content-type", "text/plain")
-                .with_body("Object Not Found")
+                .with_status(302)
+                .with_header("Location", &format!("{}/test.jpg", cdn_server.url()))
+                .create_async()
+                .await;
+
+            let download_file_mock = cdn_server
+                .mock("GET", "/test.jpg")
+                .match_header("Authorization", Matcher::Missing)
+                .with_status(200)
+                .with_header("content-type", "image/jpg")
+                .with_body("image-content")
                 .create_async()
                 .await;
 
             let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
 
-            let filename = format!("./{}.jpg", Uuid::new_v4());
+            let mut image_content: Vec<u8> = vec![];
 
-            let error = client
-                .download_wallpaper(&filename, &download_wallpaper_request)
+            client
+                .download_wallpaper_to(&mut image_content, &download_wallpaper_request)
                 .await
-                .unwrap_err();
-
-            assert_eq!(error.code, 404);
-            assert_eq!(error.description, "Not Found".to_string());
-            assert_eq!(error.errors.unwrap(), vec!["Object Not Found"]);
+                .unwrap();
 
-            assert!(!fs::exists(&filename)?);
+            assert_eq!(image_content, b"image-content");
 
             download_wallpaper_mock.assert_async().await;
+            redirect_mock.assert_async().await;
             download_file_mock.assert_async().await;
 
             Ok(())
         }
 
         #[tokio::test]
-        #[should_panic(expected = "Unable to parse the body as JSON ErrorResponse")]
-        async fn download_wallpaper_can_map_unknown_error_response_when_downloading_file() {
+        async fn download_wallpaper_does_not_follow_redirect_to_unknown_host()
+        -> Result<(), Box<dyn Error>> {
             let mut server = mockito::Server::new_async().await;
+            let mut other_server = mockito::Server::new_async().await;
 
             let download_wallpaper_request = DownloadWallpaperRequest::builder()
                 .width(2)
@@ -3683,38 +7315,62 @@ mod tests {
                 .wallpaper_id(4)
                 .build();
 
-            server
+            let download_wallpaper_mock = server
                 .mock(
                     "GET",
                     Matcher::Regex(r"^/v2/core/download/wallpaper.*$".to_string()),
                 )
-                .match_query(Matcher::Regex("show_watermark=true".to_string()))
                 .match_header("Authorization", "Bearer api_key")
                 .with_status(200)
                 .with_header("content-type", "application/json")
                 .with_body(
-                    fs::read_to_string("resources/download_wallpaper_success_fully_populated.json")
-                        .unwrap()
-                        .replace("{{host}}", &server.url()),
+                    fs::read_to_string(
+                        "resources/download_wallpaper_success_fully_populated.json",
+                    )?
+                    .replace("{{host}}", &server.url()),
                 )
                 .create_async()
                 .await;
 
-            server
-                .mock("GET", Matcher::Regex(r"^/test.jpg$".to_string()))
-                .match_header("Authorization", "Bearer api_key")
-                .with_status(405)
+            let redirect_mock = server
+                .mock("GET", "/test.jpg")
+                .with_status(302)
+                .with_header(
+                    "Location",
+                    &format!(
+                        "http://localhost:{}/test.jpg",
+                        other_server.socket_address().port()
+                    ),
+                )
                 .create_async()
                 .await;
 
-            let client =
-                DigitalBlasphemyClient::new_test("api_key".to_string(), server.url()).unwrap();
+            let other_mock = other_server
+                .mock("GET", "/test.jpg")
+                .expect(0)
+                .create_async()
+                .await;
 
-            let filename = format!("./{}.jpg", Uuid::new_v4());
+            let client = DigitalBlasphemyClient::new_test("api_key".to_string(), server.url())?;
 
-            let _ = client
-                .download_wallpaper(&filename, &download_wallpaper_request)
-                .await;
+            let mut image_content: Vec<u8> = vec![];
+
+            let ClientError::Api(error) = client
+                .download_wallpaper_to(&mut image_content, &download_wallpaper_request)
+                .await
+                .unwrap_err()
+            else {
+                panic!("Expected an API error");
+            };
+
+            assert_eq!(error.code, 302);
+            assert!(image_content.is_empty());
+
+            download_wallpaper_mock.assert_async().await;
+            redirect_mock.assert_async().await;
+            other_mock.assert_async().await;
+
+            Ok(())
         }
     }
 }
@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use url::{ParseError, Url};
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct Endpoints {
@@ -7,3 +8,74 @@ pub struct Endpoints {
     pub thumb: String,
     pub web: String,
 }
+
+impl Endpoints {
+    /// Resolves `path` against `endpoint`, keeping any path the endpoint already has and
+    /// percent-encoding characters that are not valid in a URL.
+    pub(crate) fn resolve(endpoint: &str, path: &str) -> Result<Url, ParseError> {
+        let mut base = Url::parse(endpoint)?;
+        if !base.path().ends_with('/') {
+            let base_path = format!("{}/", base.path());
+            base.set_path(&base_path);
+        }
+        base.join(path.trim_start_matches('/'))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod endpoints_test {
+        use super::*;
+
+        #[test]
+        fn test_endpoints_resolve_joins_with_single_slash() {
+            let url = Endpoints::resolve("https://cdn.digitalblasphemy.com/", "/thumbnail/a.jpg");
+
+            assert_eq!(
+                url.unwrap().as_str(),
+                "https://cdn.digitalblasphemy.com/thumbnail/a.jpg"
+            );
+        }
+
+        #[test]
+        fn test_endpoints_resolve_keeps_endpoint_path() {
+            let url = Endpoints::resolve("https://api.digitalblasphemy.com/v2/core", "wallpaper/1");
+
+            assert_eq!(
+                url.unwrap().as_str(),
+                "https://api.digitalblasphemy.com/v2/core/wallpaper/1"
+            );
+        }
+
+        #[test]
+        fn test_endpoints_resolve_percent_encodes_path() {
+            let url = Endpoints::resolve("https://cdn.digitalblasphemy.com", "/thumbnail/a b.jpg");
+
+            assert_eq!(
+                url.unwrap().as_str(),
+                "https://cdn.digitalblasphemy.com/thumbnail/a%20b.jpg"
+            );
+        }
+
+        #[test]
+        fn test_endpoints_resolve_does_not_double_encode_path() {
+            let url =
+                Endpoints::resolve("https://cdn.digitalblasphemy.com", "/thumbnail/a%20b.jpg");
+
+            assert_eq!(
+                url.unwrap().as_str(),
+                "https://cdn.digitalblasphemy.com/thumbnail/a%20b.jpg"
+            );
+        }
+
+        #[test]
+        fn test_endpoints_resolve_rejects_invalid_endpoint() {
+            assert_eq!(
+                Endpoints::resolve("not a url", "/thumbnail/a.jpg"),
+                Err(ParseError::RelativeUrlWithoutBase)
+            );
+        }
+    }
+}
@@ -1,6 +1,7 @@
-use core::panic;
-
-use crate::{model::WallpaperType, model::common_response::Endpoints};
+use crate::{
+    model::common_response::Endpoints,
+    model::{FieldError, ValidationError, WallpaperId, WallpaperType},
+};
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -25,9 +26,9 @@ pub struct DownloadWallpaperDBCoreRequest {
 pub struct DownloadWallpaperResponseParams {
     #[serde(rename = "type")]
     pub wallpaper_type: WallpaperType,
-    pub width: u16,
-    pub height: u16,
-    pub wallpaper_id: u16,
+    pub width: u32,
+    pub height: u32,
+    pub wallpaper_id: WallpaperId,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -40,9 +41,9 @@ pub struct Download {
 pub struct DownloadWallpaperRequest {
     #[serde(rename = "type")]
     pub wallpaper_type: WallpaperType,
-    pub width: u16,
-    pub height: u16,
-    pub wallpaper_id: u16,
+    pub width: u32,
+    pub height: u32,
+    pub wallpaper_id: WallpaperId,
     pub show_watermark: bool,
 }
 
@@ -50,6 +51,17 @@ impl DownloadWallpaperRequest {
     pub fn builder() -> DownloadWallpaperRequestBuilder {
         DownloadWallpaperRequestBuilder::new()
     }
+
+    /// Checks every field and reports all that are invalid.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        let errors = [
+            (self.width == 0).then(|| FieldError::new("width", "Width must be provided.")),
+            (self.height == 0).then(|| FieldError::new("height", "Height must be provided.")),
+            (self.wallpaper_id == 0)
+                .then(|| FieldError::new("wallpaper_id", "Wallpaper ID must be provided.")),
+        ];
+        ValidationError::from_errors(errors.into_iter().flatten().collect())
+    }
 }
 
 pub struct DownloadWallpaperRequestBuilder {
@@ -63,23 +75,19 @@ impl DownloadWallpaperRequestBuilder {
                 wallpaper_type: WallpaperType::Single,
                 width: 0,
                 height: 0,
-                wallpaper_id: 0,
+                wallpaper_id: WallpaperId(0),
                 show_watermark: true,
             },
         }
     }
 
     pub fn build(self) -> DownloadWallpaperRequest {
-        if self.download_wallpaper_request.width == 0 {
-            panic!("Width must be provided.")
-        }
-        if self.download_wallpaper_request.height == 0 {
-            panic!("Height must be provided.")
-        }
-        if self.download_wallpaper_request.wallpaper_id == 0 {
-            panic!("Wallpaper ID must be provided.")
-        }
-        self.download_wallpaper_request
+        self.try_build().unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    pub fn try_build(self) -> Result<DownloadWallpaperRequest, ValidationError> {
+        self.download_wallpaper_request.validate()?;
+        Ok(self.download_wallpaper_request)
     }
 
     pub fn wallpaper_type(mut self, wallpaper_type: WallpaperType) -> Self {
@@ -87,18 +95,18 @@ impl DownloadWallpaperRequestBuilder {
         self
     }
 
-    pub fn width(mut self, width: u16) -> Self {
+    pub fn width(mut self, width: u32) -> Self {
         self.download_wallpaper_request.width = width;
         self
     }
 
-    pub fn height(mut self, height: u16) -> Self {
+    pub fn height(mut self, height: u32) -> Self {
         self.download_wallpaper_request.height = height;
         self
     }
 
-    pub fn wallpaper_id(mut self, wallpaper_id: u16) -> Self {
-        self.download_wallpaper_request.wallpaper_id = wallpaper_id;
+    pub fn wallpaper_id(mut self, wallpaper_id: impl Into<WallpaperId>) -> Self {
+        self.download_wallpaper_request.wallpaper_id = wallpaper_id.into();
         self
     }
 
@@ -180,5 +188,29 @@ mod tests {
             assert_eq!(download_wallpaper_request.wallpaper_id, 6);
             assert!(!download_wallpaper_request.show_watermark);
         }
+
+        #[test]
+        fn test_download_wallpaper_request_builder_try_build_lists_every_missing_field() {
+            let error = DownloadWallpaperRequest::builder().try_build().unwrap_err();
+
+            let fields: Vec<&str> = error.errors.iter().map(|error| error.field).collect();
+            assert_eq!(fields, vec!["width", "height", "wallpaper_id"]);
+            assert_eq!(
+                error.to_string(),
+                "Width must be provided. Height must be provided. Wallpaper ID must be provided."
+            );
+        }
+
+        #[test]
+        fn test_download_wallpaper_request_builder_try_build_returns_valid_request() {
+            let download_wallpaper_request = DownloadWallpaperRequest::builder()
+                .width(1)
+                .height(2)
+                .wallpaper_id(3)
+                .try_build()
+                .unwrap();
+
+            assert_eq!(download_wallpaper_request.wallpaper_id, 3);
+        }
     }
 }
@@ -28,3 +28,168 @@ impl fmt::Display for ErrorResponse {
 }
 
 impl Error for ErrorResponse {}
+
+#[derive(Debug)]
+pub enum ClientError {
+    /// The request could not be sent or the response body could not be read.
+    Transport(reqwest::Error),
+    /// The request did not complete within the configured timeout.
+    Timeout(reqwest::Error),
+    /// The API rejected the credentials (HTTP 401).
+    Unauthorized(ErrorResponse),
+    /// The requested resource does not exist (HTTP 404).
+    NotFound(ErrorResponse),
+    /// The API is throttling requests (HTTP 429).
+    RateLimited(ErrorResponse),
+    /// The API rejected the request parameters (HTTP 400).
+    BadRequest(ErrorResponse),
+    /// Any other non-success response.
+    Api(ErrorResponse),
+    /// A response body could not be deserialised; `body` holds the raw text.
+    Decode {
+        body: String,
+        source: serde_json::Error,
+    },
+    /// Writing the downloaded file failed.
+    Io(std::io::Error),
+    /// A URL could not be built from the API's endpoints and paths.
+    Url(url::ParseError),
+    /// The API key contains characters that cannot be sent in an HTTP header.
+    InvalidApiKey,
+    /// The local catalog index could not be read or written.
+    Index(Box<dyn Error + Send + Sync>),
+}
+
+impl ClientError {
+    pub fn error_response(&self) -> Option<&ErrorResponse> {
+        match self {
+            ClientError::Unauthorized(error_response)
+            | ClientError::NotFound(error_response)
+            | ClientError::RateLimited(error_response)
+            | ClientError::BadRequest(error_response)
+            | ClientError::Api(error_response) => Some(error_response),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ClientError::Transport(error) => write!(f, "Transport error: {error}"),
+            ClientError::Timeout(error) => write!(f, "Request timed out: {error}"),
+            ClientError::Unauthorized(error_response) => {
+                write!(f, "Unauthorized: {error_response}")
+            }
+            ClientError::NotFound(error_response) => write!(f, "Not found: {error_response}"),
+            ClientError::RateLimited(error_response) => {
+                write!(f, "Rate limited: {error_response}")
+            }
+            ClientError::BadRequest(error_response) => {
+                write!(f, "Bad request: {error_response}")
+            }
+            ClientError::Api(error_response) => write!(f, "API error: {error_response}"),
+            ClientError::Decode { body, source } => {
+                write!(f, "Unable to decode response body '{body}': {source}")
+            }
+            ClientError::Io(error) => write!(f, "IO error: {error}"),
+            ClientError::Url(error) => write!(f, "Invalid URL: {error}"),
+            ClientError::InvalidApiKey => {
+                write!(f, "API key contains characters not allowed in a header")
+            }
+            ClientError::Index(error) => write!(f, "Index error: {error}"),
+        }
+    }
+}
+
+impl Error for ClientError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ClientError::Transport(error) | ClientError::Timeout(error) => Some(error),
+            ClientError::Unauthorized(error_response)
+            | ClientError::NotFound(error_response)
+            | ClientError::RateLimited(error_response)
+            | ClientError::BadRequest(error_response)
+            | ClientError::Api(error_response) => Some(error_response),
+            ClientError::Decode { source, .. } => Some(source),
+            ClientError::Io(error) => Some(error),
+            ClientError::Url(error) => Some(error),
+            ClientError::InvalidApiKey => None,
+            ClientError::Index(error) => Some(error.as_ref()),
+        }
+    }
+}
+
+impl From<reqwest::Error> for ClientError {
+    fn from(error: reqwest::Error) -> Self {
+        if error.is_timeout() {
+            ClientError::Timeout(error)
+        } else {
+            ClientError::Transport(error)
+        }
+    }
+}
+
+impl From<std::io::Error> for ClientError {
+    fn from(error: std::io::Error) -> Self {
+        ClientError::Io(error)
+    }
+}
+
+impl From<url::ParseError> for ClientError {
+    fn from(error: url::ParseError) -> Self {
+        ClientError::Url(error)
+    }
+}
+
+#[cfg(feature = "index")]
+impl From<rusqlite::Error> for ClientError {
+    fn from(error: rusqlite::Error) -> Self {
+        ClientError::Index(Box::new(error))
+    }
+}
+
+/// A request could not be built. Lists every invalid field, not just the first one found.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationError {
+    pub errors: Vec<FieldError>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldError {
+    pub field: &'static str,
+    pub message: &'static str,
+}
+
+impl ValidationError {
+    pub(crate) fn from_errors(errors: Vec<FieldError>) -> Result<(), ValidationError> {
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(ValidationError { errors })
+        }
+    }
+}
+
+impl FieldError {
+    pub(crate) fn new(field: &'static str, message: &'static str) -> Self {
+        FieldError { field, message }
+    }
+}
+
+impl From<FieldError> for ValidationError {
+    fn from(error: FieldError) -> Self {
+        ValidationError {
+            errors: vec![error],
+        }
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let messages: Vec<&str> = self.errors.iter().map(|error| error.message).collect();
+        f.write_str(&messages.join(" "))
+    }
+}
+
+impl Error for ValidationError {}
@@ -1,4 +1,8 @@
-use crate::{model::Operator, model::common_response::Endpoints, model::wallpaper::Wallpaper};
+use crate::{
+    model::common_response::Endpoints,
+    model::wallpaper::Wallpaper,
+    model::{FieldError, Operator, ValidationError, WallpaperId},
+};
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -22,7 +26,7 @@ pub struct GetWallpaperDBCoreRequest {
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct GetWallpaperResponseParams {
-    pub wallpaper_id: i64,
+    pub wallpaper_id: WallpaperId,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -39,7 +43,7 @@ pub struct GetWallpaperResponseQuery {
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct GetWallpaperRequest {
-    pub wallpaper_id: u16,
+    pub wallpaper_id: WallpaperId,
     pub filter_res_height: i64,
     pub filter_res_operator: Operator,
     pub filter_res_operator_height: Operator,
@@ -54,6 +58,13 @@ impl GetWallpaperRequest {
     pub fn builder() -> GetWallpaperRequestBuilder {
         GetWallpaperRequestBuilder::new()
     }
+
+    /// Checks every field and reports all that are invalid.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        let errors = [(self.wallpaper_id == 0)
+            .then(|| FieldError::new("wallpaper_id", "Wallpaper ID must be provided."))];
+        ValidationError::from_errors(errors.into_iter().flatten().collect())
+    }
 }
 
 #[derive(Clone)]
@@ -65,7 +76,7 @@ impl GetWallpaperRequestBuilder {
     pub(crate) fn new() -> Self {
         GetWallpaperRequestBuilder {
             get_wallpaper_request: GetWallpaperRequest {
-                wallpaper_id: 0,
+                wallpaper_id: WallpaperId(0),
                 filter_res_height: 0,
                 filter_res_operator: Operator::GreaterThanOrEqual,
                 filter_res_operator_height: Operator::GreaterThanOrEqual,
@@ -79,14 +90,19 @@ impl GetWallpaperRequestBuilder {
     }
 
     pub fn build(self) -> GetWallpaperRequest {
-        if self.get_wallpaper_request.wallpaper_id == 0 {
-            panic!("Wallpaper ID must be provided.");
-        }
-        self.get_wallpaper_request
+        self.try_build().unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    pub fn try_build(self) -> Result<GetWallpaperRequest, ValidationError> {
+        self.get_wallpaper_request.validate()?;
+        Ok(self.get_wallpaper_request)
     }
 
-    pub fn wallpaper_id(mut self, wallpaper_id: u16) -> GetWallpaperRequestBuilder {
-        self.get_wallpaper_request.wallpaper_id = wallpaper_id;
+    pub fn wallpaper_id(
+        mut self,
+        wallpaper_id: impl Into<WallpaperId>,
+    ) -> GetWallpaperRequestBuilder {
+        self.get_wallpaper_request.wallpaper_id = wallpaper_id.into();
         self
     }
 
@@ -210,5 +226,28 @@ mod tests {
             assert!(get_wallpaper_request.show_pickle_jar);
             assert!(!get_wallpaper_request.show_resolutions);
         }
+
+        #[test]
+        fn test_get_wallpaper_request_builder_try_build_rejects_missing_wallpaper_id() {
+            let error = GetWallpaperRequest::builder().try_build().unwrap_err();
+
+            assert_eq!(
+                error.errors,
+                vec![FieldError::new(
+                    "wallpaper_id",
+                    "Wallpaper ID must be provided."
+                )]
+            );
+        }
+
+        #[test]
+        fn test_get_wallpaper_request_builder_try_build_returns_valid_request() {
+            let get_wallpaper_request = GetWallpaperRequest::builder()
+                .wallpaper_id(1)
+                .try_build()
+                .unwrap();
+
+            assert_eq!(get_wallpaper_request.wallpaper_id, 1);
+        }
     }
 }
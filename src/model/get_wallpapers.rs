@@ -1,14 +1,47 @@
 use crate::{
     model::common_response::Endpoints,
     model::wallpaper::Wallpaper,
-    model::{Operator, Order},
+    model::{FieldError, GalleryId, Operator, Order, TagId, ValidationError, WallpaperId},
 };
+use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
 
 #[derive(Serialize, Deserialize, Debug, PartialEq)]
 pub struct GetWallpapersResponse {
     pub db_core: GetWallpapersDBCore,
+    /// The IDs of `db_core.wallpapers` in the order the API returned them.
+    #[serde(rename = "wallpapers", default)]
+    pub wallpaper_ids: Vec<WallpaperId>,
+}
+
+impl GetWallpapersResponse {
+    /// The wallpapers in the order the API returned them, following `wallpaper_ids` and then any
+    /// wallpapers it does not list.
+    pub fn wallpapers(&self) -> Vec<&Wallpaper> {
+        let mut wallpapers: Vec<&Wallpaper> = self
+            .wallpaper_ids
+            .iter()
+            .filter_map(|id| self.db_core.wallpapers.get(&id.to_string()))
+            .collect();
+        wallpapers.extend(
+            self.db_core
+                .wallpapers
+                .values()
+                .filter(|wallpaper| !self.wallpaper_ids.contains(&wallpaper.id)),
+        );
+        wallpapers
+    }
+
+    pub fn into_wallpapers(self) -> Vec<Wallpaper> {
+        let mut remaining = self.db_core.wallpapers;
+        let mut wallpapers: Vec<Wallpaper> = self
+            .wallpaper_ids
+            .iter()
+            .filter_map(|id| remaining.shift_remove(&id.to_string()))
+            .collect();
+        wallpapers.extend(remaining.into_values());
+        wallpapers
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, PartialEq)]
@@ -17,7 +50,7 @@ pub struct GetWallpapersDBCore {
     pub endpoints: Endpoints,
     pub request: GetWallpapersDBCoreRequest,
     pub total_pages: u16,
-    pub wallpapers: HashMap<String, Wallpaper>,
+    pub wallpapers: IndexMap<String, Wallpaper>,
 }
 
 #[derive(Serialize, Deserialize, Debug, PartialEq)]
@@ -31,7 +64,7 @@ pub struct GetWallpapersResponseQuery {
     pub filter_date_month: Option<u8>,
     pub filter_date_year: Option<u16>,
     pub filter_date_operator: Operator,
-    pub filter_gallery: Option<Vec<u16>>,
+    pub filter_gallery: Option<Vec<GalleryId>>,
     pub filter_rating: Option<f32>,
     pub filter_rating_operator: Option<Operator>,
     pub filter_res_operator_height: Option<Operator>,
@@ -39,7 +72,7 @@ pub struct GetWallpapersResponseQuery {
     pub filter_res_height: i64,
     pub filter_res_operator: Operator,
     pub filter_res_width: i64,
-    pub filter_tag: Option<Vec<u16>>,
+    pub filter_tag: Option<Vec<TagId>>,
     pub limit: u16,
     pub order: Order,
     pub order_by: GetWallpapersOrderBy,
@@ -72,7 +105,7 @@ pub struct GetWallpapersRequest {
     pub filter_date_month: u8,
     pub filter_date_year: u16,
     pub filter_date_operator: Operator,
-    pub filter_gallery: Vec<u16>,
+    pub filter_gallery: Vec<GalleryId>,
     pub filter_rating: f32,
     pub filter_rating_operator: Operator,
     pub filter_res_height: i64,
@@ -80,7 +113,7 @@ pub struct GetWallpapersRequest {
     pub filter_res_operator_height: Operator,
     pub filter_res_operator_width: Operator,
     pub filter_res_width: i64,
-    pub filter_tag: Vec<u16>,
+    pub filter_tag: Vec<TagId>,
     pub limit: u16,
     pub order: Order,
     pub order_by: GetWallpapersOrderBy,
@@ -95,6 +128,28 @@ impl GetWallpapersRequest {
     pub fn builder() -> GetWallpapersRequestBuilder {
         GetWallpapersRequestBuilder::new()
     }
+
+    /// Checks every field and reports all that are invalid, e.g. for a request deserialized from
+    /// user input rather than built. Unset (zero) date and rating filters are valid.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        let errors = [
+            (self.filter_date_day != 0)
+                .then(|| filter_date_day_error(self.filter_date_day))
+                .flatten(),
+            (self.filter_date_month != 0)
+                .then(|| filter_date_month_error(self.filter_date_month))
+                .flatten(),
+            (self.filter_date_year != 0)
+                .then(|| filter_date_year_error(self.filter_date_year))
+                .flatten(),
+            (self.filter_rating != 0_f32)
+                .then(|| filter_rating_error(self.filter_rating))
+                .flatten(),
+            limit_error(self.limit),
+            page_error(self.page),
+        ];
+        ValidationError::from_errors(errors.into_iter().flatten().collect())
+    }
 }
 
 #[derive(Clone)]
@@ -135,28 +190,57 @@ impl GetWallpapersRequestBuilder {
         self.get_wallpapers_request
     }
 
-    pub fn filter_date_day(mut self, filter_date_day: u8) -> GetWallpapersRequestBuilder {
-        if !(1..=31).contains(&filter_date_day) {
-            panic!("Filter date day must be between 1 and 31.");
+    pub fn try_build(self) -> Result<GetWallpapersRequest, ValidationError> {
+        self.get_wallpapers_request.validate()?;
+        Ok(self.get_wallpapers_request)
+    }
+
+    pub fn filter_date_day(self, filter_date_day: u8) -> GetWallpapersRequestBuilder {
+        self.try_filter_date_day(filter_date_day)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    pub fn try_filter_date_day(
+        mut self,
+        filter_date_day: u8,
+    ) -> Result<GetWallpapersRequestBuilder, ValidationError> {
+        if let Some(error) = filter_date_day_error(filter_date_day) {
+            return Err(error.into());
         }
         self.get_wallpapers_request.filter_date_day = filter_date_day;
-        self
+        Ok(self)
     }
 
-    pub fn filter_date_month(mut self, filter_date_month: u8) -> GetWallpapersRequestBuilder {
-        if !(1..=12).contains(&filter_date_month) {
-            panic!("Filter date month must be between 1 and 12.");
+    pub fn filter_date_month(self, filter_date_month: u8) -> GetWallpapersRequestBuilder {
+        self.try_filter_date_month(filter_date_month)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    pub fn try_filter_date_month(
+        mut self,
+        filter_date_month: u8,
+    ) -> Result<GetWallpapersRequestBuilder, ValidationError> {
+        if let Some(error) = filter_date_month_error(filter_date_month) {
+            return Err(error.into());
         }
         self.get_wallpapers_request.filter_date_month = filter_date_month;
-        self
+        Ok(self)
+    }
+
+    pub fn filter_date_year(self, filter_date_year: u16) -> GetWallpapersRequestBuilder {
+        self.try_filter_date_year(filter_date_year)
+            .unwrap_or_else(|error| panic!("{error}"))
     }
 
-    pub fn filter_date_year(mut self, filter_date_year: u16) -> GetWallpapersRequestBuilder {
-        if filter_date_year < 1997 {
-            panic!("Filter date year must be from 1997 inclusive.");
+    pub fn try_filter_date_year(
+        mut self,
+        filter_date_year: u16,
+    ) -> Result<GetWallpapersRequestBuilder, ValidationError> {
+        if let Some(error) = filter_date_year_error(filter_date_year) {
+            return Err(error.into());
         }
         self.get_wallpapers_request.filter_date_year = filter_date_year;
-        self
+        Ok(self)
     }
 
     pub fn filter_date_operator(
@@ -167,17 +251,29 @@ impl GetWallpapersRequestBuilder {
         self
     }
 
-    pub fn filter_gallery(mut self, filter_gallery: Vec<u16>) -> GetWallpapersRequestBuilder {
-        self.get_wallpapers_request.filter_gallery = filter_gallery;
+    pub fn filter_gallery(
+        mut self,
+        filter_gallery: impl IntoIterator<Item = impl Into<GalleryId>>,
+    ) -> GetWallpapersRequestBuilder {
+        self.get_wallpapers_request.filter_gallery =
+            filter_gallery.into_iter().map(Into::into).collect();
         self
     }
 
-    pub fn filter_rating(mut self, filter_rating: f32) -> GetWallpapersRequestBuilder {
-        if !(1_f32..=5_f32).contains(&filter_rating) {
-            panic!("Filter rating must be between 1 and 5.");
+    pub fn filter_rating(self, filter_rating: f32) -> GetWallpapersRequestBuilder {
+        self.try_filter_rating(filter_rating)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    pub fn try_filter_rating(
+        mut self,
+        filter_rating: f32,
+    ) -> Result<GetWallpapersRequestBuilder, ValidationError> {
+        if let Some(error) = filter_rating_error(filter_rating) {
+            return Err(error.into());
         }
         self.get_wallpapers_request.filter_rating = filter_rating;
-        self
+        Ok(self)
     }
 
     pub fn filter_rating_operator(
@@ -222,17 +318,25 @@ impl GetWallpapersRequestBuilder {
         self
     }
 
-    pub fn filter_tag(mut self, filter_tag: Vec<u16>) -> GetWallpapersRequestBuilder {
-        self.get_wallpapers_request.filter_tag = filter_tag;
+    pub fn filter_tag(
+        mut self,
+        filter_tag: impl IntoIterator<Item = impl Into<TagId>>,
+    ) -> GetWallpapersRequestBuilder {
+        self.get_wallpapers_request.filter_tag = filter_tag.into_iter().map(Into::into).collect();
         self
     }
 
-    pub fn limit(mut self, limit: u16) -> GetWallpapersRequestBuilder {
-        if !(1..=50).contains(&limit) {
-            panic!("Limit must be between 1 and 50.");
+    pub fn limit(self, limit: u16) -> GetWallpapersRequestBuilder {
+        self.try_limit(limit)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    pub fn try_limit(mut self, limit: u16) -> Result<GetWallpapersRequestBuilder, ValidationError> {
+        if let Some(error) = limit_error(limit) {
+            return Err(error.into());
         }
         self.get_wallpapers_request.limit = limit;
-        self
+        Ok(self)
     }
 
     pub fn order(mut self, order: Order) -> GetWallpapersRequestBuilder {
@@ -245,12 +349,17 @@ impl GetWallpapersRequestBuilder {
         self
     }
 
-    pub fn page(mut self, page: u16) -> GetWallpapersRequestBuilder {
-        if page < 1 {
-            panic!("Page must be greater than 0.");
+    pub fn page(self, page: u16) -> GetWallpapersRequestBuilder {
+        self.try_page(page)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    pub fn try_page(mut self, page: u16) -> Result<GetWallpapersRequestBuilder, ValidationError> {
+        if let Some(error) = page_error(page) {
+            return Err(error.into());
         }
         self.get_wallpapers_request.page = page;
-        self
+        Ok(self)
     }
 
     pub fn s(mut self, s: String) -> GetWallpapersRequestBuilder {
@@ -274,10 +383,119 @@ impl GetWallpapersRequestBuilder {
     }
 }
 
+fn filter_date_day_error(filter_date_day: u8) -> Option<FieldError> {
+    (!(1..=31).contains(&filter_date_day)).then(|| {
+        FieldError::new(
+            "filter_date_day",
+            "Filter date day must be between 1 and 31.",
+        )
+    })
+}
+
+fn filter_date_month_error(filter_date_month: u8) -> Option<FieldError> {
+    (!(1..=12).contains(&filter_date_month)).then(|| {
+        FieldError::new(
+            "filter_date_month",
+            "Filter date month must be between 1 and 12.",
+        )
+    })
+}
+
+fn filter_date_year_error(filter_date_year: u16) -> Option<FieldError> {
+    (filter_date_year < 1997).then(|| {
+        FieldError::new(
+            "filter_date_year",
+            "Filter date year must be from 1997 inclusive.",
+        )
+    })
+}
+
+fn filter_rating_error(filter_rating: f32) -> Option<FieldError> {
+    (!(1_f32..=5_f32).contains(&filter_rating))
+        .then(|| FieldError::new("filter_rating", "Filter rating must be between 1 and 5."))
+}
+
+fn limit_error(limit: u16) -> Option<FieldError> {
+    (!(1..=50).contains(&limit))
+        .then(|| FieldError::new("limit", "Limit must be between 1 and 50."))
+}
+
+fn page_error(page: u16) -> Option<FieldError> {
+    (page < 1).then(|| FieldError::new("page", "Page must be greater than 0."))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    mod get_wallpapers_response_test {
+        use super::*;
+        use std::fs;
+
+        fn response(page: u8) -> GetWallpapersResponse {
+            serde_json::from_str(
+                &fs::read_to_string(format!(
+                    "resources/get_wallpapers_success_page_{page}_of_3.json"
+                ))
+                .unwrap(),
+            )
+            .unwrap()
+        }
+
+        #[test]
+        fn test_get_wallpapers_response_keeps_server_order() {
+            let response = response(1);
+
+            let ids: Vec<u32> = response.wallpapers().iter().map(|w| w.id.get()).collect();
+            assert_eq!(ids, vec![3, 1]);
+            let keys: Vec<&String> = response.db_core.wallpapers.keys().collect();
+            assert_eq!(keys, vec!["3", "1"]);
+        }
+
+        #[test]
+        fn test_get_wallpapers_response_follows_wallpaper_ids() {
+            let mut response = response(2);
+            response.wallpaper_ids = vec![WallpaperId(2), WallpaperId(5)];
+
+            let ids: Vec<u32> = response.wallpapers().iter().map(|w| w.id.get()).collect();
+            assert_eq!(ids, vec![2, 5]);
+            let ids: Vec<u32> = response
+                .into_wallpapers()
+                .iter()
+                .map(|w| w.id.get())
+                .collect();
+            assert_eq!(ids, vec![2, 5]);
+        }
+
+        #[test]
+        fn test_get_wallpapers_response_appends_wallpapers_missing_from_wallpaper_ids() {
+            let mut response = response(2);
+            response.wallpaper_ids = vec![WallpaperId(2)];
+
+            let ids: Vec<u32> = response.wallpapers().iter().map(|w| w.id.get()).collect();
+            assert_eq!(ids, vec![2, 5]);
+            let ids: Vec<u32> = response
+                .into_wallpapers()
+                .iter()
+                .map(|w| w.id.get())
+                .collect();
+            assert_eq!(ids, vec![2, 5]);
+        }
+
+        #[test]
+        fn test_get_wallpapers_response_falls_back_to_map_order() {
+            let mut response = response(1);
+            response.wallpaper_ids = vec![];
+
+            let ids: Vec<u32> = response
+                .into_wallpapers()
+                .iter()
+                .map(|w| w.id.get())
+                .collect();
+            assert_eq!(ids, vec![3, 1]);
+        }
+    }
+
     mod get_wallpapers_order_by_test {
         use super::*;
 
@@ -416,7 +634,10 @@ mod tests {
                 get_wallpapers_request.filter_date_operator,
                 Operator::GreaterThanOrEqual
             );
-            assert_eq!(get_wallpapers_request.filter_gallery, Vec::<u16>::new());
+            assert_eq!(
+                get_wallpapers_request.filter_gallery,
+                Vec::<GalleryId>::new()
+            );
             assert_eq!(get_wallpapers_request.filter_rating, 0_f32);
             assert_eq!(
                 get_wallpapers_request.filter_rating_operator,
@@ -436,7 +657,7 @@ mod tests {
                 Operator::GreaterThanOrEqual
             );
             assert_eq!(get_wallpapers_request.filter_res_width, 0);
-            assert_eq!(get_wallpapers_request.filter_tag, Vec::<u16>::new());
+            assert_eq!(get_wallpapers_request.filter_tag, Vec::<TagId>::new());
             assert_eq!(get_wallpapers_request.limit, 10);
             assert_eq!(get_wallpapers_request.order, Order::Ascending);
             assert_eq!(get_wallpapers_request.order_by, GetWallpapersOrderBy::Date);
@@ -510,5 +731,85 @@ mod tests {
             assert!(get_wallpapers_request.show_pickle_jar);
             assert!(!get_wallpapers_request.show_resolutions);
         }
+
+        #[test]
+        fn test_get_wallpapers_request_builder_try_setters_reject_invalid_input() {
+            let builder = GetWallpapersRequest::builder;
+
+            assert_eq!(
+                builder().try_filter_date_day(32).err().unwrap().errors[0].field,
+                "filter_date_day"
+            );
+            assert_eq!(
+                builder().try_filter_date_month(13).err().unwrap().errors[0].field,
+                "filter_date_month"
+            );
+            assert_eq!(
+                builder().try_filter_date_year(1996).err().unwrap().errors[0].field,
+                "filter_date_year"
+            );
+            assert_eq!(
+                builder().try_filter_rating(6_f32).err().unwrap().errors[0].field,
+                "filter_rating"
+            );
+            assert_eq!(
+                builder().try_limit(51).err().unwrap().errors[0].field,
+                "limit"
+            );
+            assert_eq!(builder().try_page(0).err().unwrap().errors[0].field, "page");
+        }
+
+        #[test]
+        fn test_get_wallpapers_request_builder_try_setters_accept_valid_input()
+        -> Result<(), ValidationError> {
+            let get_wallpapers_request = GetWallpapersRequest::builder()
+                .try_filter_date_day(1)?
+                .try_filter_date_month(2)?
+                .try_filter_date_year(2000)?
+                .try_filter_rating(3_f32)?
+                .try_limit(4)?
+                .try_page(5)?
+                .try_build()?;
+
+            assert_eq!(get_wallpapers_request.filter_date_day, 1);
+            assert_eq!(get_wallpapers_request.filter_date_month, 2);
+            assert_eq!(get_wallpapers_request.filter_date_year, 2000);
+            assert_eq!(get_wallpapers_request.filter_rating, 3_f32);
+            assert_eq!(get_wallpapers_request.limit, 4);
+            assert_eq!(get_wallpapers_request.page, 5);
+
+            Ok(())
+        }
+
+        #[test]
+        fn test_get_wallpapers_request_validate_lists_every_invalid_field() {
+            let mut get_wallpapers_request = GetWallpapersRequest::builder().build();
+            get_wallpapers_request.filter_date_day = 32;
+            get_wallpapers_request.filter_date_month = 13;
+            get_wallpapers_request.filter_date_year = 1996;
+            get_wallpapers_request.filter_rating = 6_f32;
+            get_wallpapers_request.limit = 0;
+            get_wallpapers_request.page = 0;
+
+            let error = get_wallpapers_request.validate().unwrap_err();
+
+            let fields: Vec<&str> = error.errors.iter().map(|error| error.field).collect();
+            assert_eq!(
+                fields,
+                vec![
+                    "filter_date_day",
+                    "filter_date_month",
+                    "filter_date_year",
+                    "filter_rating",
+                    "limit",
+                    "page"
+                ]
+            );
+        }
+
+        #[test]
+        fn test_get_wallpapers_request_validate_accepts_unset_filters() {
+            assert_eq!(GetWallpapersRequest::builder().build().validate(), Ok(()));
+        }
     }
 }
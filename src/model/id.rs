@@ -0,0 +1,145 @@
+use serde::{Deserialize, Deserializer, Serialize};
+use std::fmt;
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+macro_rules! id {
+    ($(#[$meta:meta])* $name:ident) => {
+        $(#[$meta])*
+        #[derive(Serialize, Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        #[serde(transparent)]
+        pub struct $name(pub u32);
+
+        impl $name {
+            pub fn get(self) -> u32 {
+                self.0
+            }
+        }
+
+        impl From<u32> for $name {
+            fn from(id: u32) -> Self {
+                $name(id)
+            }
+        }
+
+        impl From<$name> for u32 {
+            fn from(id: $name) -> Self {
+                id.0
+            }
+        }
+
+        impl PartialEq<u32> for $name {
+            fn eq(&self, other: &u32) -> bool {
+                self.0 == *other
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                self.0.fmt(f)
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = ParseIntError;
+
+            fn from_str(id: &str) -> Result<Self, Self::Err> {
+                id.trim().parse().map($name)
+            }
+        }
+
+        /// Accepts the ID as either a JSON number or a string, as the API uses both.
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                crate::model::string_or_number::deserialize(deserializer).map($name)
+            }
+        }
+    };
+}
+
+id!(
+    /// Identifies a wallpaper.
+    WallpaperId
+);
+id!(
+    /// Identifies a gallery, as used by `filter_gallery`.
+    GalleryId
+);
+id!(
+    /// Identifies a tag.
+    TagId
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod wallpaper_id_test {
+        use super::*;
+
+        #[test]
+        fn test_wallpaper_id_accepts_ids_above_u16() {
+            let id: WallpaperId = serde_json::from_str("70000").unwrap();
+
+            assert_eq!(id, WallpaperId(70000));
+        }
+
+        #[test]
+        fn test_wallpaper_id_deserializes_from_string() {
+            let id: WallpaperId = serde_json::from_str(r#""123""#).unwrap();
+
+            assert_eq!(id, 123);
+        }
+
+        #[test]
+        fn test_wallpaper_id_rejects_negative_ids() {
+            assert!(serde_json::from_str::<WallpaperId>("-1").is_err());
+        }
+
+        #[test]
+        fn test_wallpaper_id_serializes_as_number() {
+            assert_eq!(serde_json::to_string(&WallpaperId(123)).unwrap(), "123");
+        }
+
+        #[test]
+        fn test_wallpaper_id_display() {
+            assert_eq!(WallpaperId(123).to_string(), "123");
+        }
+
+        #[test]
+        fn test_wallpaper_id_from_str() {
+            assert_eq!("123".parse::<WallpaperId>(), Ok(WallpaperId(123)));
+            assert!("abc".parse::<WallpaperId>().is_err());
+        }
+
+        #[test]
+        fn test_wallpaper_id_converts_to_and_from_u32() {
+            let id = WallpaperId::from(123);
+
+            assert_eq!(u32::from(id), 123);
+            assert_eq!(id.get(), 123);
+        }
+    }
+
+    mod tag_id_test {
+        use super::*;
+
+        #[test]
+        fn test_tag_id_deserializes_from_number() {
+            let id: TagId = serde_json::from_str("28").unwrap();
+
+            assert_eq!(id, TagId(28));
+        }
+    }
+
+    mod gallery_id_test {
+        use super::*;
+
+        #[test]
+        fn test_gallery_id_deserializes_from_number() {
+            let id: GalleryId = serde_json::from_str("5").unwrap();
+
+            assert_eq!(id, GalleryId(5));
+        }
+    }
+}
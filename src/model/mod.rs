@@ -5,6 +5,10 @@ mod error;
 mod get_account_information;
 mod get_wallpaper;
 mod get_wallpapers;
+mod id;
+mod string_or_number;
+#[cfg(feature = "time")]
+mod timestamps;
 mod wallpaper;
 
 pub use common::*;
@@ -14,4 +18,5 @@ pub use error::*;
 pub use get_account_information::*;
 pub use get_wallpaper::*;
 pub use get_wallpapers::*;
+pub use id::*;
 pub use wallpaper::*;
@@ -0,0 +1,181 @@
+//! Serde helpers for numeric fields the API sends as either JSON strings or numbers. Values are
+//! serialized back as strings to match the API.
+
+use serde::de::{self, Deserializer, Visitor};
+use serde::{Deserialize, Serializer};
+use std::fmt::{self, Display};
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+pub(crate) fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Display,
+    S: Serializer,
+{
+    serializer.collect_str(value)
+}
+
+pub(crate) fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: FromStr,
+    T::Err: Display,
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_any(StringOrNumberVisitor(PhantomData))
+}
+
+struct StringOrNumberVisitor<T>(PhantomData<T>);
+
+impl<T> StringOrNumberVisitor<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    fn parse<E: de::Error>(value: &str) -> Result<T, E> {
+        value
+            .trim()
+            .parse()
+            .map_err(|error| E::custom(format!("invalid number {value:?}: {error}")))
+    }
+}
+
+impl<T> Visitor<'_> for StringOrNumberVisitor<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a number or a string containing a number")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<T, E> {
+        Self::parse(value)
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<T, E> {
+        Self::parse(&value.to_string())
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<T, E> {
+        Self::parse(&value.to_string())
+    }
+
+    fn visit_f64<E: de::Error>(self, value: f64) -> Result<T, E> {
+        Self::parse(&value.to_string())
+    }
+}
+
+pub(crate) mod option {
+    use super::*;
+
+    struct StringOrNumber<T>(T);
+
+    impl<'de, T> Deserialize<'de> for StringOrNumber<T>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            super::deserialize(deserializer).map(StringOrNumber)
+        }
+    }
+
+    pub(crate) fn serialize<T, S>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Display,
+        S: Serializer,
+    {
+        match value {
+            Some(value) => serializer.collect_str(value),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub(crate) fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+    where
+        T: FromStr,
+        T::Err: Display,
+        D: Deserializer<'de>,
+    {
+        Ok(Option::<StringOrNumber<T>>::deserialize(deserializer)?.map(|value| value.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Numbers {
+        #[serde(with = "super")]
+        width: u32,
+        #[serde(default, with = "super::option")]
+        rating: Option<f64>,
+    }
+
+    #[test]
+    fn deserialize_accepts_strings() {
+        let numbers: Numbers =
+            serde_json::from_str(r#"{"width": "1920", "rating": "4.5"}"#).unwrap();
+
+        assert_eq!(
+            numbers,
+            Numbers {
+                width: 1920,
+                rating: Some(4.5)
+            }
+        );
+    }
+
+    #[test]
+    fn deserialize_accepts_numbers() {
+        let numbers: Numbers = serde_json::from_str(r#"{"width": 1920, "rating": 4}"#).unwrap();
+
+        assert_eq!(
+            numbers,
+            Numbers {
+                width: 1920,
+                rating: Some(4.0)
+            }
+        );
+    }
+
+    #[test]
+    fn deserialize_accepts_missing_and_null_options() {
+        let missing: Numbers = serde_json::from_str(r#"{"width": 1}"#).unwrap();
+        let null: Numbers = serde_json::from_str(r#"{"width": 1, "rating": null}"#).unwrap();
+
+        assert_eq!(missing.rating, None);
+        assert_eq!(null.rating, None);
+    }
+
+    #[test]
+    fn deserialize_rejects_invalid_values() {
+        let error = serde_json::from_str::<Numbers>(r#"{"width": "wide"}"#).unwrap_err();
+
+        assert!(
+            error.to_string().starts_with("invalid number \"wide\""),
+            "{error}"
+        );
+    }
+
+    #[test]
+    fn deserialize_rejects_out_of_range_values() {
+        assert!(serde_json::from_str::<Numbers>(r#"{"width": -1}"#).is_err());
+    }
+
+    #[test]
+    fn serialize_round_trips_as_strings() {
+        let numbers = Numbers {
+            width: 1920,
+            rating: Some(4.5),
+        };
+
+        let json = serde_json::to_string(&numbers).unwrap();
+
+        assert_eq!(json, r#"{"width":"1920","rating":"4.5"}"#);
+        assert_eq!(serde_json::from_str::<Numbers>(&json).unwrap(), numbers);
+    }
+}
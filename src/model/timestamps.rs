@@ -0,0 +1,154 @@
+//! Typed views of the Unix timestamps in the API responses, enabled by the `time` feature.
+
+use crate::model::{
+    Comment, Download, DownloadWallpaperDBCore, GetAccountInformationDBCore, GetWallpaperDBCore,
+    GetWallpapersDBCore, Wallpaper,
+};
+use time::{Date, OffsetDateTime};
+
+fn from_unix_timestamp(timestamp: i64) -> Option<OffsetDateTime> {
+    OffsetDateTime::from_unix_timestamp(timestamp).ok()
+}
+
+impl Wallpaper {
+    pub fn released_at(&self) -> Option<OffsetDateTime> {
+        self.timestamp.and_then(from_unix_timestamp)
+    }
+
+    /// The UTC date the wallpaper was released on.
+    pub fn released_on(&self) -> Option<Date> {
+        self.released_at().map(|released_at| released_at.date())
+    }
+}
+
+impl Comment {
+    pub fn posted_at(&self) -> Option<OffsetDateTime> {
+        from_unix_timestamp(self.timestamp)
+    }
+}
+
+impl Download {
+    pub fn expires_at(&self) -> Option<OffsetDateTime> {
+        from_unix_timestamp(self.expiration)
+    }
+
+    /// Whether the signed download URL can no longer be used at `now`.
+    pub fn is_expired(&self, now: OffsetDateTime) -> bool {
+        self.expiration <= now.unix_timestamp()
+    }
+}
+
+macro_rules! db_core_generated_at {
+    ($($db_core:ty),*) => {
+        $(
+            impl $db_core {
+                /// When the API generated the response.
+                pub fn generated_at(&self) -> Option<OffsetDateTime> {
+                    from_unix_timestamp(self.timestamp)
+                }
+            }
+        )*
+    };
+}
+
+db_core_generated_at!(
+    DownloadWallpaperDBCore,
+    GetAccountInformationDBCore,
+    GetWallpaperDBCore,
+    GetWallpapersDBCore
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::GetAccountInformationResponse;
+    use crate::test_support::wallpaper;
+    use std::fs;
+    use time::macros::{date, datetime};
+
+    mod wallpaper_test {
+        use super::*;
+
+        #[test]
+        fn test_wallpaper_released_at() {
+            let mut wallpaper = wallpaper();
+            wallpaper.timestamp = Some(1_700_000_000);
+
+            assert_eq!(
+                wallpaper.released_at(),
+                Some(datetime!(2023-11-14 22:13:20 UTC))
+            );
+            assert_eq!(wallpaper.released_on(), Some(date!(2023 - 11 - 14)));
+        }
+
+        #[test]
+        fn test_wallpaper_released_at_is_none_without_timestamp() {
+            let mut wallpaper = wallpaper();
+            wallpaper.timestamp = None;
+
+            assert_eq!(wallpaper.released_at(), None);
+            assert_eq!(wallpaper.released_on(), None);
+        }
+
+        #[test]
+        fn test_comment_posted_at() {
+            let wallpaper = wallpaper();
+            let comment = &wallpaper.comments.unwrap().comments[0];
+
+            assert_eq!(
+                comment.posted_at(),
+                Some(OffsetDateTime::from_unix_timestamp(comment.timestamp).unwrap())
+            );
+        }
+    }
+
+    mod download_test {
+        use super::*;
+
+        fn download(expiration: i64) -> Download {
+            Download {
+                expiration,
+                url: "https://arcadia.digitalblasphemy.com/wallpaper.jpg".to_string(),
+            }
+        }
+
+        #[test]
+        fn test_download_expires_at() {
+            assert_eq!(
+                download(1_700_000_000).expires_at(),
+                Some(datetime!(2023-11-14 22:13:20 UTC))
+            );
+        }
+
+        #[test]
+        fn test_download_expires_at_is_none_when_out_of_range() {
+            assert_eq!(download(i64::MAX).expires_at(), None);
+        }
+
+        #[test]
+        fn test_download_is_expired() {
+            let download = download(1_700_000_000);
+
+            assert!(!download.is_expired(datetime!(2023-11-14 22:13:19 UTC)));
+            assert!(download.is_expired(datetime!(2023-11-14 22:13:20 UTC)));
+            assert!(download.is_expired(datetime!(2024-01-01 00:00:00 UTC)));
+        }
+    }
+
+    mod db_core_test {
+        use super::*;
+
+        #[test]
+        fn test_db_core_generated_at() {
+            let response: GetAccountInformationResponse = serde_json::from_str(
+                &fs::read_to_string("resources/get_account_information_success.json").unwrap(),
+            )
+            .unwrap();
+
+            assert_eq!(
+                response.db_core.generated_at(),
+                OffsetDateTime::from_unix_timestamp(response.db_core.timestamp).ok()
+            );
+        }
+    }
+}
@@ -1,9 +1,11 @@
+use crate::model::{DownloadWallpaperRequest, Endpoints, TagId, WallpaperId, WallpaperType};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use url::{ParseError, Url};
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct Wallpaper {
-    pub id: u16,
+    pub id: WallpaperId,
     pub all_free: Option<bool>,
     pub comments: Option<Comments>,
     pub content: Option<String>,
@@ -11,13 +13,95 @@ pub struct Wallpaper {
     pub name: String,
     pub paths: Paths,
     pub pickle_jar: Option<PickleJar>,
-    pub rating: Option<String>, // f64
+    #[serde(default, with = "crate::model::string_or_number::option")]
+    pub rating: Option<f64>,
     pub resolutions: Option<Resolutions>,
     pub sku: Option<String>,
     pub tags: Option<HashMap<String, Tag>>,
     pub timestamp: Option<i64>,
 }
 
+impl Wallpaper {
+    pub fn thumbnail_url(&self, endpoints: &Endpoints) -> Result<Url, ParseError> {
+        Endpoints::resolve(&endpoints.thumb, &self.paths.thumb)
+    }
+
+    pub fn web_url(&self, endpoints: &Endpoints) -> Result<Url, ParseError> {
+        Endpoints::resolve(&endpoints.web, &self.paths.web)
+    }
+
+    /// Picks the resolution of `wallpaper_type` that best fits a `target_width` by
+    /// `target_height` display, along with a request to download it. Returns `None` when the
+    /// wallpaper has no resolutions of that type or none satisfy `fit_policy`.
+    pub fn best_resolution(
+        &self,
+        target_width: u32,
+        target_height: u32,
+        wallpaper_type: WallpaperType,
+        fit_policy: FitPolicy,
+    ) -> Option<(Resolution, DownloadWallpaperRequest)> {
+        let resolutions = self.resolutions.as_ref()?.for_type(&wallpaper_type);
+        let resolution = fit_policy.choose(resolutions, target_width, target_height)?;
+        let request = DownloadWallpaperRequest::builder()
+            .wallpaper_type(wallpaper_type)
+            .width(resolution.width)
+            .height(resolution.height)
+            .wallpaper_id(self.id)
+            .try_build()
+            .ok()?;
+        Some((resolution.clone(), request))
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum FitPolicy {
+    /// Only a resolution matching the target exactly.
+    Exact,
+    /// The smallest resolution at least as wide and as tall as the target, so it only ever needs
+    /// scaling down.
+    SmallestAtLeast,
+    /// The resolution whose aspect ratio is closest to the target's, preferring the largest when
+    /// several are equally close.
+    ClosestAspectRatio,
+}
+
+impl FitPolicy {
+    fn choose(
+        self,
+        resolutions: &[Resolution],
+        target_width: u32,
+        target_height: u32,
+    ) -> Option<&Resolution> {
+        match self {
+            FitPolicy::Exact => resolutions.iter().find(|resolution| {
+                resolution.width == target_width && resolution.height == target_height
+            }),
+            FitPolicy::SmallestAtLeast => resolutions
+                .iter()
+                .filter(|resolution| {
+                    resolution.width >= target_width && resolution.height >= target_height
+                })
+                .min_by_key(|resolution| resolution.area()),
+            FitPolicy::ClosestAspectRatio => {
+                if target_height == 0 {
+                    return None;
+                }
+                let target_ratio = target_width as f64 / target_height as f64;
+                resolutions
+                    .iter()
+                    .filter(|resolution| resolution.height != 0)
+                    .min_by(|a, b| {
+                        let a_distance = (a.aspect_ratio() - target_ratio).abs();
+                        let b_distance = (b.aspect_ratio() - target_ratio).abs();
+                        a_distance
+                            .total_cmp(&b_distance)
+                            .then_with(|| b.area().cmp(&a.area()))
+                    })
+            }
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct Comments {
     pub comments: Vec<Comment>,
@@ -25,11 +109,13 @@ pub struct Comments {
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct Comment {
-    pub id: String,
+    #[serde(with = "crate::model::string_or_number")]
+    pub id: u64,
     pub author_id: String,
     pub author_display: String,
     pub content: String,
-    pub rating: String,
+    #[serde(with = "crate::model::string_or_number")]
+    pub rating: f64,
     pub timestamp: i64,
 }
 
@@ -54,16 +140,235 @@ pub struct Resolutions {
     pub mobile: Option<Vec<Resolution>>,
 }
 
+impl Resolutions {
+    pub fn for_type(&self, wallpaper_type: &WallpaperType) -> &[Resolution] {
+        match wallpaper_type {
+            WallpaperType::Single => &self.single,
+            WallpaperType::Dual => self.dual.as_deref().unwrap_or_default(),
+            WallpaperType::Triple => self.triple.as_deref().unwrap_or_default(),
+            WallpaperType::Mobile => self.mobile.as_deref().unwrap_or_default(),
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct Resolution {
     pub label: String,
-    pub width: String,
-    pub height: String,
+    #[serde(with = "crate::model::string_or_number")]
+    pub width: u32,
+    #[serde(with = "crate::model::string_or_number")]
+    pub height: u32,
     pub image: String,
 }
 
+impl Resolution {
+    pub fn image_url(&self, endpoints: &Endpoints) -> Result<Url, ParseError> {
+        Endpoints::resolve(&endpoints.image, &self.image)
+    }
+
+    fn area(&self) -> u64 {
+        self.width as u64 * self.height as u64
+    }
+
+    fn aspect_ratio(&self) -> f64 {
+        self.width as f64 / self.height as f64
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct Tag {
-    pub id: i64,
+    pub id: TagId,
     pub name: String,
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::model::GetWallpaperResponse;
+    use std::fs;
+
+    fn get_wallpaper_response() -> GetWallpaperResponse {
+        serde_json::from_str(
+            &fs::read_to_string("resources/get_wallpaper_success_fully_populated.json").unwrap(),
+        )
+        .unwrap()
+    }
+
+    mod wallpaper_test {
+        use super::*;
+
+        #[test]
+        fn test_wallpaper_thumbnail_url() {
+            let response = get_wallpaper_response();
+            let wallpaper = response.wallpaper.unwrap();
+
+            assert_eq!(
+                wallpaper
+                    .thumbnail_url(&response.db_core.endpoints)
+                    .unwrap()
+                    .as_str(),
+                "https://cdn.digitalblasphemy.com/thumbnail/12x13/vulcan_thumbnail_12x13.jpg"
+            );
+        }
+
+        #[test]
+        fn test_wallpaper_web_url() {
+            let response = get_wallpaper_response();
+            let wallpaper = response.wallpaper.unwrap();
+
+            assert_eq!(
+                wallpaper
+                    .web_url(&response.db_core.endpoints)
+                    .unwrap()
+                    .as_str(),
+                "https://digitalblasphemy.com/sec/vulcan/"
+            );
+        }
+    }
+
+    mod resolution_test {
+        use super::*;
+
+        #[test]
+        fn test_resolution_image_url() {
+            let response = get_wallpaper_response();
+            let resolutions = response.wallpaper.unwrap().resolutions.unwrap();
+
+            assert_eq!(
+                resolutions.single[0]
+                    .image_url(&response.db_core.endpoints)
+                    .unwrap()
+                    .as_str(),
+                "https://arcadia.digitalblasphemy.com/single/12x13/vulcan_single_12x13.jpg"
+            );
+        }
+    }
+
+    mod best_resolution_test {
+        use crate::model::{FitPolicy, Wallpaper, WallpaperType};
+        use crate::test_support::resolutions;
+
+        fn wallpaper() -> Wallpaper {
+            let mut wallpaper = crate::test_support::wallpaper();
+            wallpaper.resolutions = Some(resolutions(
+                &[(1280, 1024), (1920, 1080), (2560, 1440), (3840, 2160)],
+                &[(3840, 1080)],
+                &[],
+            ));
+            wallpaper
+        }
+
+        #[test]
+        fn test_best_resolution_exact() {
+            let (resolution, request) = wallpaper()
+                .best_resolution(2560, 1440, WallpaperType::Single, FitPolicy::Exact)
+                .unwrap();
+
+            assert_eq!(resolution.label, "2560x1440");
+            assert_eq!(request.width, 2560);
+            assert_eq!(request.height, 1440);
+            assert_eq!(request.wallpaper_id, wallpaper().id);
+            assert_eq!(request.wallpaper_type, WallpaperType::Single);
+        }
+
+        #[test]
+        fn test_best_resolution_exact_without_match() {
+            assert!(
+                wallpaper()
+                    .best_resolution(2000, 1000, WallpaperType::Single, FitPolicy::Exact)
+                    .is_none()
+            );
+        }
+
+        #[test]
+        fn test_best_resolution_smallest_at_least() {
+            let (resolution, _) = wallpaper()
+                .best_resolution(
+                    2000,
+                    1200,
+                    WallpaperType::Single,
+                    FitPolicy::SmallestAtLeast,
+                )
+                .unwrap();
+
+            assert_eq!(resolution.label, "2560x1440");
+        }
+
+        #[test]
+        fn test_best_resolution_smallest_at_least_without_large_enough_resolution() {
+            assert!(
+                wallpaper()
+                    .best_resolution(
+                        5120,
+                        2880,
+                        WallpaperType::Single,
+                        FitPolicy::SmallestAtLeast
+                    )
+                    .is_none()
+            );
+        }
+
+        #[test]
+        fn test_best_resolution_closest_aspect_ratio_prefers_largest() {
+            let (resolution, _) = wallpaper()
+                .best_resolution(
+                    1600,
+                    900,
+                    WallpaperType::Single,
+                    FitPolicy::ClosestAspectRatio,
+                )
+                .unwrap();
+
+            assert_eq!(resolution.label, "3840x2160");
+        }
+
+        #[test]
+        fn test_best_resolution_closest_aspect_ratio() {
+            let (resolution, _) = wallpaper()
+                .best_resolution(
+                    1600,
+                    1280,
+                    WallpaperType::Single,
+                    FitPolicy::ClosestAspectRatio,
+                )
+                .unwrap();
+
+            assert_eq!(resolution.label, "1280x1024");
+        }
+
+        #[test]
+        fn test_best_resolution_uses_resolutions_of_type() {
+            let (resolution, request) = wallpaper()
+                .best_resolution(3840, 1080, WallpaperType::Dual, FitPolicy::Exact)
+                .unwrap();
+
+            assert_eq!(resolution.label, "3840x1080");
+            assert_eq!(request.wallpaper_type, WallpaperType::Dual);
+        }
+
+        #[test]
+        fn test_best_resolution_without_resolutions_of_type() {
+            assert!(
+                wallpaper()
+                    .best_resolution(
+                        5760,
+                        1080,
+                        WallpaperType::Triple,
+                        FitPolicy::ClosestAspectRatio
+                    )
+                    .is_none()
+            );
+        }
+
+        #[test]
+        fn test_best_resolution_without_resolutions() {
+            let mut wallpaper = wallpaper();
+            wallpaper.resolutions = None;
+
+            assert!(
+                wallpaper
+                    .best_resolution(1920, 1080, WallpaperType::Single, FitPolicy::Exact)
+                    .is_none()
+            );
+        }
+    }
+}
@@ -0,0 +1,301 @@
+use reqwest::header::HeaderMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+const REMAINING_HEADERS: [&str; 2] = ["x-ratelimit-remaining", "ratelimit-remaining"];
+const RESET_HEADERS: [&str; 2] = ["x-ratelimit-reset", "ratelimit-reset"];
+
+/// Reset values above this are treated as Unix timestamps rather than a number of seconds.
+const RESET_TIMESTAMP_THRESHOLD: u64 = 1_000_000_000;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RateLimit {
+    requests: u32,
+    per: Duration,
+    burst: u32,
+}
+
+impl RateLimit {
+    /// Allows `requests` requests every `per`, with bursts of up to `requests`.
+    pub fn new(requests: u32, per: Duration) -> Self {
+        if requests < 1 {
+            panic!("Requests must be greater than 0.");
+        }
+        if per.is_zero() {
+            panic!("Per must be greater than 0.");
+        }
+        RateLimit {
+            requests,
+            per,
+            burst: requests,
+        }
+    }
+
+    pub fn with_burst(mut self, burst: u32) -> Self {
+        if burst < 1 {
+            panic!("Burst must be greater than 0.");
+        }
+        self.burst = burst;
+        self
+    }
+
+    pub fn requests(&self) -> u32 {
+        self.requests
+    }
+
+    pub fn per(&self) -> Duration {
+        self.per
+    }
+
+    pub fn burst(&self) -> u32 {
+        self.burst
+    }
+
+    fn tokens_per_second(&self) -> f64 {
+        self.requests as f64 / self.per.as_secs_f64()
+    }
+}
+
+/// A token bucket that makes callers wait for capacity instead of failing.
+#[derive(Debug)]
+pub(crate) struct RateLimiter {
+    rate_limit: RateLimit,
+    state: Mutex<RateLimiterState>,
+}
+
+#[derive(Debug)]
+struct RateLimiterState {
+    tokens: f64,
+    last_refill: Instant,
+    paused_until: Option<Instant>,
+}
+
+impl RateLimiter {
+    pub(crate) fn new(rate_limit: RateLimit) -> Self {
+        RateLimiter {
+            rate_limit,
+            state: Mutex::new(RateLimiterState {
+                tokens: rate_limit.burst as f64,
+                last_refill: Instant::now(),
+                paused_until: None,
+            }),
+        }
+    }
+
+    pub(crate) async fn acquire(&self) {
+        while let Some(wait) = self.try_acquire(Instant::now()) {
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    /// Takes a token if one is available, otherwise returns how long to wait before trying again.
+    fn try_acquire(&self, now: Instant) -> Option<Duration> {
+        let mut state = self.state.lock().unwrap_or_else(|error| error.into_inner());
+        if let Some(paused_until) = state.paused_until {
+            if paused_until > now {
+                return Some(paused_until - now);
+            }
+            state.paused_until = None;
+        }
+
+        let elapsed = now
+            .saturating_duration_since(state.last_refill)
+            .as_secs_f64();
+        state.tokens = (state.tokens + elapsed * self.rate_limit.tokens_per_second())
+            .min(self.rate_limit.burst as f64);
+        state.last_refill = now;
+
+        if state.tokens >= 1_f64 {
+            state.tokens -= 1_f64;
+            None
+        } else {
+            let missing = 1_f64 - state.tokens;
+            Some(Duration::from_secs_f64(
+                missing / self.rate_limit.tokens_per_second(),
+            ))
+        }
+    }
+
+    /// Stops handing out tokens until `now + delay`.
+    pub(crate) fn pause(&self, delay: Duration) {
+        self.pause_at(Instant::now(), delay);
+    }
+
+    fn pause_at(&self, now: Instant, delay: Duration) {
+        let mut state = self.state.lock().unwrap_or_else(|error| error.into_inner());
+        let paused_until = now + delay;
+        if state
+            .paused_until
+            .is_none_or(|current| current < paused_until)
+        {
+            state.paused_until = Some(paused_until);
+        }
+    }
+
+    /// Adapts to `X-RateLimit-Remaining`/`X-RateLimit-Reset` (or the unprefixed `RateLimit-*`)
+    /// headers, so the bucket never holds more tokens than the server says are left.
+    pub(crate) fn update(&self, headers: &HeaderMap) {
+        let Some(remaining) = header_value(headers, &REMAINING_HEADERS) else {
+            return;
+        };
+        let reset = header_value(headers, &RESET_HEADERS).map(|reset| {
+            if reset > RESET_TIMESTAMP_THRESHOLD {
+                let now = SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .map_or(0, |duration| duration.as_secs());
+                Duration::from_secs(reset.saturating_sub(now))
+            } else {
+                Duration::from_secs(reset)
+            }
+        });
+        self.update_at(Instant::now(), remaining, reset);
+    }
+
+    fn update_at(&self, now: Instant, remaining: u64, reset: Option<Duration>) {
+        if remaining == 0 {
+            if let Some(reset) = reset {
+                self.pause_at(now, reset);
+            }
+            return;
+        }
+        let mut state = self.state.lock().unwrap_or_else(|error| error.into_inner());
+        state.tokens = state.tokens.min(remaining as f64);
+    }
+}
+
+fn header_value(headers: &HeaderMap, names: &[&str]) -> Option<u64> {
+    names
+        .iter()
+        .filter_map(|name| headers.get(*name))
+        .find_map(|value| value.to_str().ok()?.trim().parse().ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    mod rate_limit_test {
+        use super::*;
+
+        #[test]
+        fn rate_limit_new_sets_burst_to_requests() {
+            let rate_limit = RateLimit::new(5, Duration::from_secs(1));
+
+            assert_eq!(rate_limit.burst(), 5);
+        }
+
+        #[test]
+        fn rate_limit_with_burst_overrides_burst() {
+            let rate_limit = RateLimit::new(5, Duration::from_secs(1)).with_burst(1);
+
+            assert_eq!(rate_limit.burst(), 1);
+        }
+
+        #[test]
+        #[should_panic(expected = "Requests must be greater than 0.")]
+        fn rate_limit_new_rejects_0_requests() {
+            RateLimit::new(0, Duration::from_secs(1));
+        }
+
+        #[test]
+        #[should_panic(expected = "Per must be greater than 0.")]
+        fn rate_limit_new_rejects_zero_duration() {
+            RateLimit::new(1, Duration::ZERO);
+        }
+
+        #[test]
+        #[should_panic(expected = "Burst must be greater than 0.")]
+        fn rate_limit_with_burst_rejects_0() {
+            RateLimit::new(1, Duration::from_secs(1)).with_burst(0);
+        }
+    }
+
+    mod rate_limiter_test {
+        use super::*;
+
+        #[test]
+        fn rate_limiter_allows_burst_then_waits() {
+            let rate_limiter = RateLimiter::new(RateLimit::new(2, Duration::from_secs(1)));
+            let now = Instant::now();
+
+            assert_eq!(rate_limiter.try_acquire(now), None);
+            assert_eq!(rate_limiter.try_acquire(now), None);
+            assert_eq!(
+                rate_limiter.try_acquire(now),
+                Some(Duration::from_millis(500))
+            );
+        }
+
+        #[test]
+        fn rate_limiter_refills_over_time() {
+            let rate_limiter = RateLimiter::new(RateLimit::new(2, Duration::from_secs(1)));
+            let now = Instant::now();
+
+            rate_limiter.try_acquire(now);
+            rate_limiter.try_acquire(now);
+
+            assert_eq!(
+                rate_limiter.try_acquire(now + Duration::from_millis(500)),
+                None
+            );
+        }
+
+        #[test]
+        fn rate_limiter_waits_while_paused() {
+            let rate_limiter = RateLimiter::new(RateLimit::new(2, Duration::from_secs(1)));
+            let now = Instant::now();
+
+            rate_limiter.pause_at(now, Duration::from_secs(3));
+
+            assert_eq!(
+                rate_limiter.try_acquire(now + Duration::from_secs(1)),
+                Some(Duration::from_secs(2))
+            );
+            assert_eq!(rate_limiter.try_acquire(now + Duration::from_secs(3)), None);
+        }
+
+        #[test]
+        fn rate_limiter_update_caps_tokens_at_remaining() {
+            let rate_limiter = RateLimiter::new(RateLimit::new(10, Duration::from_secs(1)));
+            let now = Instant::now();
+
+            rate_limiter.update_at(now, 1, None);
+
+            assert_eq!(rate_limiter.try_acquire(now), None);
+            assert!(rate_limiter.try_acquire(now).is_some());
+        }
+
+        #[test]
+        fn rate_limiter_update_pauses_until_reset_when_exhausted() {
+            let rate_limiter = RateLimiter::new(RateLimit::new(10, Duration::from_secs(1)));
+            let now = Instant::now();
+
+            rate_limiter.update_at(now, 0, Some(Duration::from_secs(30)));
+
+            assert_eq!(rate_limiter.try_acquire(now), Some(Duration::from_secs(30)));
+        }
+
+        #[test]
+        fn rate_limiter_update_reads_rate_limit_headers() {
+            let rate_limiter = RateLimiter::new(RateLimit::new(10, Duration::from_secs(1)));
+            let mut headers = HeaderMap::new();
+            headers.insert("x-ratelimit-remaining", HeaderValue::from_static("0"));
+            headers.insert("x-ratelimit-reset", HeaderValue::from_static("60"));
+
+            rate_limiter.update(&headers);
+
+            let wait = rate_limiter.try_acquire(Instant::now()).unwrap();
+            assert!(wait > Duration::from_secs(59));
+        }
+
+        #[test]
+        fn rate_limiter_update_ignores_missing_headers() {
+            let rate_limiter = RateLimiter::new(RateLimit::new(1, Duration::from_secs(1)));
+
+            rate_limiter.update(&HeaderMap::new());
+
+            assert_eq!(rate_limiter.try_acquire(Instant::now()), None);
+        }
+    }
+}
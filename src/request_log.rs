@@ -0,0 +1,127 @@
+use reqwest::Request;
+use reqwest::header::{
+    ACCEPT, AUTHORIZATION, HeaderName, IF_MODIFIED_SINCE, IF_NONE_MATCH, RANGE, USER_AGENT,
+};
+
+const REDACTED: &str = "[REDACTED]";
+
+const LOGGED_HEADERS: [HeaderName; 6] = [
+    ACCEPT,
+    AUTHORIZATION,
+    IF_MODIFIED_SINCE,
+    IF_NONE_MATCH,
+    RANGE,
+    USER_AGENT,
+];
+
+/// Describes `request` for logging without leaking credentials. The `Authorization` header is
+/// always masked, and query values are masked when `mask_query` is set, as signed download URLs
+/// carry their credentials in the query string.
+pub(crate) fn describe(request: &Request, mask_query: bool) -> String {
+    let url = request.url();
+    let mut description = format!(
+        "{} {}{}",
+        request.method(),
+        url.origin().ascii_serialization(),
+        url.path()
+    );
+
+    let query: Vec<String> = url
+        .query_pairs()
+        .map(|(name, value)| {
+            if mask_query {
+                format!("{name}={REDACTED}")
+            } else {
+                format!("{name}={value}")
+            }
+        })
+        .collect();
+    if !query.is_empty() {
+        description.push('?');
+        description.push_str(&query.join("&"));
+    }
+
+    let headers: Vec<String> = LOGGED_HEADERS
+        .iter()
+        .filter_map(|name| {
+            let value = request.headers().get(name)?;
+            let value = if *name == AUTHORIZATION {
+                REDACTED
+            } else {
+                value.to_str().unwrap_or("<non-ascii>")
+            };
+            Some(format!("{name}: {value}"))
+        })
+        .collect();
+    if !headers.is_empty() {
+        description.push_str(" [");
+        description.push_str(&headers.join(", "));
+        description.push(']');
+    }
+
+    description
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(url: &str) -> Request {
+        reqwest::Client::new()
+            .get(url)
+            .header(ACCEPT, "application/json")
+            .header(AUTHORIZATION, "Bearer api_key")
+            .header(RANGE, "bytes=6-")
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn describe_masks_authorization_header() {
+        let description = describe(&request("https://api.digitalblasphemy.com/v2/core"), false);
+
+        assert_eq!(
+            description,
+            "GET https://api.digitalblasphemy.com/v2/core [accept: application/json, authorization: [REDACTED], range: bytes=6-]"
+        );
+    }
+
+    #[test]
+    fn describe_includes_query_when_not_masked() {
+        let description = describe(
+            &request("https://api.digitalblasphemy.com/v2/core/wallpapers?limit=10&page=2"),
+            false,
+        );
+
+        assert!(description.starts_with(
+            "GET https://api.digitalblasphemy.com/v2/core/wallpapers?limit=10&page=2 ["
+        ));
+    }
+
+    #[test]
+    fn describe_masks_query_values_when_masked() {
+        let description = describe(
+            &request("https://arcadia.digitalblasphemy.com/test.jpg?Expires=5&Signature=abc"),
+            true,
+        );
+
+        assert!(description.starts_with(
+            "GET https://arcadia.digitalblasphemy.com/test.jpg?Expires=[REDACTED]&Signature=[REDACTED] ["
+        ));
+        assert!(!description.contains("abc"));
+        assert!(!description.contains("api_key"));
+    }
+
+    #[test]
+    fn describe_omits_headers_when_none_are_logged() {
+        let request = reqwest::Client::new()
+            .get("https://api.digitalblasphemy.com/v2/core/account")
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            describe(&request, false),
+            "GET https://api.digitalblasphemy.com/v2/core/account"
+        );
+    }
+}
@@ -0,0 +1,428 @@
+use crate::ClientError;
+use crate::atomic_write::write_atomically;
+use reqwest::header::{
+    ETAG, HeaderMap, HeaderValue, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED,
+};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// The endpoints whose responses can be cached.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CacheEndpoint {
+    /// `DigitalBlasphemyClient::get_wallpapers`.
+    Wallpapers,
+    /// `DigitalBlasphemyClient::get_wallpaper`.
+    Wallpaper,
+}
+
+impl CacheEndpoint {
+    const ALL: [CacheEndpoint; 2] = [CacheEndpoint::Wallpapers, CacheEndpoint::Wallpaper];
+
+    fn directory(&self) -> &'static str {
+        match self {
+            CacheEndpoint::Wallpapers => "wallpapers",
+            CacheEndpoint::Wallpaper => "wallpaper",
+        }
+    }
+}
+
+/// Stores API responses on disk so repeated calls within their TTL skip the network. If the API
+/// cannot be reached, expired entries are served instead and reported as [`Freshness::Stale`].
+/// Expired entries with an `ETag` or `Last-Modified` are revalidated with a conditional request.
+#[derive(Debug, Clone)]
+pub struct ResponseCache {
+    directory: PathBuf,
+    default_ttl: Duration,
+    ttls: HashMap<CacheEndpoint, Duration>,
+}
+
+impl ResponseCache {
+    /// Caches every endpoint for `default_ttl`. The directory is created when the first response
+    /// is stored.
+    pub fn new(directory: impl Into<PathBuf>, default_ttl: Duration) -> Self {
+        ResponseCache {
+            directory: directory.into(),
+            default_ttl,
+            ttls: HashMap::new(),
+        }
+    }
+
+    /// Overrides the TTL for `endpoint`. A zero TTL only uses entries as an offline fallback.
+    pub fn with_ttl(mut self, endpoint: CacheEndpoint, ttl: Duration) -> Self {
+        self.ttls.insert(endpoint, ttl);
+        self
+    }
+
+    pub fn ttl(&self, endpoint: CacheEndpoint) -> Duration {
+        self.ttls
+            .get(&endpoint)
+            .copied()
+            .unwrap_or(self.default_ttl)
+    }
+
+    pub(crate) fn entry_path(&self, endpoint: CacheEndpoint, key: &str) -> PathBuf {
+        let mut file_name = Sha256::digest(key.as_bytes())
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect::<String>();
+        file_name.push_str(".json");
+        self.directory.join(endpoint.directory()).join(file_name)
+    }
+
+    /// Returns `None` if there is no entry or it cannot be read.
+    pub(crate) async fn load(&self, entry_path: &Path) -> Option<CacheEntry> {
+        let entry = tokio::fs::read(entry_path).await.ok()?;
+        serde_json::from_slice(&entry).ok()
+    }
+
+    pub(crate) async fn store(
+        &self,
+        entry_path: &Path,
+        entry: &CacheEntry,
+    ) -> Result<(), ClientError> {
+        let entry = serde_json::to_vec(entry).map_err(std::io::Error::other)?;
+        if let Some(parent) = entry_path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+        write_atomically(entry_path, &entry).await
+    }
+
+    pub(crate) async fn remove(&self, entry_path: &Path) -> Result<(), ClientError> {
+        ignore_not_found(tokio::fs::remove_file(entry_path).await)
+    }
+
+    /// Removes every entry for `endpoint`, or for every endpoint when `endpoint` is `None`. Other
+    /// files in the cache directory are left alone.
+    pub(crate) async fn clear(&self, endpoint: Option<CacheEndpoint>) -> Result<(), ClientError> {
+        let endpoints = match &endpoint {
+            Some(endpoint) => std::slice::from_ref(endpoint),
+            None => &CacheEndpoint::ALL,
+        };
+        for endpoint in endpoints {
+            let directory = self.directory.join(endpoint.directory());
+            ignore_not_found(tokio::fs::remove_dir_all(directory).await)?;
+        }
+        Ok(())
+    }
+}
+
+/// Identifies a request by `path` and `query`. Query parameters are sorted, so their order does
+/// not affect the key, and the base URL is not part of it.
+pub(crate) fn cache_key(path: &str, query: &[(&str, String)]) -> String {
+    let mut query = query.to_vec();
+    query.sort();
+    url::form_urlencoded::Serializer::new(format!("{path}?"))
+        .extend_pairs(query)
+        .finish()
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+fn ignore_not_found(result: std::io::Result<()>) -> Result<(), ClientError> {
+    match result {
+        Err(error) if error.kind() != ErrorKind::NotFound => Err(error.into()),
+        _ => Ok(()),
+    }
+}
+
+/// A response body along with the validators the API sent for it.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub(crate) struct CacheEntry {
+    stored_at: u64,
+    pub(crate) body: String,
+    #[serde(default)]
+    etag: Option<String>,
+    #[serde(default)]
+    last_modified: Option<String>,
+}
+
+impl CacheEntry {
+    pub(crate) fn new(body: String, headers: &HeaderMap) -> Self {
+        CacheEntry {
+            stored_at: now(),
+            body,
+            etag: header(headers, ETAG),
+            last_modified: header(headers, LAST_MODIFIED),
+        }
+    }
+
+    pub(crate) fn age(&self) -> Duration {
+        Duration::from_secs(now().saturating_sub(self.stored_at))
+    }
+
+    pub(crate) fn has_validators(&self) -> bool {
+        self.etag.is_some() || self.last_modified.is_some()
+    }
+
+    /// `If-None-Match` and `If-Modified-Since` for the stored validators.
+    pub(crate) fn conditional_headers(&self) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        let validators = [
+            (IF_NONE_MATCH, &self.etag),
+            (IF_MODIFIED_SINCE, &self.last_modified),
+        ];
+        for (name, value) in validators {
+            if let Some(value) = value
+                && let Ok(value) = HeaderValue::from_str(value)
+            {
+                headers.insert(name, value);
+            }
+        }
+        headers
+    }
+
+    /// Restarts the TTL after a `304 Not Modified`, keeping any validators the API replaced.
+    pub(crate) fn revalidated(mut self, headers: &HeaderMap) -> Self {
+        self.stored_at = now();
+        self.etag = header(headers, ETAG).or(self.etag);
+        self.last_modified = header(headers, LAST_MODIFIED).or(self.last_modified);
+        self
+    }
+}
+
+fn header(headers: &HeaderMap, name: reqwest::header::HeaderName) -> Option<String> {
+    headers
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string)
+}
+
+/// Where a response came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Freshness {
+    /// Fetched from the API.
+    Network,
+    /// Read from the cache within its TTL.
+    Cached,
+    /// Read from the stored body after the API answered `304 Not Modified`.
+    NotModified,
+    /// Read from the cache after its TTL expired, because the API could not be reached.
+    Stale,
+}
+
+#[derive(Debug)]
+pub struct Cached<T> {
+    pub value: T,
+    pub freshness: Freshness,
+}
+
+impl<T> Cached<T> {
+    pub(crate) fn map<U>(self, f: impl FnOnce(T) -> U) -> Cached<U> {
+        Cached {
+            value: f(self.value),
+            freshness: self.freshness,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error;
+
+    fn entry(body: &str) -> CacheEntry {
+        CacheEntry::new(body.to_string(), &HeaderMap::new())
+    }
+
+    mod response_cache_test {
+        use super::*;
+
+        #[test]
+        fn test_response_cache_ttl_uses_endpoint_override() {
+            let cache = ResponseCache::new("cache", Duration::from_secs(60))
+                .with_ttl(CacheEndpoint::Wallpaper, Duration::from_secs(10));
+
+            assert_eq!(
+                cache.ttl(CacheEndpoint::Wallpapers),
+                Duration::from_secs(60)
+            );
+            assert_eq!(cache.ttl(CacheEndpoint::Wallpaper), Duration::from_secs(10));
+        }
+
+        #[test]
+        fn test_response_cache_entry_path_is_per_endpoint() {
+            let cache = ResponseCache::new("cache", Duration::ZERO);
+
+            let entry_path = cache.entry_path(CacheEndpoint::Wallpapers, "key");
+
+            assert!(entry_path.starts_with(Path::new("cache").join("wallpapers")));
+            assert_ne!(
+                entry_path,
+                cache.entry_path(CacheEndpoint::Wallpapers, "other")
+            );
+        }
+
+        #[tokio::test]
+        async fn test_response_cache_store_and_load() -> Result<(), Box<dyn Error>> {
+            let directory = tempfile::tempdir()?;
+            let cache = ResponseCache::new(directory.path(), Duration::ZERO);
+            let entry_path = cache.entry_path(CacheEndpoint::Wallpapers, "key");
+
+            cache.store(&entry_path, &entry("body")).await?;
+
+            let entry = cache.load(&entry_path).await.unwrap();
+            assert_eq!(entry.body, "body");
+            assert!(entry.age() < Duration::from_secs(5));
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn test_response_cache_loads_entries_without_validators() -> Result<(), Box<dyn Error>>
+        {
+            let directory = tempfile::tempdir()?;
+            let cache = ResponseCache::new(directory.path(), Duration::ZERO);
+            let entry_path = cache.entry_path(CacheEndpoint::Wallpapers, "key");
+            tokio::fs::create_dir_all(entry_path.parent().unwrap()).await?;
+            tokio::fs::write(&entry_path, r#"{"stored_at": 0, "body": "body"}"#).await?;
+
+            let entry = cache.load(&entry_path).await.unwrap();
+
+            assert!(!entry.has_validators());
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn test_response_cache_clear_only_removes_endpoint() -> Result<(), Box<dyn Error>> {
+            let directory = tempfile::tempdir()?;
+            let cache = ResponseCache::new(directory.path(), Duration::ZERO);
+            let wallpapers = cache.entry_path(CacheEndpoint::Wallpapers, "key");
+            let wallpaper = cache.entry_path(CacheEndpoint::Wallpaper, "key");
+            cache.store(&wallpapers, &entry("body")).await?;
+            cache.store(&wallpaper, &entry("body")).await?;
+
+            cache.clear(Some(CacheEndpoint::Wallpapers)).await?;
+
+            assert!(cache.load(&wallpapers).await.is_none());
+            assert!(cache.load(&wallpaper).await.is_some());
+
+            cache.clear(None).await?;
+            cache.clear(None).await?;
+            cache.remove(&wallpaper).await?;
+
+            assert!(cache.load(&wallpaper).await.is_none());
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn test_response_cache_clear_keeps_files_outside_endpoint_directories()
+        -> Result<(), Box<dyn Error>> {
+            let directory = tempfile::tempdir()?;
+            let cache = ResponseCache::new(directory.path(), Duration::ZERO);
+            let wallpapers = cache.entry_path(CacheEndpoint::Wallpapers, "key");
+            let wallpaper = cache.entry_path(CacheEndpoint::Wallpaper, "key");
+            cache.store(&wallpapers, &entry("body")).await?;
+            cache.store(&wallpaper, &entry("body")).await?;
+            let other = directory.path().join("notes.txt");
+            tokio::fs::write(&other, "keep").await?;
+
+            cache.clear(None).await?;
+
+            assert!(cache.load(&wallpapers).await.is_none());
+            assert!(cache.load(&wallpaper).await.is_none());
+            assert_eq!(tokio::fs::read_to_string(&other).await?, "keep");
+
+            Ok(())
+        }
+    }
+
+    mod cache_key_test {
+        use super::*;
+
+        #[test]
+        fn test_cache_key_ignores_query_order() {
+            assert_eq!(
+                cache_key(
+                    "/v2/core/wallpapers",
+                    &[("page", "1".to_string()), ("limit", "10".to_string())]
+                ),
+                cache_key(
+                    "/v2/core/wallpapers",
+                    &[("limit", "10".to_string()), ("page", "1".to_string())]
+                )
+            );
+        }
+
+        #[test]
+        fn test_cache_key_differs_by_query_and_path() {
+            let query = [("page", "1".to_string())];
+
+            let key = cache_key("/v2/core/wallpaper/1", &query);
+
+            assert_ne!(key, cache_key("/v2/core/wallpaper/2", &query));
+            assert_ne!(
+                key,
+                cache_key("/v2/core/wallpaper/1", &[("page", "2".to_string())])
+            );
+        }
+    }
+
+    mod cache_entry_test {
+        use super::*;
+
+        fn headers(etag: Option<&str>, last_modified: Option<&str>) -> HeaderMap {
+            let mut headers = HeaderMap::new();
+            if let Some(etag) = etag {
+                headers.insert(ETAG, HeaderValue::from_str(etag).unwrap());
+            }
+            if let Some(last_modified) = last_modified {
+                headers.insert(LAST_MODIFIED, HeaderValue::from_str(last_modified).unwrap());
+            }
+            headers
+        }
+
+        #[test]
+        fn test_cache_entry_conditional_headers() {
+            let entry = CacheEntry::new(
+                "body".to_string(),
+                &headers(Some("\"v1\""), Some("Tue, 14 Nov 2023 22:13:20 GMT")),
+            );
+
+            let conditional_headers = entry.conditional_headers();
+
+            assert!(entry.has_validators());
+            assert_eq!(conditional_headers[IF_NONE_MATCH], "\"v1\"");
+            assert_eq!(
+                conditional_headers[IF_MODIFIED_SINCE],
+                "Tue, 14 Nov 2023 22:13:20 GMT"
+            );
+        }
+
+        #[test]
+        fn test_cache_entry_without_validators_sends_no_conditional_headers() {
+            let entry = entry("body");
+
+            assert!(!entry.has_validators());
+            assert!(entry.conditional_headers().is_empty());
+        }
+
+        #[test]
+        fn test_cache_entry_revalidated_keeps_unreplaced_validators() {
+            let entry = CacheEntry::new(
+                "body".to_string(),
+                &headers(Some("\"v1\""), Some("Tue, 14 Nov 2023 22:13:20 GMT")),
+            );
+
+            let entry = entry.revalidated(&headers(Some("\"v2\""), None));
+
+            let conditional_headers = entry.conditional_headers();
+            assert_eq!(entry.body, "body");
+            assert_eq!(conditional_headers[IF_NONE_MATCH], "\"v2\"");
+            assert_eq!(
+                conditional_headers[IF_MODIFIED_SINCE],
+                "Tue, 14 Nov 2023 22:13:20 GMT"
+            );
+        }
+    }
+}
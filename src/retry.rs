@@ -0,0 +1,383 @@
+use reqwest::header::RETRY_AFTER;
+use reqwest::{Response, StatusCode};
+use std::time::{Duration, SystemTime};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RetryableError {
+    /// The request timed out.
+    Timeout,
+    /// A connection to the server could not be established.
+    Connect,
+    /// The connection failed while sending the request, e.g. it was reset.
+    Request,
+}
+
+impl RetryableError {
+    fn matches(&self, error: &reqwest::Error) -> bool {
+        match self {
+            RetryableError::Timeout => error.is_timeout(),
+            RetryableError::Connect => error.is_connect(),
+            RetryableError::Request => error.is_request() && !error.is_timeout(),
+        }
+    }
+}
+
+/// Built with [`RetryPolicy::builder`], which validates every setting.
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    multiplier: f64,
+    jitter: bool,
+    /// The longest `Retry-After` to wait for. Longer requests end the retries instead.
+    max_retry_after: Duration,
+    retry_statuses: Vec<StatusCode>,
+    retry_errors: Vec<RetryableError>,
+}
+
+impl RetryPolicy {
+    pub fn builder() -> RetryPolicyBuilder {
+        RetryPolicyBuilder::new()
+    }
+
+    /// A policy that sends every request exactly once.
+    pub fn none() -> Self {
+        RetryPolicy {
+            max_attempts: 1,
+            ..RetryPolicyBuilder::new().build()
+        }
+    }
+
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    pub fn initial_backoff(&self) -> Duration {
+        self.initial_backoff
+    }
+
+    pub fn max_backoff(&self) -> Duration {
+        self.max_backoff
+    }
+
+    pub fn multiplier(&self) -> f64 {
+        self.multiplier
+    }
+
+    pub fn jitter(&self) -> bool {
+        self.jitter
+    }
+
+    pub fn max_retry_after(&self) -> Duration {
+        self.max_retry_after
+    }
+
+    pub fn retry_statuses(&self) -> &[StatusCode] {
+        &self.retry_statuses
+    }
+
+    pub fn retry_errors(&self) -> &[RetryableError] {
+        &self.retry_errors
+    }
+
+    pub(crate) fn should_retry_status(&self, status: StatusCode) -> bool {
+        self.retry_statuses.contains(&status)
+    }
+
+    pub(crate) fn should_retry_error(&self, error: &reqwest::Error) -> bool {
+        self.retry_errors.iter().any(|kind| kind.matches(error))
+    }
+
+    /// The delay before the attempt following `attempt` (1-based), before any jitter is applied.
+    /// Falls back to `max_backoff` when the product overflows or is not a number.
+    pub(crate) fn backoff(&self, attempt: u32) -> Duration {
+        let factor = self
+            .multiplier
+            .powi(attempt.saturating_sub(1).min(i32::MAX as u32) as i32);
+        Duration::try_from_secs_f64(self.initial_backoff.as_secs_f64() * factor)
+            .unwrap_or(self.max_backoff)
+            .min(self.max_backoff)
+    }
+
+    /// Returns `None` when the server asks to wait longer than `max_retry_after`.
+    pub(crate) fn delay(&self, attempt: u32, response: Option<&Response>) -> Option<Duration> {
+        let retry_after = response
+            .filter(|response| {
+                matches!(
+                    response.status(),
+                    StatusCode::TOO_MANY_REQUESTS | StatusCode::SERVICE_UNAVAILABLE
+                )
+            })
+            .and_then(retry_after);
+        if let Some(retry_after) = retry_after {
+            return (retry_after <= self.max_retry_after).then_some(retry_after);
+        }
+        let backoff = self.backoff(attempt);
+        if self.jitter {
+            Some(backoff.mul_f64(0.5 + fastrand::f64() * 0.5))
+        } else {
+            Some(backoff)
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicyBuilder::new().build()
+    }
+}
+
+#[derive(Clone)]
+pub struct RetryPolicyBuilder {
+    retry_policy: RetryPolicy,
+}
+
+impl RetryPolicyBuilder {
+    pub(crate) fn new() -> Self {
+        RetryPolicyBuilder {
+            retry_policy: RetryPolicy {
+                max_attempts: 3,
+                initial_backoff: Duration::from_millis(200),
+                max_backoff: Duration::from_secs(10),
+                multiplier: 2_f64,
+                jitter: true,
+                max_retry_after: Duration::from_secs(60),
+                retry_statuses: vec![
+                    StatusCode::TOO_MANY_REQUESTS,
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    StatusCode::BAD_GATEWAY,
+                    StatusCode::SERVICE_UNAVAILABLE,
+                    StatusCode::GATEWAY_TIMEOUT,
+                ],
+                retry_errors: vec![
+                    RetryableError::Timeout,
+                    RetryableError::Connect,
+                    RetryableError::Request,
+                ],
+            },
+        }
+    }
+
+    pub fn build(self) -> RetryPolicy {
+        self.retry_policy
+    }
+
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        if max_attempts < 1 {
+            panic!("Max attempts must be greater than 0.");
+        }
+        self.retry_policy.max_attempts = max_attempts;
+        self
+    }
+
+    pub fn initial_backoff(mut self, initial_backoff: Duration) -> Self {
+        self.retry_policy.initial_backoff = initial_backoff;
+        self
+    }
+
+    pub fn max_backoff(mut self, max_backoff: Duration) -> Self {
+        self.retry_policy.max_backoff = max_backoff;
+        self
+    }
+
+    pub fn multiplier(mut self, multiplier: f64) -> Self {
+        if !multiplier.is_finite() || multiplier < 1_f64 {
+            panic!("Multiplier must be a finite number of at least 1.");
+        }
+        self.retry_policy.multiplier = multiplier;
+        self
+    }
+
+    pub fn jitter(mut self, jitter: bool) -> Self {
+        self.retry_policy.jitter = jitter;
+        self
+    }
+
+    pub fn max_retry_after(mut self, max_retry_after: Duration) -> Self {
+        self.retry_policy.max_retry_after = max_retry_after;
+        self
+    }
+
+    pub fn retry_statuses(mut self, retry_statuses: Vec<StatusCode>) -> Self {
+        self.retry_policy.retry_statuses = retry_statuses;
+        self
+    }
+
+    pub fn retry_errors(mut self, retry_errors: Vec<RetryableError>) -> Self {
+        self.retry_policy.retry_errors = retry_errors;
+        self
+    }
+}
+
+/// Reads `Retry-After` as either a number of seconds or an HTTP date.
+pub(crate) fn retry_after(response: &Response) -> Option<Duration> {
+    let retry_after = response.headers().get(RETRY_AFTER)?.to_str().ok()?;
+    parse_retry_after(retry_after, SystemTime::now())
+}
+
+fn parse_retry_after(retry_after: &str, now: SystemTime) -> Option<Duration> {
+    if let Ok(seconds) = retry_after.trim().parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = httpdate::parse_http_date(retry_after).ok()?;
+    Some(date.duration_since(now).unwrap_or(Duration::ZERO))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod retry_policy_test {
+        use super::*;
+
+        #[test]
+        fn retry_policy_none_only_attempts_once() {
+            assert_eq!(RetryPolicy::none().max_attempts, 1);
+        }
+
+        #[test]
+        fn retry_policy_backoff_grows_exponentially() {
+            let retry_policy = RetryPolicy::builder()
+                .initial_backoff(Duration::from_millis(100))
+                .multiplier(3_f64)
+                .build();
+
+            assert_eq!(retry_policy.backoff(1), Duration::from_millis(100));
+            assert_eq!(retry_policy.backoff(2), Duration::from_millis(300));
+            assert_eq!(retry_policy.backoff(3), Duration::from_millis(900));
+        }
+
+        #[test]
+        fn retry_policy_backoff_is_capped_at_max_backoff() {
+            let retry_policy = RetryPolicy::builder()
+                .initial_backoff(Duration::from_secs(1))
+                .max_backoff(Duration::from_secs(5))
+                .build();
+
+            assert_eq!(retry_policy.backoff(10), Duration::from_secs(5));
+        }
+
+        #[test]
+        fn retry_policy_backoff_falls_back_to_max_backoff_on_overflow() {
+            let retry_policy = RetryPolicy::builder()
+                .max_attempts(u32::MAX)
+                .multiplier(10_f64)
+                .max_backoff(Duration::from_secs(5))
+                .build();
+
+            assert_eq!(retry_policy.backoff(25), Duration::from_secs(5));
+            assert_eq!(retry_policy.backoff(u32::MAX), Duration::from_secs(5));
+        }
+
+        #[test]
+        fn retry_policy_backoff_falls_back_to_max_backoff_for_nan_multiplier() {
+            let retry_policy = RetryPolicy {
+                multiplier: f64::NAN,
+                ..RetryPolicy::default()
+            };
+
+            assert_eq!(retry_policy.backoff(2), retry_policy.max_backoff);
+        }
+
+        #[test]
+        fn retry_policy_delay_with_jitter_stays_within_backoff() {
+            let retry_policy = RetryPolicy::builder()
+                .initial_backoff(Duration::from_millis(100))
+                .build();
+
+            for _ in 0..100 {
+                let delay = retry_policy.delay(1, None).unwrap();
+                assert!(delay >= Duration::from_millis(50));
+                assert!(delay <= Duration::from_millis(100));
+            }
+        }
+
+        #[test]
+        fn retry_policy_delay_without_jitter_is_backoff() {
+            let retry_policy = RetryPolicy::builder()
+                .initial_backoff(Duration::from_millis(100))
+                .jitter(false)
+                .build();
+
+            assert_eq!(
+                retry_policy.delay(2, None),
+                Some(Duration::from_millis(200))
+            );
+        }
+
+        #[test]
+        fn retry_policy_retries_default_statuses() {
+            let retry_policy = RetryPolicy::default();
+
+            assert!(retry_policy.should_retry_status(StatusCode::TOO_MANY_REQUESTS));
+            assert!(retry_policy.should_retry_status(StatusCode::SERVICE_UNAVAILABLE));
+            assert!(!retry_policy.should_retry_status(StatusCode::NOT_FOUND));
+        }
+    }
+
+    mod retry_policy_builder_test {
+        use super::*;
+
+        #[test]
+        #[should_panic(expected = "Max attempts must be greater than 0.")]
+        fn retry_policy_builder_max_attempts_rejects_0() {
+            RetryPolicy::builder().max_attempts(0);
+        }
+
+        #[test]
+        #[should_panic(expected = "Multiplier must be a finite number of at least 1.")]
+        fn retry_policy_builder_multiplier_rejects_less_than_1() {
+            RetryPolicy::builder().multiplier(0.5);
+        }
+
+        #[test]
+        #[should_panic(expected = "Multiplier must be a finite number of at least 1.")]
+        fn retry_policy_builder_multiplier_rejects_nan() {
+            RetryPolicy::builder().multiplier(f64::NAN);
+        }
+
+        #[test]
+        #[should_panic(expected = "Multiplier must be a finite number of at least 1.")]
+        fn retry_policy_builder_multiplier_rejects_infinity() {
+            RetryPolicy::builder().multiplier(f64::INFINITY);
+        }
+    }
+
+    mod parse_retry_after_test {
+        use super::*;
+
+        #[test]
+        fn parse_retry_after_accepts_seconds() {
+            assert_eq!(
+                parse_retry_after("120", SystemTime::now()),
+                Some(Duration::from_secs(120))
+            );
+        }
+
+        #[test]
+        fn parse_retry_after_accepts_http_date() {
+            let now = httpdate::parse_http_date("Wed, 21 Oct 2015 07:28:00 GMT").unwrap();
+
+            assert_eq!(
+                parse_retry_after("Wed, 21 Oct 2015 07:28:30 GMT", now),
+                Some(Duration::from_secs(30))
+            );
+        }
+
+        #[test]
+        fn parse_retry_after_treats_past_http_date_as_zero() {
+            let now = httpdate::parse_http_date("Wed, 21 Oct 2015 07:28:30 GMT").unwrap();
+
+            assert_eq!(
+                parse_retry_after("Wed, 21 Oct 2015 07:28:00 GMT", now),
+                Some(Duration::ZERO)
+            );
+        }
+
+        #[test]
+        fn parse_retry_after_rejects_invalid_value() {
+            assert_eq!(parse_retry_after("soon", SystemTime::now()), None);
+        }
+    }
+}
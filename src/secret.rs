@@ -0,0 +1,41 @@
+use std::fmt;
+use zeroize::Zeroizing;
+
+/// Holds a credential that is wiped from memory on drop and never shown in `Debug` output.
+#[derive(Clone)]
+pub(crate) struct Secret(Zeroizing<String>);
+
+impl Secret {
+    pub(crate) fn new(value: String) -> Self {
+        Secret(Zeroizing::new(value))
+    }
+
+    pub(crate) fn expose(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Debug for Secret {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Secret([REDACTED])")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn secret_debug_does_not_contain_value() {
+        let secret = Secret::new("api_key".to_string());
+
+        assert_eq!(format!("{secret:?}"), "Secret([REDACTED])");
+    }
+
+    #[test]
+    fn secret_expose_returns_value() {
+        let secret = Secret::new("api_key".to_string());
+
+        assert_eq!(secret.expose(), "api_key");
+    }
+}
@@ -0,0 +1,576 @@
+use crate::atomic_write::write_atomically;
+use crate::{
+    ClientError, DigitalBlasphemyClient, DownloadWallpaperRequest, FitPolicy, GetWallpapersRequest,
+    Resolution, Wallpaper, WallpaperId, WallpaperType,
+};
+use futures::{StreamExt, TryStreamExt, stream};
+use log::{debug, warn};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+const MANIFEST_FILE_NAME: &str = ".manifest.json";
+
+/// A wallpaper type and display size to mirror.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SyncTarget {
+    pub wallpaper_type: WallpaperType,
+    pub width: u32,
+    pub height: u32,
+    pub fit_policy: FitPolicy,
+}
+
+impl SyncTarget {
+    /// Mirrors resolutions that exactly match `width` by `height`.
+    pub fn new(wallpaper_type: WallpaperType, width: u32, height: u32) -> Self {
+        SyncTarget {
+            wallpaper_type,
+            width,
+            height,
+            fit_policy: FitPolicy::Exact,
+        }
+    }
+
+    pub fn fit_policy(mut self, fit_policy: FitPolicy) -> Self {
+        self.fit_policy = fit_policy;
+        self
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SyncOptions {
+    pub root: PathBuf,
+    /// Selects which wallpapers to mirror. Resolutions are always requested.
+    pub request: GetWallpapersRequest,
+    pub targets: Vec<SyncTarget>,
+    /// The maximum number of downloads in flight.
+    pub concurrency: usize,
+}
+
+impl SyncOptions {
+    pub fn new(
+        root: impl Into<PathBuf>,
+        request: GetWallpapersRequest,
+        targets: Vec<SyncTarget>,
+    ) -> Self {
+        SyncOptions {
+            root: root.into(),
+            request,
+            targets,
+            concurrency: 4,
+        }
+    }
+
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency;
+        self
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct SyncReport {
+    /// Files that were missing or outdated and have been downloaded.
+    pub downloaded: Vec<PathBuf>,
+    /// Files already present at the version recorded in the manifest.
+    pub up_to_date: Vec<PathBuf>,
+    /// Downloads that failed. They are retried on the next run.
+    pub failed: Vec<(PathBuf, ClientError)>,
+    /// Wallpapers with no resolution matching a target.
+    pub unavailable: Vec<(WallpaperId, SyncTarget)>,
+}
+
+/// Records what was downloaded to each path, relative to the sync root, so reruns only fetch
+/// files that are missing or whose wallpaper has changed since.
+#[derive(Serialize, Deserialize, Debug, Default, PartialEq)]
+pub(crate) struct SyncManifest {
+    files: BTreeMap<String, ManifestEntry>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+struct ManifestEntry {
+    wallpaper_id: WallpaperId,
+    timestamp: Option<i64>,
+}
+
+impl SyncManifest {
+    async fn load(root: &Path) -> Result<SyncManifest, ClientError> {
+        match tokio::fs::read_to_string(root.join(MANIFEST_FILE_NAME)).await {
+            Ok(manifest) => serde_json::from_str(&manifest).map_err(|source| ClientError::Decode {
+                body: manifest,
+                source,
+            }),
+            Err(error) if error.kind() == ErrorKind::NotFound => Ok(SyncManifest::default()),
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    async fn save(&self, root: &Path) -> Result<(), ClientError> {
+        let manifest = serde_json::to_vec_pretty(self).map_err(std::io::Error::other)?;
+        write_atomically(&root.join(MANIFEST_FILE_NAME), &manifest).await
+    }
+}
+
+struct SyncJob {
+    key: String,
+    path: PathBuf,
+    request: DownloadWallpaperRequest,
+    entry: ManifestEntry,
+}
+
+impl DigitalBlasphemyClient {
+    /// Mirrors every wallpaper matching `options.request` into `options.root`, laid out as
+    /// `<type>/<width>x<height>/<file name>`. Only files that are missing, or whose wallpaper
+    /// timestamp differs from the manifest, are downloaded. Failed downloads are reported rather
+    /// than aborting the sync. The manifest is saved as downloads complete, so a sync that is
+    /// interrupted resumes where it stopped.
+    pub async fn sync(&self, options: &SyncOptions) -> Result<SyncReport, ClientError> {
+        tokio::fs::create_dir_all(&options.root).await?;
+        let mut manifest = SyncManifest::load(&options.root).await?;
+
+        let mut request = options.request.clone();
+        request.show_resolutions = true;
+        let wallpapers: Vec<Wallpaper> = self.wallpapers_stream(&request).try_collect().await?;
+
+        let mut report = SyncReport::default();
+        let mut jobs = vec![];
+        let mut planned = HashSet::new();
+        for wallpaper in &wallpapers {
+            for target in &options.targets {
+                let Some((resolution, request)) = wallpaper.best_resolution(
+                    target.width,
+                    target.height,
+                    target.wallpaper_type.clone(),
+                    target.fit_policy,
+                ) else {
+                    report.unavailable.push((wallpaper.id, target.clone()));
+                    continue;
+                };
+                let key = relative_path(wallpaper, &target.wallpaper_type, &resolution);
+                if !planned.insert(key.clone()) {
+                    continue;
+                }
+                let path = options.root.join(&key);
+                let entry = ManifestEntry {
+                    wallpaper_id: wallpaper.id,
+                    timestamp: wallpaper.timestamp,
+                };
+                if manifest.files.get(&key) == Some(&entry) && tokio::fs::try_exists(&path).await? {
+                    report.up_to_date.push(path);
+                    continue;
+                }
+                jobs.push(SyncJob {
+                    key,
+                    path,
+                    request,
+                    entry,
+                });
+            }
+        }
+        debug!(
+            "Syncing {} files, {} up to date",
+            jobs.len(),
+            report.up_to_date.len()
+        );
+
+        let concurrency = options.concurrency.max(1);
+        let mut results = stream::iter(jobs)
+            .map(|job| async move {
+                let result = self.download_sync_job(&job).await;
+                (job, result)
+            })
+            .buffer_unordered(concurrency);
+        // Saving once per round of concurrent downloads bounds the work an interrupted sync repeats.
+        let mut unsaved = 0;
+        while let Some((job, result)) = results.next().await {
+            match result {
+                Ok(()) => {
+                    manifest.files.insert(job.key, job.entry);
+                    report.downloaded.push(job.path);
+                    unsaved += 1;
+                    if unsaved == concurrency {
+                        manifest.save(&options.root).await?;
+                        unsaved = 0;
+                    }
+                }
+                Err(error) => {
+                    warn!("Unable to sync {}: {error}", job.path.display());
+                    report.failed.push((job.path, error));
+                }
+            }
+        }
+
+        manifest.save(&options.root).await?;
+        Ok(report)
+    }
+
+    async fn download_sync_job(&self, job: &SyncJob) -> Result<(), ClientError> {
+        if let Some(directory) = job.path.parent() {
+            tokio::fs::create_dir_all(directory).await?;
+        }
+        self.download_wallpaper(&job.path, &job.request).await
+    }
+}
+
+/// Uses the file name from the resolution's image path, falling back to one built from the
+/// wallpaper ID when the path has none.
+fn relative_path(
+    wallpaper: &Wallpaper,
+    wallpaper_type: &WallpaperType,
+    resolution: &Resolution,
+) -> String {
+    let file_name = Path::new(&resolution.image)
+        .file_name()
+        .map(|file_name| file_name.to_string_lossy().to_string())
+        .unwrap_or_else(|| {
+            format!(
+                "{}_{}_{}x{}.jpg",
+                wallpaper.id,
+                wallpaper_type.as_str(),
+                resolution.width,
+                resolution.height
+            )
+        });
+    format!(
+        "{}/{}x{}/{}",
+        wallpaper_type.as_str(),
+        resolution.width,
+        resolution.height,
+        file_name
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{Matcher, Mock, ServerGuard};
+    use std::error::Error;
+    use std::fs;
+
+    async fn mock_wallpapers(server: &mut ServerGuard) -> Mock {
+        server
+            .mock(
+                "GET",
+                Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
+            )
+            .match_query(Matcher::UrlEncoded(
+                "show_resolutions".to_string(),
+                "true".to_string(),
+            ))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                fs::read_to_string("resources/get_wallpapers_success_with_resolutions.json")
+                    .unwrap(),
+            )
+            .create_async()
+            .await
+    }
+
+    async fn mock_download(server: &mut ServerGuard, id: u32, body: &str, hits: usize) -> Mock {
+        let url = format!("{}/{id}.jpg", server.url());
+        server
+            .mock(
+                "GET",
+                Matcher::Regex(format!(
+                    r"^/v2/core/download/wallpaper/single/1920/1080/{id}.*$"
+                )),
+            )
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                fs::read_to_string("resources/download_wallpaper_success_minimal_populated.json")
+                    .unwrap()
+                    .replace("{{host}}/test.jpg", &url),
+            )
+            .expect(hits)
+            .create_async()
+            .await;
+        server
+            .mock("GET", format!("/{id}.jpg").as_str())
+            .with_status(200)
+            .with_body(body)
+            .expect(hits)
+            .create_async()
+            .await
+    }
+
+    fn options(root: &Path) -> SyncOptions {
+        SyncOptions::new(
+            root,
+            GetWallpapersRequest::builder()
+                .show_resolutions(false)
+                .build(),
+            vec![SyncTarget::new(WallpaperType::Single, 1920, 1080)],
+        )
+        .concurrency(2)
+    }
+
+    fn client(server: &ServerGuard) -> Result<DigitalBlasphemyClient, ClientError> {
+        DigitalBlasphemyClient::builder("api_key".to_string())
+            .base_url(server.url())
+            .build()
+    }
+
+    mod sync_test {
+        use super::*;
+
+        #[tokio::test]
+        async fn sync_downloads_missing_files_and_writes_manifest() -> Result<(), Box<dyn Error>> {
+            let mut server = mockito::Server::new_async().await;
+            let root = tempfile::tempdir()?;
+
+            let wallpapers_mock = mock_wallpapers(&mut server).await;
+            let afterglow_mock = mock_download(&mut server, 1, "afterglow", 1).await;
+            let boreal_mock = mock_download(&mut server, 2, "boreal", 1).await;
+
+            let report = client(&server)?.sync(&options(root.path())).await?;
+
+            assert_eq!(report.downloaded.len(), 2);
+            assert!(report.failed.is_empty());
+            assert_eq!(
+                fs::read_to_string(
+                    root.path()
+                        .join("single/1920x1080/afterglow_single_1920x1080.jpg")
+                )?,
+                "afterglow"
+            );
+            assert_eq!(
+                fs::read_to_string(
+                    root.path()
+                        .join("single/1920x1080/boreal_single_1920x1080.jpg")
+                )?,
+                "boreal"
+            );
+            let manifest: SyncManifest =
+                serde_json::from_str(&fs::read_to_string(root.path().join(MANIFEST_FILE_NAME))?)?;
+            assert_eq!(
+                manifest.files["single/1920x1080/boreal_single_1920x1080.jpg"],
+                ManifestEntry {
+                    wallpaper_id: WallpaperId(2),
+                    timestamp: Some(200),
+                }
+            );
+
+            wallpapers_mock.assert_async().await;
+            afterglow_mock.assert_async().await;
+            boreal_mock.assert_async().await;
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn sync_is_incremental() -> Result<(), Box<dyn Error>> {
+            let mut server = mockito::Server::new_async().await;
+            let root = tempfile::tempdir()?;
+
+            mock_wallpapers(&mut server).await;
+            let afterglow_mock = mock_download(&mut server, 1, "afterglow", 1).await;
+            let boreal_mock = mock_download(&mut server, 2, "boreal", 1).await;
+            let client = client(&server)?;
+
+            client.sync(&options(root.path())).await?;
+            let report = client.sync(&options(root.path())).await?;
+
+            assert!(report.downloaded.is_empty());
+            assert_eq!(report.up_to_date.len(), 2);
+
+            afterglow_mock.assert_async().await;
+            boreal_mock.assert_async().await;
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn sync_redownloads_outdated_and_deleted_files() -> Result<(), Box<dyn Error>> {
+            let mut server = mockito::Server::new_async().await;
+            let root = tempfile::tempdir()?;
+
+            mock_wallpapers(&mut server).await;
+            let afterglow_mock = mock_download(&mut server, 1, "afterglow", 2).await;
+            let boreal_mock = mock_download(&mut server, 2, "boreal", 2).await;
+            let client = client(&server)?;
+
+            client.sync(&options(root.path())).await?;
+            let mut manifest: SyncManifest =
+                serde_json::from_str(&fs::read_to_string(root.path().join(MANIFEST_FILE_NAME))?)?;
+            manifest
+                .files
+                .get_mut("single/1920x1080/afterglow_single_1920x1080.jpg")
+                .unwrap()
+                .timestamp = Some(1);
+            fs::write(
+                root.path().join(MANIFEST_FILE_NAME),
+                serde_json::to_string(&manifest)?,
+            )?;
+            fs::remove_file(
+                root.path()
+                    .join("single/1920x1080/boreal_single_1920x1080.jpg"),
+            )?;
+
+            let report = client.sync(&options(root.path())).await?;
+
+            assert_eq!(report.downloaded.len(), 2);
+            assert!(report.up_to_date.is_empty());
+
+            afterglow_mock.assert_async().await;
+            boreal_mock.assert_async().await;
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn sync_reports_failed_downloads_and_retries_them() -> Result<(), Box<dyn Error>> {
+            let mut server = mockito::Server::new_async().await;
+            let root = tempfile::tempdir()?;
+
+            mock_wallpapers(&mut server).await;
+            mock_download(&mut server, 1, "afterglow", 1).await;
+            let failed_mock = server
+                .mock(
+                    "GET",
+                    Matcher::Regex(
+                        r"^/v2/core/download/wallpaper/single/1920/1080/2.*$".to_string(),
+                    ),
+                )
+                .with_status(500)
+                .expect(1)
+                .create_async()
+                .await;
+            let client = client(&server)?;
+
+            let report = client.sync(&options(root.path())).await?;
+
+            assert_eq!(report.downloaded.len(), 1);
+            assert_eq!(report.failed.len(), 1);
+            assert!(matches!(report.failed[0].1, ClientError::Api(_)));
+
+            failed_mock.assert_async().await;
+            let boreal_mock = mock_download(&mut server, 2, "boreal", 1).await;
+
+            let report = client.sync(&options(root.path())).await?;
+
+            assert_eq!(report.downloaded.len(), 1);
+            assert_eq!(report.up_to_date.len(), 1);
+
+            boreal_mock.assert_async().await;
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn sync_resumes_after_interrupted_run() -> Result<(), Box<dyn Error>> {
+            let mut server = mockito::Server::new_async().await;
+            let root = tempfile::tempdir()?;
+            let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await?;
+
+            mock_wallpapers(&mut server).await;
+            let afterglow_mock = mock_download(&mut server, 1, "afterglow", 1).await;
+            let stalled_mock = server
+                .mock(
+                    "GET",
+                    Matcher::Regex(
+                        r"^/v2/core/download/wallpaper/single/1920/1080/2.*$".to_string(),
+                    ),
+                )
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(
+                    fs::read_to_string(
+                        "resources/download_wallpaper_success_minimal_populated.json",
+                    )?
+                    .replace(
+                        "{{host}}/test.jpg",
+                        &format!("http://{}/2.jpg", listener.local_addr()?),
+                    ),
+                )
+                .expect(1)
+                .create_async()
+                .await;
+            let client = client(&server)?;
+            let options = options(root.path()).concurrency(1);
+
+            // Stop the first run once the second download has connected and will never finish.
+            tokio::select! {
+                _ = client.sync(&options) => panic!("sync finished despite a stalled download"),
+                accepted = listener.accept() => {
+                    accepted?;
+                }
+            }
+
+            let manifest: SyncManifest =
+                serde_json::from_str(&fs::read_to_string(root.path().join(MANIFEST_FILE_NAME))?)?;
+            assert_eq!(
+                manifest.files.keys().collect::<Vec<_>>(),
+                vec!["single/1920x1080/afterglow_single_1920x1080.jpg"]
+            );
+
+            stalled_mock.assert_async().await;
+            stalled_mock.remove_async().await;
+            let boreal_mock = mock_download(&mut server, 2, "boreal", 1).await;
+
+            let report = client.sync(&options).await?;
+
+            assert_eq!(report.downloaded.len(), 1);
+            assert_eq!(report.up_to_date.len(), 1);
+
+            afterglow_mock.assert_async().await;
+            boreal_mock.assert_async().await;
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn sync_reports_unavailable_targets() -> Result<(), Box<dyn Error>> {
+            let mut server = mockito::Server::new_async().await;
+            let root = tempfile::tempdir()?;
+
+            mock_wallpapers(&mut server).await;
+            let mut options = options(root.path());
+            options.targets = vec![SyncTarget::new(WallpaperType::Single, 2560, 1440)];
+            let download_mock = server
+                .mock(
+                    "GET",
+                    Matcher::Regex(
+                        r"^/v2/core/download/wallpaper/single/2560/1440/2.*$".to_string(),
+                    ),
+                )
+                .with_status(500)
+                .create_async()
+                .await;
+
+            let report = client(&server)?.sync(&options).await?;
+
+            assert_eq!(report.unavailable.len(), 1);
+            assert_eq!(report.unavailable[0].0, WallpaperId(1));
+            assert_eq!(report.failed.len(), 1);
+
+            download_mock.assert_async().await;
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn sync_fails_without_touching_manifest_when_listing_fails()
+        -> Result<(), Box<dyn Error>> {
+            let mut server = mockito::Server::new_async().await;
+            let root = tempfile::tempdir()?;
+
+            server
+                .mock(
+                    "GET",
+                    Matcher::Regex(r"^/v2/core/wallpapers.*$".to_string()),
+                )
+                .with_status(401)
+                .create_async()
+                .await;
+
+            let result = client(&server)?.sync(&options(root.path())).await;
+
+            assert!(matches!(result, Err(ClientError::Unauthorized(_))));
+            assert!(!root.path().join(MANIFEST_FILE_NAME).exists());
+
+            Ok(())
+        }
+    }
+}
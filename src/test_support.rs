@@ -0,0 +1,47 @@
+use crate::model::{GetWallpaperResponse, Resolution, Resolutions, Wallpaper, WallpaperId};
+use std::fs;
+
+pub(crate) fn resolution(width: u32, height: u32) -> Resolution {
+    Resolution {
+        label: format!("{width}x{height}"),
+        width,
+        height,
+        image: format!("/single/{width}x{height}/vulcan_single_{width}x{height}.jpg"),
+    }
+}
+
+/// Empty `dual` or `triple` sizes are left as `None`.
+pub(crate) fn resolutions(
+    single: &[(u32, u32)],
+    dual: &[(u32, u32)],
+    triple: &[(u32, u32)],
+) -> Resolutions {
+    let to_resolutions = |sizes: &[(u32, u32)]| {
+        sizes
+            .iter()
+            .map(|&(width, height)| resolution(width, height))
+            .collect::<Vec<_>>()
+    };
+    Resolutions {
+        single: to_resolutions(single),
+        dual: (!dual.is_empty()).then(|| to_resolutions(dual)),
+        triple: (!triple.is_empty()).then(|| to_resolutions(triple)),
+        mobile: None,
+    }
+}
+
+/// The wallpaper from `resources/get_wallpaper_success_fully_populated.json`.
+pub(crate) fn wallpaper() -> Wallpaper {
+    let response: GetWallpaperResponse = serde_json::from_str(
+        &fs::read_to_string("resources/get_wallpaper_success_fully_populated.json").unwrap(),
+    )
+    .unwrap();
+    response.wallpaper.unwrap()
+}
+
+pub(crate) fn wallpaper_released_at(id: u32, timestamp: Option<i64>) -> Wallpaper {
+    let mut wallpaper = wallpaper();
+    wallpaper.id = WallpaperId(id);
+    wallpaper.timestamp = timestamp;
+    wallpaper
+}